@@ -68,6 +68,9 @@ class BirthmarkSubstrate:
         authority_id: str,
         parent_image_hash: Optional[str] = None,
         wait_for_inclusion: bool = True,
+        width: Optional[int] = None,
+        height: Optional[int] = None,
+        byte_size: Optional[int] = None,
     ) -> Dict[str, Any]:
         """
         Submit a single image authentication record to the blockchain.
@@ -79,6 +82,9 @@ class BirthmarkSubstrate:
             authority_id: Manufacturer or software developer identifier
             parent_image_hash: Optional parent hash for provenance chain
             wait_for_inclusion: Wait for block inclusion before returning
+            width: Optional image width in pixels (advisory)
+            height: Optional image height in pixels (advisory)
+            byte_size: Optional file size in bytes (advisory)
 
         Returns:
             Dictionary with submission result:
@@ -116,6 +122,9 @@ class BirthmarkSubstrate:
                 'modification_level': modification_level,
                 'parent_image_hash': parent_hash_bytes,
                 'authority_id': authority_id_bytes,
+                'width': width,
+                'height': height,
+                'byte_size': byte_size,
             }
         )
 
@@ -183,16 +192,16 @@ class BirthmarkSubstrate:
         if not records or len(records) > 100:
             raise ValueError("Batch must contain 1-100 records")
 
-        # Convert records to Substrate format
+        # Convert records to BatchRecord structs (named fields in metadata)
         formatted_records = []
         for rec in records:
-            formatted_records.append((
-                bytes.fromhex(rec['image_hash']),
-                rec['submission_type'],
-                rec['modification_level'],
-                bytes.fromhex(rec['parent_image_hash']) if rec.get('parent_image_hash') else None,
-                rec['authority_id'].encode('utf-8'),
-            ))
+            formatted_records.append({
+                'image_hash': bytes.fromhex(rec['image_hash']),
+                'submission_type': rec['submission_type'],
+                'modification_level': rec['modification_level'],
+                'parent': bytes.fromhex(rec['parent_image_hash']) if rec.get('parent_image_hash') else None,
+                'authority': rec['authority_id'].encode('utf-8'),
+            })
 
         call = self.substrate.compose_call(
             call_module='Birthmark',
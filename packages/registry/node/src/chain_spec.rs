@@ -1,8 +1,8 @@
-use birthmark_runtime::{AccountId, Signature, RuntimeGenesisConfig, WASM_BINARY};
+use birthmark_runtime::{AccountId, SS58Prefix, Signature, RuntimeGenesisConfig, WASM_BINARY};
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{sr25519, Pair, Public};
+use sp_core::{sr25519, Get, Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
 // The URL for the telemetry server
@@ -11,6 +11,21 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec<RuntimeGenesisConfig>;
 
+/// Token symbol shown by wallets
+pub const TOKEN_SYMBOL: &str = "BMARK";
+
+/// Decimal places of the token: genesis balances are multiples of 10^12 base units
+pub const TOKEN_DECIMALS: u8 = 12;
+
+/// Chain spec properties read by wallets and `birthmark_chainProperties`
+pub fn chain_properties() -> serde_json::Map<String, serde_json::Value> {
+    let mut properties = serde_json::Map::new();
+    properties.insert("tokenSymbol".into(), TOKEN_SYMBOL.into());
+    properties.insert("tokenDecimals".into(), TOKEN_DECIMALS.into());
+    properties.insert("ss58Format".into(), SS58Prefix::get().into());
+    properties
+}
+
 /// Generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
     TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -42,23 +57,15 @@ pub fn development_config() -> Result<ChainSpec, String> {
     .with_name("Birthmark Development")
     .with_id("birthmark_dev")
     .with_chain_type(ChainType::Development)
+    .with_properties(chain_properties())
     .with_genesis_config_patch(testnet_genesis(
         // Initial PoA authorities (validators)
         vec![authority_keys_from_seed("Alice")],
-        // Sudo account
+        // Aggregator account
         get_account_id_from_seed::<sr25519::Public>("Alice"),
-        // Pre-funded accounts
-        vec![
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie"),
-            get_account_id_from_seed::<sr25519::Public>("Dave"),
-            get_account_id_from_seed::<sr25519::Public>("Eve"),
-            get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-            get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-        ],
-        true,
+        // Authorities are registered on first submission
+        vec![],
+        false,
     ))
     .build())
 }
@@ -72,6 +79,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
     .with_name("Birthmark Local Testnet")
     .with_id("birthmark_local")
     .with_chain_type(ChainType::Local)
+    .with_properties(chain_properties())
     .with_genesis_config_patch(testnet_genesis(
         // Initial PoA authorities (validators) - simulates journalism coalition
         vec![
@@ -79,16 +87,11 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
             authority_keys_from_seed("Bob"),
             authority_keys_from_seed("Charlie"),
         ],
-        // Sudo account
+        // Aggregator account
         get_account_id_from_seed::<sr25519::Public>("Alice"),
-        // Pre-funded accounts
-        vec![
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie"),
-            get_account_id_from_seed::<sr25519::Public>("Dave"),
-        ],
-        true,
+        // Authorities are registered on first submission
+        vec![],
+        false,
     ))
     .build())
 }
@@ -105,6 +108,7 @@ pub fn production_config() -> Result<ChainSpec, String> {
     .with_name("Birthmark Media Registry")
     .with_id("birthmark_mainnet")
     .with_chain_type(ChainType::Live)
+    .with_properties(chain_properties())
     .with_genesis_config_patch(testnet_genesis(
         // TODO: Replace with actual validator keys from journalism orgs
         // Example structure for production:
@@ -115,43 +119,30 @@ pub fn production_config() -> Result<ChainSpec, String> {
         //     // ... up to 50 validators
         // ],
         vec![authority_keys_from_seed("ProductionValidator1")],
-        // TODO: Set to governance-controlled sudo account or remove sudo entirely
+        // TODO: Replace with the submission server accounts of the launch aggregators
         get_account_id_from_seed::<sr25519::Public>("GovernanceAccount"),
-        // Pre-funded accounts for initial gas (journalism orgs)
-        vec![
-            get_account_id_from_seed::<sr25519::Public>("NPPA"),
-            get_account_id_from_seed::<sr25519::Public>("IFCN"),
-            get_account_id_from_seed::<sr25519::Public>("CPJ"),
-        ],
-        false, // Do not include sudo in production
+        // TODO: Replace with the vetted launch authorities; they are verified at
+        // genesis because the runtime has no origin that could verify them later
+        vec!["LAUNCH_AUTHORITY_1"],
+        true, // Verified authorities, parents for edits, no auto-registration
     ))
     .build())
 }
 
 /// Configure initial storage state for FRAME modules
+///
+/// Only pallets present in the runtime are configured; the runtime has no sudo
+/// or governance pallets, and balances only back identity deposits, so there are
+/// no keys or endowments to set.
+/// `production_mode` turns on the registry's production safety checks at genesis
+/// and marks `registry_authorities` as verified.
 fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
-    root_key: AccountId,
-    endowed_accounts: Vec<AccountId>,
-    _enable_println: bool,
+    aggregator: AccountId,
+    registry_authorities: Vec<&str>,
+    production_mode: bool,
 ) -> serde_json::Value {
-    // Convert AuraId to AccountId for council members
-    // Note: In production, council members should have proper account derivation
-    let council_members: Vec<AccountId> = initial_authorities
-        .iter()
-        .take(10) // Max 10 initial council members
-        .map(|_| get_account_id_from_seed::<sr25519::Public>("Alice")) // Placeholder
-        .collect();
-
     serde_json::json!({
-        "balances": {
-            // Configure pre-funded accounts (for gas fees)
-            "balances": endowed_accounts
-                .iter()
-                .cloned()
-                .map(|k| (k, 1_000_000_000_000_000u128))
-                .collect::<Vec<_>>(),
-        },
         "aura": {
             // Configure initial block production authorities
             "authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
@@ -163,19 +154,85 @@ fn testnet_genesis(
                 .map(|x| (x.1.clone(), 1))
                 .collect::<Vec<_>>(),
         },
-        "sudo": {
-            // Sudo key (remove in production or use governance-controlled account)
-            "key": Some(root_key),
-        },
-        "council": {
-            // Configure initial council members (journalism org representatives)
-            "members": council_members,
-            "phantom": null,
-        },
-        "democracy": {},
-        "treasury": {},
         "birthmark": {
-            // Initialize birthmark pallet (currently no genesis config needed)
+            // Authorized aggregators (submission servers)
+            "aggregators": vec![aggregator],
+            // Authority names seeded from `FirstUserAuthorityId`
+            "authorities": registry_authorities
+                .iter()
+                .map(|name| name.as_bytes().to_vec())
+                .collect::<Vec<_>>(),
+            "productionMode": production_mode,
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn genesis_only_configures_runtime_pallets() {
+        let genesis = testnet_genesis(
+            vec![authority_keys_from_seed("ProductionValidator1")],
+            get_account_id_from_seed::<sr25519::Public>("GovernanceAccount"),
+            vec!["LAUNCH_AUTHORITY_1"],
+            true,
+        );
+        assert_eq!(genesis["birthmark"]["productionMode"], true);
+        assert_eq!(genesis["birthmark"]["authorities"].as_array().unwrap().len(), 1);
+        for removed in ["sudo", "council", "democracy", "treasury"] {
+            assert!(genesis.get(removed).is_none(), "{removed} is not in the runtime");
+        }
+
+        let genesis = testnet_genesis(
+            vec![authority_keys_from_seed("Alice")],
+            get_account_id_from_seed::<sr25519::Public>("Alice"),
+            vec![],
+            false,
+        );
+        assert_eq!(genesis["birthmark"]["productionMode"], false);
+    }
+
+    #[test]
+    fn chain_specs_build_genesis_storage() {
+        use sc_service::ChainSpec as _;
+        use sp_runtime::BuildStorage;
+
+        // Fails on any genesis section the runtime does not know
+        for spec in [development_config(), local_testnet_config(), production_config()] {
+            let spec = spec.expect("runtime wasm is built with the node");
+            if let Err(error) = spec.build_storage() {
+                panic!("{} genesis does not build: {error}", spec.id());
+            }
+        }
+    }
+
+    #[test]
+    fn production_spec_verifies_its_genesis_authorities() {
+        use birthmark_runtime::Birthmark;
+        use sc_service::ChainSpec as _;
+
+        let storage = production_config().unwrap().build_storage().unwrap();
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            let authorities = Birthmark::genesis_authorities();
+            assert!(!authorities.is_empty(), "production spec seeds no authorities");
+            assert!(authorities.into_iter().all(Birthmark::is_authority_verified));
+        });
+    }
+
+    #[test]
+    fn chain_specs_declare_token_properties() {
+        use sc_service::ChainSpec as _;
+
+        for spec in [development_config(), local_testnet_config(), production_config()] {
+            let spec = spec.expect("runtime wasm is built with the node");
+            let json: serde_json::Value =
+                serde_json::from_str(&spec.as_json(false).unwrap()).unwrap();
+
+            assert_eq!(json["properties"]["tokenSymbol"], "BMARK");
+            assert_eq!(json["properties"]["tokenDecimals"], 12);
+            assert_eq!(json["properties"]["ss58Format"], 42);
+        }
+    }
+}
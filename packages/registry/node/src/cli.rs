@@ -12,6 +12,14 @@ pub struct Cli {
 
     #[command(flatten)]
     pub run: RunCmd,
+
+    /// Serve registry queries only, without accepting transactions over RPC
+    ///
+    /// Intended for public verifier nodes. Nonce and fee RPCs are not exposed and
+    /// the transaction pool holds nothing, so `author_submitExtrinsic` fails.
+    /// Cannot be combined with `--validator`.
+    #[arg(long)]
+    pub read_only_registry: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -46,3 +54,4 @@ pub enum Subcommand {
     #[command(subcommand)]
     Benchmark(frame_benchmarking_cli::BenchmarkCmd),
 }
+
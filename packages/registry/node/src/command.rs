@@ -161,8 +161,9 @@ pub fn run() -> sc_cli::Result<()> {
         }
         None => {
             let runner = cli.create_runner(&cli.run)?;
+            let read_only_registry = cli.read_only_registry;
             runner.run_node_until_exit(|config| async move {
-                service::new_full(config).map_err(sc_cli::Error::Service)
+                service::new_full(config, read_only_registry).map_err(sc_cli::Error::Service)
             })
         }
     }
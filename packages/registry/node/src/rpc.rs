@@ -3,11 +3,13 @@
 ///! Provides fast query endpoint for image hash verification.
 
 use std::sync::Arc;
-use birthmark_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use birthmark_runtime::{opaque::Block, AccountId, Balance, Nonce, SS58Prefix};
+use sc_client_api::ProofProvider;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::Get;
 use jsonrpsee::RpcModule;
 
 /// Full RPC dependencies
@@ -18,6 +20,10 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: sc_rpc::DenyUnsafe,
+    /// Serve registry queries only (`--read-only-registry`)
+    pub read_only: bool,
+    /// Properties of the chain spec the node was started with
+    pub properties: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Instantiate all full RPC extensions
@@ -27,12 +33,18 @@ pub fn create_full<C, P>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: ProofProvider<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_birthmark_runtime_api::BirthmarkApi<Block, AccountId>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
+    use pallet_birthmark_rpc::{
+        Birthmark, BirthmarkApiServer, ChainProperties, ChainPropertiesApiServer,
+        ChainPropertiesJson,
+    };
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -41,34 +53,44 @@ where
         client,
         pool,
         deny_unsafe,
+        read_only,
+        properties,
     } = deps;
 
-    // Standard Substrate RPC endpoints
-    module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    // Standard Substrate RPC endpoints. Nonce, dry-run and fee lookups only serve
+    // transaction authors, so read-only nodes leave them out.
+    if !read_only {
+        module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+        module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    }
 
-    // TODO: Add custom Birthmark RPC endpoints
-    //
-    // Example custom RPC for fast image hash queries:
-    //
-    // module.merge(Birthmark::new(client.clone()).into_rpc())?;
-    //
-    // This would provide endpoints like:
+    // Birthmark registry endpoints (pallets/birthmark/rpc):
     // - birthmark_getRecord(image_hash) -> ImageRecord | null
-    // - birthmark_getTotalRecords() -> u64
-    // - birthmark_verifyImage(image_hash) -> bool
-    //
-    // Implementation requires:
-    // 1. Create pallets/birthmark/rpc crate
-    // 2. Define RPC trait with #[rpc(client, server)] macro
-    // 3. Implement trait using runtime API calls
-    // 4. Merge into module here
+    // - birthmark_getRecords([image_hash]) -> [ImageRecord | null] (positional)
+    // - birthmark_recordBlock(image_hash) -> block number | null
+    // - birthmark_getRecordResolved(image_hash) -> ImageRecord + authorityName | null
+    // - birthmark_verify(image_hash) -> { exists, authority, modificationLevel, blockNumber }
+    // - birthmark_batchVerifyDetailed([image_hash]) -> [birthmark_verify result] (positional)
+    // - birthmark_getProvenanceReport(image_hash) -> ancestors with authority names
+    // - birthmark_levelHistogram(from_block, to_block) -> per-level counts per snapshot
+    // - birthmark_recordsMerkleRoot(from_block, to_block) -> Merkle root of records (anchoring)
+    // - birthmark_exportSnapshot(start_key, page_size) -> records + nextKey (mirrors)
+    // - birthmark_estimateBatchWeight(records, parents) -> refTime + proofSize
+    // - birthmark_storageVersion() -> on-chain pallet storage version
+    // - birthmark_status() -> best/finalized block and total records (readiness probe)
+    // - birthmark_isAggregator(account) -> whether the account may submit records
+    // - birthmark_recordsBySubmitter(account, start, limit) -> record hashes (paged, audits)
+    // - birthmark_storageFootprint() -> record/authority counts + estimated bytes
+    // - birthmark_constants() -> batch/name/hash/provenance limits (client SDKs)
+    // - birthmark_decodeRecord(scale_hex) -> ImageRecord + authorityName (debugging)
+    // - birthmark_getRecordProof(image_hash) -> storage proof + state root
+    // - birthmark_getRecordWithProof(image_hash) -> record | null + storage proof (one block)
+    module.merge(Birthmark::<_, _, AccountId>::new(client.clone()).into_rpc())?;
+
+    // Chain spec properties for wallets (pallets/birthmark/rpc):
+    // - birthmark_chainProperties() -> ss58Prefix, tokenSymbol, tokenDecimals
+    let properties = ChainPropertiesJson::from_properties(&properties, SS58Prefix::get().into());
+    module.merge(ChainProperties::new(properties).into_rpc())?;
 
     Ok(module)
 }
-
-// Custom RPC implementation example (commented out until pallet RPC crate is created)
-//
-// use birthmark_rpc::{Birthmark, BirthmarkApiServer};
-//
-// module.merge(Birthmark::new(client.clone()).into_rpc())?;
@@ -3,13 +3,15 @@
 ///! This module sets up the full node service including consensus, networking,
 ///! RPC, and transaction pool.
 
-use birthmark_runtime::{self, opaque::Block, RuntimeApi};
+use birthmark_runtime::{self, opaque::Block, AccountId, RuntimeApi};
+use pallet_birthmark_runtime_api::BirthmarkApi;
 use sc_client_api::backend::Backend;
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
 use std::{sync::Arc, time::Duration};
 
@@ -137,8 +139,60 @@ pub fn new_partial(config: &Configuration) -> Result<PartialComponents, ServiceE
     })
 }
 
+/// Transaction pool limits for a `--read-only-registry` node
+///
+/// `spawn_tasks` always registers the author RPC, so submissions are refused by
+/// a pool that cannot hold any transaction: each import is dropped immediately.
+pub fn read_only_pool_options(
+    options: sc_transaction_pool::Options,
+) -> sc_transaction_pool::Options {
+    let empty = sc_transaction_pool::PoolLimit { count: 0, total_bytes: 0 };
+    sc_transaction_pool::Options { ready: empty.clone(), future: empty, ..options }
+}
+
+/// Accept a `BirthmarkApi` version reported by the runtime, or explain its absence
+pub fn check_birthmark_api(version: Option<u32>) -> Result<u32, ServiceError> {
+    version.ok_or_else(|| {
+        ServiceError::Other(
+            "the runtime at the best block does not expose BirthmarkApi; \
+             check that the chain spec and database belong to a Birthmark runtime"
+                .into(),
+        )
+    })
+}
+
+/// Fail fast if the best block's runtime lacks `BirthmarkApi`
+///
+/// Without it every `birthmark_*` RPC call fails, which is easy to miss after
+/// an accidental runtime downgrade or a wrong `--chain`.
+fn ensure_birthmark_api(client: &FullClient) -> Result<(), ServiceError> {
+    let best = client.chain_info().best_hash;
+    let version = client
+        .runtime_api()
+        .api_version::<dyn BirthmarkApi<Block, AccountId>>(best)
+        .map_err(|e| ServiceError::Other(format!("Unable to read runtime API versions: {e}")))?;
+    let version = check_birthmark_api(version)?;
+    log::info!("Runtime exposes BirthmarkApi version {version}");
+    Ok(())
+}
+
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
+///
+/// With `read_only_registry` the node serves registry queries but accepts no
+/// transactions, so it must not author blocks.
+pub fn new_full(
+    mut config: Configuration,
+    read_only_registry: bool,
+) -> Result<TaskManager, ServiceError> {
+    if read_only_registry {
+        if config.role.is_authority() {
+            return Err(ServiceError::Other(
+                "--read-only-registry cannot be combined with --validator".into(),
+            ));
+        }
+        config.transaction_pool = read_only_pool_options(config.transaction_pool.clone());
+    }
+
     let sc_service::PartialComponents {
         client,
         backend,
@@ -150,6 +204,8 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
         other: (block_import, grandpa_link, mut telemetry),
     } = new_partial(&config)?;
 
+    ensure_birthmark_api(&client)?;
+
     let mut net_config = sc_network::config::FullNetworkConfiguration::new(&config.network);
 
     let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
@@ -216,12 +272,15 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        let properties = config.chain_spec.properties();
 
         Box::new(move |deny_unsafe, _| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: pool.clone(),
                 deny_unsafe,
+                read_only: read_only_registry,
+                properties: properties.clone(),
             };
             crate::rpc::create_full(deps).map_err(Into::into)
         })
@@ -323,3 +382,24 @@ pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
     network_starter.start_network();
     Ok(task_manager)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_only_pool_holds_no_transactions() {
+        let options = read_only_pool_options(sc_transaction_pool::Options::default());
+        assert_eq!(options.ready.count, 0);
+        assert_eq!(options.future.count, 0);
+        assert_eq!(options.ready.total_bytes, 0);
+    }
+
+    #[test]
+    fn missing_birthmark_api_is_an_error() {
+        assert_eq!(check_birthmark_api(Some(1)).unwrap(), 1);
+
+        let err = check_birthmark_api(None).unwrap_err();
+        assert!(err.to_string().contains("does not expose BirthmarkApi"));
+    }
+}
@@ -0,0 +1,133 @@
+//! End-to-end check of `--read-only-registry` against a running node
+//!
+//! `spawn_tasks` always registers the author RPC, so the flag cannot remove it;
+//! this test starts the node binary and checks over HTTP that submissions are
+//! refused while registry queries are still answered.
+
+use birthmark_runtime::{
+    check_aggregator::CheckAggregator, count_rejections::CountRejections, RuntimeCall,
+    SignedExtra, UncheckedExtrinsic, VERSION,
+};
+use codec::Encode;
+use serde_json::{json, Value};
+use sp_core::{bytes, sr25519, Pair, H256};
+use sp_runtime::{generic, MultiAddress};
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Node process that is killed when the test ends, pass or fail
+struct Node(Child);
+
+impl Drop for Node {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// A port that was free a moment ago
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port()
+}
+
+/// Send one JSON-RPC request over HTTP and return the response object
+fn rpc(port: u16, method: &str, params: Value) -> std::io::Result<Value> {
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string();
+    let mut stream = TcpStream::connect(("127.0.0.1", port))?;
+    write!(
+        stream,
+        "POST / HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    let (_, body) = response.split_once("\r\n\r\n").unwrap_or_default();
+    serde_json::from_str(body).map_err(Into::into)
+}
+
+/// A correctly signed `system.remark` from Alice, SCALE-encoded as hex
+fn signed_remark(genesis_hash: H256) -> String {
+    let alice = sr25519::Pair::from_string("//Alice", None).unwrap();
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: b"read-only".to_vec() });
+    let extra: SignedExtra = (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckEra::from(generic::Era::Immortal),
+        frame_system::CheckNonce::from(0),
+        frame_system::CheckWeight::new(),
+        CheckAggregator,
+        CountRejections,
+    );
+    let additional = (
+        (),
+        VERSION.spec_version,
+        VERSION.transaction_version,
+        genesis_hash,
+        genesis_hash,
+        (),
+        (),
+        (),
+        (),
+    );
+    let payload = generic::SignedPayload::from_raw(call.clone(), extra.clone(), additional);
+    let signature = payload.using_encoded(|payload| alice.sign(payload));
+    let extrinsic = UncheckedExtrinsic::new_signed(
+        call,
+        MultiAddress::Id(alice.public().into()),
+        signature.into(),
+        extra,
+    );
+    bytes::to_hex(&extrinsic.encode(), false)
+}
+
+#[test]
+fn read_only_node_rejects_submissions_but_answers_queries() {
+    let rpc_port = free_port();
+    let node = Node(
+        Command::new(env!("CARGO_BIN_EXE_birthmark-node"))
+            .args(["--chain", "dev", "--tmp", "--read-only-registry", "--no-prometheus"])
+            .args(["--rpc-port", &rpc_port.to_string(), "--port", &free_port().to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("node binary is built with the tests"),
+    );
+
+    // Queries are answered once the RPC server is up
+    let hash = format!("0x{}", "00".repeat(32));
+    let deadline = Instant::now() + Duration::from_secs(120);
+    let record = loop {
+        match rpc(rpc_port, "birthmark_getRecord", json!([hash])) {
+            Ok(response) => break response,
+            Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(500)),
+            Err(error) => panic!("node RPC did not come up: {error}"),
+        }
+    };
+    assert_eq!(record["result"], Value::Null, "unexpected response: {record}");
+    assert!(record.get("error").is_none(), "unexpected error: {record}");
+
+    // Author-only helpers are not exposed
+    let methods = rpc(rpc_port, "rpc_methods", json!([])).unwrap();
+    let methods = methods["result"]["methods"].as_array().unwrap();
+    assert!(methods.contains(&json!("birthmark_getRecord")));
+    assert!(!methods.contains(&json!("system_accountNextIndex")));
+
+    // A well-formed signed extrinsic is refused
+    let genesis = rpc(rpc_port, "chain_getBlockHash", json!([0])).unwrap();
+    let genesis = bytes::from_hex(genesis["result"].as_str().unwrap()).unwrap();
+    let extrinsic = signed_remark(H256::from_slice(&genesis));
+    let submitted = rpc(rpc_port, "author_submitExtrinsic", json!([extrinsic])).unwrap();
+    assert!(submitted.get("error").is_some(), "submission was accepted: {submitted}");
+
+    drop(node);
+}
@@ -0,0 +1,81 @@
+//! Errors returned by the Birthmark RPC methods.
+
+use jsonrpsee::types::{ErrorObject, ErrorObjectOwned};
+
+/// Error returned to RPC clients
+///
+/// Each variant maps to a stable numeric code so client SDKs can branch on the
+/// error type instead of parsing messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BirthmarkRpcError {
+    /// The image hash is not valid hex
+    InvalidHex(String),
+    /// The image hash decoded to the wrong number of bytes (expected 32)
+    HashWrongLength(usize),
+    /// Calling into the runtime or reading client state failed
+    RuntimeApi(String),
+    /// The bytes are not a SCALE-encoded `ImageRecord`
+    MalformedRecord(String),
+    /// The block range is wider than the runtime accepts; carries the widest accepted span
+    RangeTooWide(u32),
+    /// The block range holds more records than the runtime accepts; carries the limit
+    TooManyRecords(u32),
+}
+
+impl BirthmarkRpcError {
+    /// Code for [`BirthmarkRpcError::RuntimeApi`]
+    pub const RUNTIME_API: i32 = 1;
+    /// Code for [`BirthmarkRpcError::InvalidHex`]
+    pub const INVALID_HEX: i32 = 2;
+    /// Code for [`BirthmarkRpcError::HashWrongLength`]
+    pub const HASH_WRONG_LENGTH: i32 = 3;
+    /// Code for [`BirthmarkRpcError::MalformedRecord`]
+    pub const MALFORMED_RECORD: i32 = 4;
+    /// Code for [`BirthmarkRpcError::RangeTooWide`]
+    pub const RANGE_TOO_WIDE: i32 = 5;
+    /// Code for [`BirthmarkRpcError::TooManyRecords`]
+    pub const TOO_MANY_RECORDS: i32 = 6;
+
+    /// Stable numeric code reported to clients
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::RuntimeApi(_) => Self::RUNTIME_API,
+            Self::InvalidHex(_) => Self::INVALID_HEX,
+            Self::HashWrongLength(_) => Self::HASH_WRONG_LENGTH,
+            Self::MalformedRecord(_) => Self::MALFORMED_RECORD,
+            Self::RangeTooWide(_) => Self::RANGE_TOO_WIDE,
+            Self::TooManyRecords(_) => Self::TOO_MANY_RECORDS,
+        }
+    }
+
+    /// Wrap a client or runtime API failure
+    pub fn runtime(context: &str, e: impl std::fmt::Debug) -> Self {
+        Self::RuntimeApi(format!("{}: {:?}", context, e))
+    }
+}
+
+impl From<BirthmarkRpcError> for ErrorObjectOwned {
+    fn from(e: BirthmarkRpcError) -> Self {
+        let code = e.code();
+        match e {
+            BirthmarkRpcError::InvalidHex(detail) => {
+                ErrorObject::owned(code, "Invalid hex image hash", Some(detail))
+            }
+            BirthmarkRpcError::HashWrongLength(len) => {
+                ErrorObject::owned(code, "Image hash must be 32 bytes", Some(len))
+            }
+            BirthmarkRpcError::RuntimeApi(detail) => {
+                ErrorObject::owned(code, "Runtime API call failed", Some(detail))
+            }
+            BirthmarkRpcError::MalformedRecord(detail) => {
+                ErrorObject::owned(code, "Bytes do not decode as an image record", Some(detail))
+            }
+            BirthmarkRpcError::RangeTooWide(max) => {
+                ErrorObject::owned(code, "Block range too wide", Some(max))
+            }
+            BirthmarkRpcError::TooManyRecords(max) => {
+                ErrorObject::owned(code, "Block range holds too many records", Some(max))
+            }
+        }
+    }
+}
@@ -0,0 +1,959 @@
+//! # Birthmark RPC
+//!
+//! JSON-RPC interface for the Birthmark pallet, backed by the `BirthmarkApi` runtime API.
+//!
+//! Image hashes are accepted as hex strings (64 chars, optional `0x` prefix) and
+//! rendered as `0x`-prefixed hex in responses. Failures are reported with the
+//! stable codes of [`BirthmarkRpcError`]; an absent record is not an error.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use birthmark_verify::HashError;
+use codec::{Codec, DecodeAll};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_birthmark::{
+    BirthmarkConstants, ImageRecord, StorageFootprint, SubmissionType, MAX_BLOCK_RANGE,
+    MAX_MERKLE_LEAVES,
+};
+use pallet_birthmark_runtime_api::BirthmarkApi as BirthmarkRuntimeApi;
+use sc_client_api::ProofProvider;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+
+mod error;
+pub use error::BirthmarkRpcError;
+
+#[cfg(test)]
+mod tests;
+
+/// JSON view of an on-chain [`ImageRecord`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageRecordJson {
+    /// SHA-256 hash of the image
+    pub image_hash: H256,
+    /// "Camera" or "Software"
+    pub submission_type: String,
+    /// 0 = raw, 1 = validated, 2 = modified
+    pub modification_level: u8,
+    /// Hash of the parent image, if any
+    pub parent_image_hash: Option<H256>,
+    /// Authority registry index
+    pub authority_id: u32,
+    /// Submission timestamp (server processing time)
+    pub timestamp: u32,
+    /// Block the record was stored in
+    pub block_number: u32,
+    /// Index of the validator that authored that block, if known
+    pub validator_index: Option<u32>,
+    /// Image width in pixels, as reported by the submitter
+    pub width: Option<u32>,
+    /// Image height in pixels, as reported by the submitter
+    pub height: Option<u32>,
+    /// File size in bytes, as reported by the submitter
+    pub byte_size: Option<u32>,
+    /// Record schema version (0 for records stored before schemas existed)
+    pub schema: u8,
+}
+
+impl From<ImageRecord> for ImageRecordJson {
+    fn from(record: ImageRecord) -> Self {
+        let submission_type = match record.submission_type {
+            SubmissionType::Camera => "Camera",
+            SubmissionType::Software => "Software",
+        };
+
+        Self {
+            image_hash: H256::from(record.image_hash),
+            submission_type: submission_type.into(),
+            modification_level: record.modification_level,
+            parent_image_hash: record.parent_image_hash.map(H256::from),
+            authority_id: record.authority_id,
+            timestamp: record.timestamp,
+            block_number: record.block_number,
+            validator_index: record.validator_index,
+            width: record.width.map(|w| w.0),
+            height: record.height.map(|h| h.0),
+            byte_size: record.byte_size.map(|b| b.0),
+            schema: record.schema,
+        }
+    }
+}
+
+/// JSON view of a record with its authority name resolved
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedRecordJson {
+    /// The record itself
+    #[serde(flatten)]
+    pub record: ImageRecordJson,
+    /// Authority name (see [`render_authority_name`]), `null` if the registry entry is missing
+    pub authority_name: Option<String>,
+}
+
+impl From<(ImageRecord, Option<Vec<u8>>)> for ResolvedRecordJson {
+    fn from((record, name): (ImageRecord, Option<Vec<u8>>)) -> Self {
+        Self {
+            record: record.into(),
+            authority_name: name.map(render_authority_name),
+        }
+    }
+}
+
+/// One step of a provenance report
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenanceStepJson {
+    /// SHA-256 hash of this image
+    pub image_hash: H256,
+    /// Authority name (see [`render_authority_name`]), `null` if the registry entry is missing
+    pub authority_name: Option<String>,
+    /// 0 = raw, 1 = validated, 2 = modified
+    pub modification_level: u8,
+    /// "Camera" or "Software"
+    pub submission_type: String,
+}
+
+impl From<(ImageRecord, Option<Vec<u8>>)> for ProvenanceStepJson {
+    fn from(step: (ImageRecord, Option<Vec<u8>>)) -> Self {
+        let resolved = ResolvedRecordJson::from(step);
+
+        Self {
+            image_hash: resolved.record.image_hash,
+            authority_name: resolved.authority_name,
+            modification_level: resolved.record.modification_level,
+            submission_type: resolved.record.submission_type,
+        }
+    }
+}
+
+/// One-shot answer to "is this authentic, and who authenticated it?"
+///
+/// Every field except `exists` is `null` when the hash is not registered.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationJson {
+    /// Whether a record is stored under the hash
+    pub exists: bool,
+    /// Authority name (see [`render_authority_name`])
+    pub authority: Option<String>,
+    /// 0 = raw, 1 = validated, 2 = modified
+    pub modification_level: Option<u8>,
+    /// Block the record was stored in
+    pub block_number: Option<u32>,
+}
+
+impl From<Option<(ImageRecord, Option<Vec<u8>>)>> for VerificationJson {
+    fn from(resolved: Option<(ImageRecord, Option<Vec<u8>>)>) -> Self {
+        let resolved = resolved.map(ResolvedRecordJson::from);
+
+        Self {
+            exists: resolved.is_some(),
+            authority: resolved.as_ref().and_then(|r| r.authority_name.clone()),
+            modification_level: resolved.as_ref().map(|r| r.record.modification_level),
+            block_number: resolved.as_ref().map(|r| r.record.block_number),
+        }
+    }
+}
+
+/// Per-level record counts captured by one statistics snapshot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelHistogramPoint {
+    /// Block the snapshot was taken in
+    pub block: u32,
+    /// Raw sensor records (level 0)
+    pub raw: u64,
+    /// Validated records (level 1)
+    pub validated: u64,
+    /// Modified records (level 2)
+    pub modified: u64,
+}
+
+impl From<(u32, [u64; 3])> for LevelHistogramPoint {
+    fn from((block, [raw, validated, modified]): (u32, [u64; 3])) -> Self {
+        Self {
+            block,
+            raw,
+            validated,
+            modified,
+        }
+    }
+}
+
+/// One page of a full registry export
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotPage {
+    /// Records in storage-key order, each carrying its own image hash
+    pub records: Vec<ImageRecordJson>,
+    /// Pass as `start_key` to fetch the next page; `null` on the last page
+    pub next_key: Option<H256>,
+}
+
+impl From<(Vec<ImageRecord>, Option<[u8; 32]>)> for SnapshotPage {
+    fn from((records, next_key): (Vec<ImageRecord>, Option<[u8; 32]>)) -> Self {
+        Self {
+            records: records.into_iter().map(Into::into).collect(),
+            next_key: next_key.map(H256::from),
+        }
+    }
+}
+
+/// Weight a batch submission will be charged, for sizing batches before signing
+///
+/// The runtime is feeless, so weight (not a token amount) is what limits how
+/// many batches fit in a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchWeightEstimate {
+    /// Computation time, in picoseconds
+    pub ref_time: u64,
+    /// Proof size, in bytes
+    pub proof_size: u64,
+}
+
+impl From<(u64, u64)> for BatchWeightEstimate {
+    fn from((ref_time, proof_size): (u64, u64)) -> Self {
+        Self {
+            ref_time,
+            proof_size,
+        }
+    }
+}
+
+/// Registry readiness report for load balancers and monitoring
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryStatus {
+    /// Best (head) block number; `0` means the node is still at genesis
+    pub best_block: u64,
+    /// Last finalized block number
+    pub finalized_block: u64,
+    /// Records stored as of the best block
+    pub total_records: u64,
+}
+
+/// Estimated registry state size, for capacity planning
+///
+/// `estimatedBytes` bounds record and authority values only; storage keys and
+/// indexes add to the real footprint.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageFootprintJson {
+    /// Records currently stored
+    pub record_count: u64,
+    /// Registered authorities, active or not
+    pub authority_count: u32,
+    /// Upper-bound estimate of record and authority values, in bytes
+    pub estimated_bytes: u64,
+}
+
+impl From<StorageFootprint> for StorageFootprintJson {
+    fn from(footprint: StorageFootprint) -> Self {
+        Self {
+            record_count: footprint.record_count,
+            authority_count: footprint.authority_count,
+            estimated_bytes: footprint.estimated_bytes,
+        }
+    }
+}
+
+/// Storage read proof for a single `ImageRecords` entry
+///
+/// Verify it against `state_root`, which is taken from the header of `block_hash`.
+/// Clients should only trust that root if they trust the header itself (e.g. it is
+/// GRANDPA-finalized). See `pallet_birthmark::proof::verify_record_proof`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordProof<Hash> {
+    /// Block the proof was generated at
+    pub block_hash: Hash,
+    /// State root from the header of `block_hash`
+    pub state_root: Hash,
+    /// Storage key of the record
+    pub key: Bytes,
+    /// Trie nodes proving the value (or absence) stored under `key`
+    pub proof: Vec<Bytes>,
+}
+
+/// A record together with the storage proof for its `ImageRecords` entry
+///
+/// `record` is `null` when the hash is absent; the proof then shows non-inclusion
+/// (see `pallet_birthmark::proof::verify_record_absence`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordWithProof<Hash> {
+    /// The record at `blockHash`, if present
+    pub record: Option<ImageRecordJson>,
+    /// Proof of the record's presence or absence
+    #[serde(flatten)]
+    pub proof: RecordProof<Hash>,
+}
+
+/// Birthmark RPC methods
+#[rpc(client, server)]
+pub trait BirthmarkApi<BlockHash, AccountId> {
+    /// Fetch the record stored under an image hash, or `null` if absent
+    #[method(name = "birthmark_getRecord")]
+    fn get_record(&self, image_hash: String, at: Option<BlockHash>) -> RpcResult<Option<ImageRecordJson>>;
+
+    /// Fetch many records at once; `null` entries mark absent hashes
+    ///
+    /// Results are positional and cover at most the runtime's `MaxBulkQuerySize`
+    /// hashes, so compare the lengths of request and response.
+    #[method(name = "birthmark_getRecords")]
+    fn get_records(
+        &self,
+        image_hashes: Vec<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<ImageRecordJson>>>;
+
+    /// Block number a hash was recorded in, or `null` if absent
+    #[method(name = "birthmark_recordBlock")]
+    fn record_block(&self, image_hash: String, at: Option<BlockHash>) -> RpcResult<Option<u32>>;
+
+    /// Fetch a record with its authority name resolved, or `null` if absent
+    #[method(name = "birthmark_getRecordResolved")]
+    fn get_record_resolved(
+        &self,
+        image_hash: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ResolvedRecordJson>>;
+
+    /// Whether a hash is registered, with its authority, level and block
+    ///
+    /// An unknown hash yields `exists: false` rather than `null`.
+    #[method(name = "birthmark_verify")]
+    fn verify(&self, image_hash: String, at: Option<BlockHash>) -> RpcResult<VerificationJson>;
+
+    /// `birthmark_verify` for many hashes in one call, in input order
+    ///
+    /// Only the first `MaxBulkQuerySize` hashes are answered, so callers should
+    /// compare lengths and query the rest separately.
+    #[method(name = "birthmark_batchVerifyDetailed")]
+    fn batch_verify_detailed(
+        &self,
+        image_hashes: Vec<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<VerificationJson>>;
+
+    /// Walk a record's ancestors, child first, with authority names resolved
+    ///
+    /// Empty if the record is absent; capped at the runtime's `MaxProvenanceDepth`.
+    #[method(name = "birthmark_getProvenanceReport")]
+    fn get_provenance_report(
+        &self,
+        image_hash: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProvenanceStepJson>>;
+
+    /// Generate a storage read proof for the record stored under an image hash
+    #[method(name = "birthmark_getRecordProof")]
+    fn get_record_proof(
+        &self,
+        image_hash: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RecordProof<BlockHash>>;
+
+    /// Fetch a record and its storage proof from the same block in one call
+    #[method(name = "birthmark_getRecordWithProof")]
+    fn get_record_with_proof(
+        &self,
+        image_hash: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<RecordWithProof<BlockHash>>;
+
+    /// Per-level counts from statistics snapshots taken between two blocks (inclusive)
+    #[method(name = "birthmark_levelHistogram")]
+    fn level_histogram(
+        &self,
+        from_block: u32,
+        to_block: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<LevelHistogramPoint>>;
+
+    /// Merkle root of all records stored in `from_block..=to_block`, for external anchoring
+    ///
+    /// Zero if the range holds no records. Ranges wider than the runtime's
+    /// `MAX_BLOCK_RANGE`, or holding more than `MAX_MERKLE_LEAVES` records, are
+    /// refused; split them into narrower ranges. Blocks past `BlockIndexRetention` and
+    /// removed records drop out of the root, so publish the covered hashes with it.
+    #[method(name = "birthmark_recordsMerkleRoot")]
+    fn records_merkle_root(
+        &self,
+        from_block: u32,
+        to_block: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<H256>;
+
+    /// Export the registry a page at a time, for read-only mirrors
+    ///
+    /// Pages hold at most `page_size` records (capped at the runtime's
+    /// `MaxBulkQuerySize`). For a consistent mirror, pin every page to the same
+    /// `at` block, then follow `ImageRecordSubmitted` events from the next block.
+    #[method(name = "birthmark_exportSnapshot")]
+    fn export_snapshot(
+        &self,
+        start_key: Option<String>,
+        page_size: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SnapshotPage>;
+
+    /// Weight of a batch with `records` entries, `parents` of which name a parent
+    ///
+    /// Uses the same parent-aware formula as `submit_image_batch` dispatch.
+    #[method(name = "birthmark_estimateBatchWeight")]
+    fn estimate_batch_weight(
+        &self,
+        records: u32,
+        parents: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<BatchWeightEstimate>;
+
+    /// On-chain storage version of the Birthmark pallet
+    #[method(name = "birthmark_storageVersion")]
+    fn storage_version(&self, at: Option<BlockHash>) -> RpcResult<u16>;
+
+    /// Chain and registry state for readiness probes
+    #[method(name = "birthmark_status")]
+    fn status(&self) -> RpcResult<RegistryStatus>;
+
+    /// Number of records attributed to an authority
+    #[method(name = "birthmark_authorityRecordCount")]
+    fn authority_record_count(&self, authority_id: u32, at: Option<BlockHash>) -> RpcResult<u64>;
+
+    /// Whether an account may currently submit records
+    ///
+    /// Submissions must be signed, so root is never an aggregator.
+    #[method(name = "birthmark_isAggregator")]
+    fn is_aggregator(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// Hashes of records submitted by an account, a page at a time
+    ///
+    /// Pages hold at most `limit` hashes (capped at the runtime's
+    /// `MaxBulkQuerySize`). Pass the last hash of a page as `start` for the next.
+    #[method(name = "birthmark_recordsBySubmitter")]
+    fn records_by_submitter(
+        &self,
+        account: AccountId,
+        start: Option<String>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<H256>>;
+
+    /// Record and authority counts with an estimate of the state they occupy
+    #[method(name = "birthmark_storageFootprint")]
+    fn storage_footprint(&self, at: Option<BlockHash>) -> RpcResult<StorageFootprintJson>;
+
+    /// Configured submission limits, so clients need not hard-code them
+    #[method(name = "birthmark_constants")]
+    fn constants(&self, at: Option<BlockHash>) -> RpcResult<BirthmarkConstantsJson>;
+
+    /// Decode a hex SCALE-encoded `ImageRecord`, e.g. a value from a storage proof
+    ///
+    /// Decoding uses this node's record type and does not require the record to
+    /// be on chain; only the authority name is looked up, at `at`.
+    #[method(name = "birthmark_decodeRecord")]
+    fn decode_record(&self, record: String, at: Option<BlockHash>) -> RpcResult<ResolvedRecordJson>;
+}
+
+/// Implementation of the Birthmark RPC methods
+///
+/// `AccountId` must match the runtime's account type, which parameterizes the runtime API.
+pub struct Birthmark<C, Block, AccountId> {
+    client: Arc<C>,
+    _marker: PhantomData<(Block, AccountId)>,
+}
+
+impl<C, Block, AccountId> Birthmark<C, Block, AccountId> {
+    /// Create a new RPC handler backed by `client`
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId> BirthmarkApiServer<<Block as BlockT>::Hash, AccountId>
+    for Birthmark<C, Block, AccountId>
+where
+    Block: BlockT,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block> + Send + Sync + 'static,
+    C::Api: BirthmarkRuntimeApi<Block, AccountId>,
+{
+    fn get_record(
+        &self,
+        image_hash: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ImageRecordJson>> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let record = self
+            .client
+            .runtime_api()
+            .get_image_record(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query image record", e))?;
+
+        Ok(record.map(Into::into))
+    }
+
+    fn get_records(
+        &self,
+        image_hashes: Vec<String>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<Option<ImageRecordJson>>> {
+        let image_hashes = image_hashes
+            .iter()
+            .map(|hash| parse_hash(hash))
+            .collect::<Result<Vec<_>, _>>()?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let records = self
+            .client
+            .runtime_api()
+            .get_records(at, image_hashes)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query image records", e))?;
+
+        Ok(records.into_iter().map(|record| record.map(Into::into)).collect())
+    }
+
+    fn record_block(&self, image_hash: String, at: Option<Block::Hash>) -> RpcResult<Option<u32>> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let block = self
+            .client
+            .runtime_api()
+            .record_block(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query record block", e))?;
+
+        Ok(block)
+    }
+
+    fn get_record_resolved(
+        &self,
+        image_hash: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ResolvedRecordJson>> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let resolved = self
+            .client
+            .runtime_api()
+            .get_record_with_authority(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query resolved image record", e))?;
+
+        Ok(resolved.map(Into::into))
+    }
+
+    fn verify(&self, image_hash: String, at: Option<Block::Hash>) -> RpcResult<VerificationJson> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let resolved = self
+            .client
+            .runtime_api()
+            .get_record_with_authority(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to verify image hash", e))?;
+
+        Ok(resolved.into())
+    }
+
+    fn batch_verify_detailed(
+        &self,
+        image_hashes: Vec<String>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<VerificationJson>> {
+        let image_hashes = image_hashes
+            .iter()
+            .map(|hash| parse_hash(hash))
+            .collect::<Result<Vec<_>, _>>()?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let resolved = self
+            .client
+            .runtime_api()
+            .get_records_with_authority(at, image_hashes)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to verify image hashes", e))?;
+
+        Ok(resolved.into_iter().map(Into::into).collect())
+    }
+
+    fn get_provenance_report(
+        &self,
+        image_hash: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ProvenanceStepJson>> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let report = self
+            .client
+            .runtime_api()
+            .provenance_report(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to build provenance report", e))?;
+
+        Ok(report.into_iter().map(Into::into).collect())
+    }
+
+    fn get_record_proof(
+        &self,
+        image_hash: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<RecordProof<Block::Hash>> {
+        let image_hash = parse_hash(&image_hash)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let key = self
+            .client
+            .runtime_api()
+            .record_storage_key(at, image_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to compute record storage key", e))?;
+
+        // The runtime cannot see trie nodes, so the proof is read from the client's state
+        let header = self
+            .client
+            .header(at)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to read block header", e))?
+            .ok_or_else(|| BirthmarkRpcError::runtime("Block header not found", at))?;
+        let proof = self
+            .client
+            .read_proof(at, &mut std::iter::once(key.as_slice()))
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to generate storage proof", e))?;
+
+        Ok(RecordProof {
+            block_hash: at,
+            state_root: *header.state_root(),
+            key: key.into(),
+            proof: proof.into_iter_nodes().map(Into::into).collect(),
+        })
+    }
+
+    fn get_record_with_proof(
+        &self,
+        image_hash: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<RecordWithProof<Block::Hash>> {
+        // Pin both reads to one block so the record matches the proof
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let proof = self.get_record_proof(image_hash.clone(), Some(at))?;
+        let record = self.get_record(image_hash, Some(at))?;
+
+        Ok(RecordWithProof { record, proof })
+    }
+
+    fn level_histogram(
+        &self,
+        from_block: u32,
+        to_block: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<LevelHistogramPoint>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let histogram = self
+            .client
+            .runtime_api()
+            .level_histogram(at, from_block, to_block)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query level histogram", e))?;
+
+        Ok(histogram.into_iter().map(Into::into).collect())
+    }
+
+    fn records_merkle_root(
+        &self,
+        from_block: u32,
+        to_block: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<H256> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let root = self
+            .client
+            .runtime_api()
+            .records_merkle_root(at, from_block, to_block)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to compute records Merkle root", e))?
+            .ok_or(if to_block.saturating_sub(from_block) > MAX_BLOCK_RANGE {
+                BirthmarkRpcError::RangeTooWide(MAX_BLOCK_RANGE)
+            } else {
+                BirthmarkRpcError::TooManyRecords(MAX_MERKLE_LEAVES)
+            })?;
+
+        Ok(H256::from(root))
+    }
+
+    fn export_snapshot(
+        &self,
+        start_key: Option<String>,
+        page_size: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<SnapshotPage> {
+        let start_key = start_key.as_deref().map(parse_hash).transpose()?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let page = self
+            .client
+            .runtime_api()
+            .list_records(at, start_key, page_size)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to list records", e))?;
+
+        Ok(page.into())
+    }
+
+    fn estimate_batch_weight(
+        &self,
+        records: u32,
+        parents: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<BatchWeightEstimate> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let weight = self
+            .client
+            .runtime_api()
+            .batch_weight(at, records, parents)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to estimate batch weight", e))?;
+
+        Ok(weight.into())
+    }
+
+    fn storage_version(&self, at: Option<Block::Hash>) -> RpcResult<u16> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let version = self
+            .client
+            .runtime_api()
+            .storage_version(at)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query storage version", e))?;
+
+        Ok(version)
+    }
+
+    fn status(&self) -> RpcResult<RegistryStatus> {
+        let info = self.client.info();
+
+        let total_records = self
+            .client
+            .runtime_api()
+            .total_records(info.best_hash)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query total records", e))?;
+
+        Ok(RegistryStatus {
+            best_block: info.best_number.unique_saturated_into(),
+            finalized_block: info.finalized_number.unique_saturated_into(),
+            total_records,
+        })
+    }
+
+    fn authority_record_count(&self, authority_id: u32, at: Option<Block::Hash>) -> RpcResult<u64> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let count = self
+            .client
+            .runtime_api()
+            .records_count_for_authority(at, authority_id)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query authority record count", e))?;
+
+        Ok(count)
+    }
+
+    fn is_aggregator(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<bool> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let authorized = self
+            .client
+            .runtime_api()
+            .is_aggregator(at, who)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to query aggregator status", e))?;
+
+        Ok(authorized)
+    }
+
+    fn records_by_submitter(
+        &self,
+        account: AccountId,
+        start: Option<String>,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<H256>> {
+        let start = start.as_deref().map(parse_hash).transpose()?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let hashes = self
+            .client
+            .runtime_api()
+            .records_by_submitter(at, account, start, limit)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to list submitter records", e))?;
+
+        Ok(hashes.into_iter().map(H256::from).collect())
+    }
+
+    fn storage_footprint(&self, at: Option<Block::Hash>) -> RpcResult<StorageFootprintJson> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let footprint = self
+            .client
+            .runtime_api()
+            .storage_footprint(at)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to estimate storage footprint", e))?;
+
+        Ok(footprint.into())
+    }
+
+    fn constants(&self, at: Option<Block::Hash>) -> RpcResult<BirthmarkConstantsJson> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let constants = self
+            .client
+            .runtime_api()
+            .config_constants(at)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to read pallet constants", e))?;
+
+        Ok(constants.into())
+    }
+
+    fn decode_record(&self, record: String, at: Option<Block::Hash>) -> RpcResult<ResolvedRecordJson> {
+        let record = decode_record(&record)?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let name = self
+            .client
+            .runtime_api()
+            .authority_name(at, record.authority_id)
+            .map_err(|e| BirthmarkRpcError::runtime("Unable to resolve authority name", e))?;
+
+        Ok((record, name).into())
+    }
+}
+
+/// Submission limits of the runtime at the queried block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BirthmarkConstantsJson {
+    /// Records accepted per batch extrinsic
+    pub max_batch_size: u32,
+    /// Longest authority name, in bytes
+    pub max_authority_id_length: u32,
+    /// Longest accepted image hash input, in bytes (64 for hex)
+    pub max_image_hash_length: u32,
+    /// Ancestors followed by provenance queries
+    pub max_provenance_depth: u32,
+}
+
+impl From<BirthmarkConstants> for BirthmarkConstantsJson {
+    fn from(constants: BirthmarkConstants) -> Self {
+        Self {
+            max_batch_size: constants.max_batch_size,
+            max_authority_id_length: constants.max_authority_id_length,
+            max_image_hash_length: constants.max_image_hash_length,
+            max_provenance_depth: constants.max_provenance_depth,
+        }
+    }
+}
+
+/// Address format and token of the chain, for wallets connecting to it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainPropertiesJson {
+    /// SS58 address prefix
+    pub ss58_prefix: u16,
+    /// Token ticker, `null` if the chain spec sets none
+    pub token_symbol: Option<String>,
+    /// Decimal places of the token's base unit, `null` if the chain spec sets none
+    pub token_decimals: Option<u8>,
+}
+
+impl ChainPropertiesJson {
+    /// Read `ss58Format`, `tokenSymbol` and `tokenDecimals` from chain spec properties
+    ///
+    /// `default_ss58_prefix` (the runtime's `SS58Prefix`) is used when the spec
+    /// does not set `ss58Format`. Values of the wrong type count as unset.
+    pub fn from_properties(
+        properties: &serde_json::Map<String, serde_json::Value>,
+        default_ss58_prefix: u16,
+    ) -> Self {
+        Self {
+            ss58_prefix: properties
+                .get("ss58Format")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|prefix| prefix.try_into().ok())
+                .unwrap_or(default_ss58_prefix),
+            token_symbol: properties
+                .get("tokenSymbol")
+                .and_then(serde_json::Value::as_str)
+                .map(Into::into),
+            token_decimals: properties
+                .get("tokenDecimals")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|decimals| decimals.try_into().ok()),
+        }
+    }
+}
+
+/// Node-level chain information, served without calling into the runtime
+#[rpc(client, server)]
+pub trait ChainPropertiesApi {
+    /// SS58 prefix and token symbol/decimals of this chain
+    #[method(name = "birthmark_chainProperties")]
+    fn chain_properties(&self) -> RpcResult<ChainPropertiesJson>;
+}
+
+/// Implementation of [`ChainPropertiesApiServer`], fixed when the node starts
+pub struct ChainProperties {
+    properties: ChainPropertiesJson,
+}
+
+impl ChainProperties {
+    /// Serve `properties` to clients
+    pub fn new(properties: ChainPropertiesJson) -> Self {
+        Self { properties }
+    }
+}
+
+impl ChainPropertiesApiServer for ChainProperties {
+    fn chain_properties(&self) -> RpcResult<ChainPropertiesJson> {
+        Ok(self.properties.clone())
+    }
+}
+
+/// Render an authority name as text, or as `0x`-prefixed hex if it is not UTF-8
+///
+/// Non-UTF-8 names only exist on chains with `ValidateUtf8AuthorityNames` disabled.
+pub fn render_authority_name(name: Vec<u8>) -> String {
+    String::from_utf8(name).unwrap_or_else(|err| sp_core::bytes::to_hex(err.as_bytes(), false))
+}
+
+/// Decode a hex (optional `0x` prefix) SCALE-encoded [`ImageRecord`]
+///
+/// Trailing bytes are rejected along with truncated input.
+pub fn decode_record(input: &str) -> Result<ImageRecord, BirthmarkRpcError> {
+    let bytes = sp_core::bytes::from_hex(input)
+        .map_err(|e| BirthmarkRpcError::InvalidHex(e.to_string()))?;
+
+    ImageRecord::decode_all(&mut &bytes[..])
+        .map_err(|e| BirthmarkRpcError::MalformedRecord(e.to_string()))
+}
+
+/// Parse a hex image hash (optional `0x` prefix) into its 32-byte binary form
+///
+/// Delegates to `birthmark_verify` so the RPC accepts exactly the hex forms the
+/// runtime does. Only hex text is accepted here: a 32-character string would
+/// otherwise be taken as a raw binary hash.
+pub fn parse_hash(input: &str) -> Result<[u8; 32], BirthmarkRpcError> {
+    let hex_len = input.strip_prefix("0x").unwrap_or(input).len();
+    if hex_len != 64 {
+        return Err(BirthmarkRpcError::HashWrongLength(hex_len / 2));
+    }
+
+    birthmark_verify::parse_hash(input.as_bytes()).map_err(|e| match e {
+        HashError::InvalidLength(len) => BirthmarkRpcError::HashWrongLength(len / 2),
+        HashError::InvalidHex => BirthmarkRpcError::InvalidHex(e.to_string()),
+    })
+}
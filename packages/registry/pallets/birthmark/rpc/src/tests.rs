@@ -0,0 +1,319 @@
+use super::*;
+use jsonrpsee::types::ErrorObjectOwned;
+
+#[test]
+fn short_hash_reports_wrong_length_code() {
+    let err = parse_hash("0123456789").unwrap_err();
+    assert_eq!(err, BirthmarkRpcError::HashWrongLength(5));
+
+    let rpc_err: ErrorObjectOwned = err.into();
+    assert_eq!(rpc_err.code(), BirthmarkRpcError::HASH_WRONG_LENGTH);
+}
+
+#[test]
+fn non_hex_hash_reports_invalid_hex_code() {
+    let err = parse_hash(&"zz".repeat(32)).unwrap_err();
+    assert_eq!(err.code(), BirthmarkRpcError::INVALID_HEX);
+}
+
+#[test]
+fn well_formed_hash_parses_with_or_without_prefix() {
+    // A well-formed but unknown hash is passed through to the runtime, which
+    // answers `null`/`false` rather than an error
+    let hex = "ab".repeat(32);
+    assert_eq!(parse_hash(&hex), Ok([0xab; 32]));
+    assert_eq!(parse_hash(&format!("0x{}", hex)), Ok([0xab; 32]));
+}
+
+#[test]
+fn raw_length_text_is_not_taken_as_binary_hash() {
+    // 32 characters are a valid binary hash on-chain, but RPC input is hex text
+    assert_eq!(parse_hash(&"a".repeat(32)), Err(BirthmarkRpcError::HashWrongLength(16)));
+}
+
+#[test]
+fn absent_record_serializes_as_null() {
+    let response: Option<ImageRecordJson> = None;
+    assert_eq!(serde_json::to_string(&response).unwrap(), "null");
+}
+
+#[test]
+fn resolved_record_flattens_with_authority_name() {
+    let record = ImageRecord {
+        image_hash: [0xab; 32],
+        submission_type: SubmissionType::Camera,
+        modification_level: 0,
+        parent_image_hash: None,
+        authority_id: 3,
+        timestamp: 12345,
+        block_number: 7,
+        validator_index: Some(0),
+        width: None,
+        height: None,
+        byte_size: None,
+        schema: 1,
+    };
+    let resolved: ResolvedRecordJson = (record, Some(b"Canon".to_vec())).into();
+
+    let json = serde_json::to_value(&resolved).unwrap();
+    assert_eq!(json["authorityId"], 3);
+    assert_eq!(json["authorityName"], "Canon");
+    assert_eq!(json["schema"], 1);
+    assert_eq!(json["imageHash"], format!("0x{}", "ab".repeat(32)));
+}
+
+#[test]
+fn non_utf8_authority_name_is_hex_encoded() {
+    assert_eq!(render_authority_name("Sony".into()), "Sony");
+    assert_eq!(render_authority_name(vec![0xc3, 0x28]), "0xc328");
+}
+
+#[test]
+fn verification_of_present_record_fills_every_field() {
+    let record = ImageRecord {
+        image_hash: [0xcd; 32],
+        submission_type: SubmissionType::Software,
+        modification_level: 2,
+        parent_image_hash: Some([0xab; 32]),
+        authority_id: 1,
+        timestamp: 999,
+        block_number: 42,
+        validator_index: None,
+        width: None,
+        height: None,
+        byte_size: None,
+        schema: 1,
+    };
+    let verification = VerificationJson::from(Some((record, Some(b"Adobe".to_vec()))));
+
+    let json = serde_json::to_value(&verification).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "exists": true,
+            "authority": "Adobe",
+            "modificationLevel": 2,
+            "blockNumber": 42,
+        })
+    );
+}
+
+#[test]
+fn verification_of_absent_record_is_not_null() {
+    let verification = VerificationJson::from(None);
+
+    let json = serde_json::to_value(&verification).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "exists": false,
+            "authority": null,
+            "modificationLevel": null,
+            "blockNumber": null,
+        })
+    );
+}
+
+#[test]
+fn level_histogram_point_names_levels() {
+    let point: LevelHistogramPoint = (4, [2, 0, 1]).into();
+    let json = serde_json::to_value(&point).unwrap();
+    assert_eq!(json, serde_json::json!({ "block": 4, "raw": 2, "validated": 0, "modified": 1 }));
+}
+
+#[test]
+fn registry_status_serializes_camel_case() {
+    let status = RegistryStatus {
+        best_block: 12,
+        finalized_block: 10,
+        total_records: 3,
+    };
+    let json = serde_json::to_value(&status).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "bestBlock": 12, "finalizedBlock": 10, "totalRecords": 3 })
+    );
+}
+
+#[test]
+fn final_snapshot_page_has_null_next_key() {
+    let page = SnapshotPage::from((Vec::new(), None));
+    let json = serde_json::to_value(&page).unwrap();
+    assert_eq!(json, serde_json::json!({ "records": [], "nextKey": null }));
+}
+
+#[test]
+fn batch_weight_estimate_serializes_both_dimensions() {
+    let estimate = BatchWeightEstimate::from((1_000_000, 512));
+    let json = serde_json::to_value(&estimate).unwrap();
+    assert_eq!(json, serde_json::json!({ "refTime": 1_000_000, "proofSize": 512 }));
+}
+
+#[test]
+fn record_with_proof_flattens_proof_fields() {
+    let response = RecordWithProof {
+        record: None,
+        proof: RecordProof {
+            block_hash: H256::repeat_byte(1),
+            state_root: H256::repeat_byte(2),
+            key: vec![0xaa].into(),
+            proof: vec![vec![0xbb].into()],
+        },
+    };
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert!(json["record"].is_null());
+    assert_eq!(json["blockHash"], format!("0x{}", "01".repeat(32)));
+    assert_eq!(json["proof"], serde_json::json!(["0xbb"]));
+}
+
+#[test]
+fn storage_footprint_serializes_camel_case() {
+    let footprint = StorageFootprintJson::from(StorageFootprint {
+        record_count: 2,
+        authority_count: 1,
+        estimated_bytes: 300,
+    });
+    let json = serde_json::to_value(&footprint).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "recordCount": 2, "authorityCount": 1, "estimatedBytes": 300 })
+    );
+}
+
+#[test]
+fn constants_serialize_camel_case() {
+    let constants = BirthmarkConstantsJson::from(BirthmarkConstants {
+        max_batch_size: 100,
+        max_authority_id_length: 100,
+        max_image_hash_length: 64,
+        max_provenance_depth: 8,
+    });
+    let json = serde_json::to_value(&constants).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "maxBatchSize": 100,
+            "maxAuthorityIdLength": 100,
+            "maxImageHashLength": 64,
+            "maxProvenanceDepth": 8,
+        })
+    );
+}
+
+#[test]
+fn encoded_record_decodes_back() {
+    let record = ImageRecord {
+        image_hash: [0xef; 32],
+        submission_type: SubmissionType::Camera,
+        modification_level: 1,
+        parent_image_hash: Some([0xab; 32]),
+        authority_id: 70_000,
+        timestamp: 1_700_000_000,
+        block_number: 12,
+        validator_index: Some(2),
+        width: Some(4000u32.into()),
+        height: Some(3000u32.into()),
+        byte_size: None,
+        schema: 1,
+    };
+    let blob = sp_core::bytes::to_hex(&codec::Encode::encode(&record), false);
+
+    assert_eq!(decode_record(&blob), Ok(record.clone()));
+    let resolved: ResolvedRecordJson = (record, Some(b"Canon".to_vec())).into();
+    let json = serde_json::to_value(&resolved).unwrap();
+    assert_eq!(json["imageHash"], format!("0x{}", "ef".repeat(32)));
+    assert_eq!(json["authorityName"], "Canon");
+}
+
+#[test]
+fn truncated_record_reports_malformed_code() {
+    let record = ImageRecord {
+        image_hash: [0x12; 32],
+        submission_type: SubmissionType::Software,
+        modification_level: 2,
+        parent_image_hash: None,
+        authority_id: 4,
+        timestamp: 5,
+        block_number: 6,
+        validator_index: None,
+        width: None,
+        height: None,
+        byte_size: None,
+        schema: 1,
+    };
+    let mut bytes = codec::Encode::encode(&record);
+    bytes.truncate(bytes.len() - 1);
+
+    let err = decode_record(&sp_core::bytes::to_hex(&bytes, false)).unwrap_err();
+    assert!(matches!(err, BirthmarkRpcError::MalformedRecord(_)));
+
+    let rpc_err: ErrorObjectOwned = err.into();
+    assert_eq!(rpc_err.code(), BirthmarkRpcError::MALFORMED_RECORD);
+}
+
+#[test]
+fn wide_range_reports_range_code() {
+    let rpc_err: ErrorObjectOwned = BirthmarkRpcError::RangeTooWide(MAX_BLOCK_RANGE).into();
+    assert_eq!(rpc_err.code(), BirthmarkRpcError::RANGE_TOO_WIDE);
+    assert_eq!(rpc_err.data().map(|data| data.get()), Some("14400"));
+}
+
+#[test]
+fn crowded_range_reports_record_limit() {
+    let rpc_err: ErrorObjectOwned = BirthmarkRpcError::TooManyRecords(MAX_MERKLE_LEAVES).into();
+    assert_eq!(rpc_err.code(), BirthmarkRpcError::TOO_MANY_RECORDS);
+    assert_eq!(rpc_err.data().map(|data| data.get()), Some("65536"));
+}
+
+#[test]
+fn chain_properties_come_from_the_chain_spec() {
+    let properties = serde_json::json!({ "ss58Format": 42, "tokenSymbol": "BMARK", "tokenDecimals": 12 });
+    let rpc = ChainProperties::new(ChainPropertiesJson::from_properties(
+        properties.as_object().unwrap(),
+        0,
+    ));
+
+    let json = serde_json::to_value(rpc.chain_properties().unwrap()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "ss58Prefix": 42, "tokenSymbol": "BMARK", "tokenDecimals": 12 })
+    );
+}
+
+#[test]
+fn chain_properties_fall_back_to_runtime_prefix() {
+    let properties = ChainPropertiesJson::from_properties(&serde_json::Map::new(), 42);
+    assert_eq!(
+        properties,
+        ChainPropertiesJson { ss58_prefix: 42, token_symbol: None, token_decimals: None }
+    );
+}
+
+#[test]
+fn batch_verification_keeps_order_of_present_and_absent_hashes() {
+    let record = ImageRecord {
+        image_hash: [0x21; 32],
+        submission_type: SubmissionType::Camera,
+        modification_level: 0,
+        parent_image_hash: None,
+        authority_id: 2,
+        timestamp: 10,
+        block_number: 3,
+        validator_index: None,
+        width: None,
+        height: None,
+        byte_size: None,
+        schema: 1,
+    };
+    let resolved = vec![None, Some((record, Some(b"Nikon".to_vec()))), None];
+    let verified: Vec<VerificationJson> = resolved.into_iter().map(Into::into).collect();
+
+    let json = serde_json::to_value(&verified).unwrap();
+    assert_eq!(json[0]["exists"], false);
+    assert!(json[0]["authority"].is_null());
+    assert_eq!(json[1]["exists"], true);
+    assert_eq!(json[1]["authority"], "Nikon");
+    assert_eq!(json[1]["modificationLevel"], 0);
+    assert_eq!(json[2]["exists"], false);
+}
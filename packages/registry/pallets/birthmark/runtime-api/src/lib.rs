@@ -0,0 +1,134 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Birthmark Runtime API
+//!
+//! Runtime API definition for the Birthmark pallet. The node's RPC layer calls into
+//! these functions to query the registry without decoding raw storage.
+
+use codec::Codec;
+use pallet_birthmark::{
+    BirthmarkConstants, ImageRecord, RecordStatus, StatsSnapshot, StorageFootprint, SubmissionType,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Query interface exposed by runtimes that include the Birthmark pallet
+    pub trait BirthmarkApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Fetch an image record by its binary SHA-256 hash
+        fn get_image_record(image_hash: [u8; 32]) -> Option<ImageRecord>;
+
+        /// Block in which an image hash was recorded, if it is present
+        fn record_block(image_hash: [u8; 32]) -> Option<u32>;
+
+        /// Fetch an image record together with its authority's name
+        fn get_record_with_authority(image_hash: [u8; 32]) -> Option<(ImageRecord, Option<Vec<u8>>)>;
+
+        /// Provenance chain from a record to its root, each with its authority name
+        ///
+        /// Ordered child first and capped at `MaxProvenanceDepth`.
+        fn provenance_report(image_hash: [u8; 32]) -> Vec<(ImageRecord, Option<Vec<u8>>)>;
+
+        /// Whether a hash is present, tombstoned by a takedown, or absent
+        fn record_status(image_hash: [u8; 32]) -> RecordStatus;
+
+        /// Storage key of the `ImageRecords` entry for an image hash
+        ///
+        /// The node reads a storage proof for this key to serve light clients.
+        fn record_storage_key(image_hash: [u8; 32]) -> Vec<u8>;
+
+        /// On-chain storage version of the Birthmark pallet
+        fn storage_version() -> u16;
+
+        /// Total number of records currently stored
+        fn total_records() -> u64;
+
+        /// Number of records attributed to an authority
+        fn records_count_for_authority(authority_id: u32) -> u64;
+
+        /// Positional existence check for many hashes, capped at `MaxBulkQuerySize`
+        fn images_exist(image_hashes: Vec<[u8; 32]>) -> Vec<bool>;
+
+        /// Positional record lookup for many hashes, capped at `MaxBulkQuerySize`
+        fn get_records(image_hashes: Vec<[u8; 32]>) -> Vec<Option<ImageRecord>>;
+
+        /// Positional record lookup with authority names, capped at `MaxBulkQuerySize`
+        fn get_records_with_authority(image_hashes: Vec<[u8; 32]>) -> Vec<Option<(ImageRecord, Option<Vec<u8>>)>>;
+
+        /// Statistics snapshot taken at a block, if any
+        fn get_snapshot(block: u32) -> Option<StatsSnapshot>;
+
+        /// All statistics snapshots, oldest first
+        fn list_snapshots() -> Vec<(u32, StatsSnapshot)>;
+
+        /// `(block, [raw, validated, modified])` for each snapshot in `from_block..=to_block`
+        fn level_histogram(from_block: u32, to_block: u32) -> Vec<(u32, [u64; 3])>;
+
+        /// Record hashes stored in blocks `from..=to` from `start`, at most `limit` (capped at `MaxBulkQuerySize`)
+        ///
+        /// Returns the page and the `(block, last hash)` cursor to resume from, or
+        /// `None` if the range spans more than `MAX_BLOCK_RANGE` blocks.
+        fn records_in_range(
+            from: u32,
+            to: u32,
+            start: Option<(u32, Option<[u8; 32]>)>,
+            limit: u32,
+        ) -> Option<(Vec<[u8; 32]>, Option<(u32, Option<[u8; 32]>)>)>;
+
+        /// BlakeTwo256 Merkle root of the sorted record hashes stored in blocks `from..=to`
+        ///
+        /// `None` if the range spans more than `MAX_BLOCK_RANGE` blocks or holds
+        /// more than `MAX_MERKLE_LEAVES` records.
+        fn records_merkle_root(from: u32, to: u32) -> Option<[u8; 32]>;
+
+        /// Record hashes of one submission type after `start`, at most `limit` (capped at `MaxBulkQuerySize`)
+        fn records_by_submission_type(kind: SubmissionType, start: Option<[u8; 32]>, limit: u32) -> Vec<[u8; 32]>;
+
+        /// Records after `start` in storage order, at most `limit`, plus the key to resume from
+        fn list_records(start: Option<[u8; 32]>, limit: u32) -> (Vec<ImageRecord>, Option<[u8; 32]>);
+
+        /// `(ref_time, proof_size)` charged for a batch of `records` entries, `parents` of which name a parent
+        fn batch_weight(records: u32, parents: u32) -> (u64, u64);
+
+        /// Human-readable label of a modification level (e.g. "raw"), if one is set
+        fn modification_label(level: u8) -> Option<Vec<u8>>;
+
+        /// Authority IDs seeded at genesis, in registration order
+        fn genesis_authorities() -> Vec<u32>;
+
+        /// Hashes of the `n` most recently stored records, newest first
+        fn recent_records(n: u32) -> Vec<[u8; 32]>;
+
+        /// 0-100 trust score of an authority, `None` if it is not registered
+        fn authority_trust(id: u32) -> Option<u8>;
+
+        /// Number of accepted records submitted by an account
+        fn submissions_by(who: AccountId) -> u64;
+
+        /// Whether an account may submit records (allowlist or identity, per runtime config)
+        fn is_aggregator(who: AccountId) -> bool;
+
+        /// Hashes of records submitted by `who` after `start`, at most `limit` (capped at `MaxBulkQuerySize`)
+        fn records_by_submitter(who: AccountId, start: Option<[u8; 32]>, limit: u32) -> Vec<[u8; 32]>;
+
+        /// `(reason code, count)` of failed submissions, where the code is the pallet error index
+        fn rejection_stats() -> Vec<(u8, u64)>;
+
+        /// Hashes declared the same image as `image_hash`: its own link first, then records linking to it
+        fn linked_records(image_hash: [u8; 32]) -> Vec<[u8; 32]>;
+
+        /// Record and authority counts with an upper-bound estimate of their encoded size
+        fn storage_footprint() -> StorageFootprint;
+
+        /// Names the authority has had, oldest first; empty if it is not registered
+        fn authority_name_history(authority_id: u32) -> Vec<Vec<u8>>;
+
+        /// Batch, authority name, image hash and provenance limits of this runtime
+        fn config_constants() -> BirthmarkConstants;
+
+        /// Current name of an authority, if it is registered
+        fn authority_name(authority_id: u32) -> Option<Vec<u8>>;
+    }
+}
@@ -0,0 +1,265 @@
+//! Benchmarks for the Birthmark pallet.
+//!
+//! The query benchmarks are never dispatched; they back the runtime API and so
+//! the RPC endpoints. Their storage-read cost is what RPC operators need in order
+//! to size `MaxProvenanceDepth`, `MaxBulkQuerySize` and any node-side request limits.
+//!
+//! The remaining benchmarks back the functions in [`crate::weights`].
+//!
+//! Run with:
+//!
+//! ```text
+//! birthmark-node benchmark pallet --chain dev --pallet pallet_birthmark --extrinsic '*'
+//! ```
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::BoundedVec;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{One, Saturating};
+use sp_std::vec::Vec;
+
+/// Distinct image hash for the `i`-th seeded record
+fn seed_hash(i: u32) -> [u8; 32] {
+    let mut hash = [0xbeu8; 32];
+    hash[..4].copy_from_slice(&i.to_le_bytes());
+    hash
+}
+
+/// Authority name the submission benchmarks attribute records to
+const BENCH_AUTHORITY: &[u8] = b"BENCHMARK";
+
+/// Allow the whitelisted caller to submit records
+fn aggregator<T: Config>() -> T::AccountId {
+    let caller: T::AccountId = whitelisted_caller();
+    AuthorizedAggregators::<T>::insert(&caller, ());
+    caller
+}
+
+/// Store `count` records, each the parent of the next, and return the newest hash
+///
+/// Records are written directly to `ImageRecords`; indexes and counters are left
+/// alone since the benchmarked queries do not read them.
+fn seed_chain<T: Config>(count: u32) -> [u8; 32] {
+    let mut parent = None;
+    for i in 0..count {
+        let image_hash = seed_hash(i);
+        let record = ImageRecord {
+            image_hash,
+            submission_type: SubmissionType::Software,
+            modification_level: if parent.is_some() { 2 } else { 0 },
+            parent_image_hash: parent,
+            authority_id: 0,
+            timestamp: 0,
+            block_number: 0,
+            validator_index: None,
+            width: None,
+            height: None,
+            byte_size: None,
+            schema: RECORD_SCHEMA,
+        };
+        ImageRecords::<T>::insert(image_hash, record);
+        parent = Some(image_hash);
+    }
+    parent.unwrap_or_default()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// One `ImageRecords` read per ancestor walked
+    #[benchmark]
+    fn provenance_chain(d: Linear<1, { T::MaxProvenanceDepth::get() }>) {
+        let leaf = seed_chain::<T>(d);
+        let chain: Vec<ImageRecord>;
+
+        #[block]
+        {
+            chain = Pallet::<T>::provenance_chain(&leaf);
+        }
+
+        assert_eq!(chain.len(), d as usize);
+    }
+
+    /// One `ImageRecords` iteration step per record, plus the look-ahead entry
+    #[benchmark]
+    fn list_records(l: Linear<1, { T::MaxBulkQuerySize::get() }>) {
+        // One record more than requested so the page has a continuation key
+        seed_chain::<T>(l + 1);
+        let page: (Vec<ImageRecord>, Option<[u8; 32]>);
+
+        #[block]
+        {
+            page = Pallet::<T>::list_records(None, l);
+        }
+
+        assert_eq!(page.0.len(), l as usize);
+        assert!(page.1.is_some());
+    }
+
+    /// Record insertion and index updates, resolving an already registered authority
+    #[benchmark]
+    fn submit_image_record_existing_authority() {
+        let caller = aggregator::<T>();
+        Pallet::<T>::register_or_get_authority(BENCH_AUTHORITY.to_vec()).unwrap();
+        let hash = seed_hash(0);
+
+        #[extrinsic_call]
+        submit_image_record(
+            RawOrigin::Signed(caller),
+            hash.to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            BENCH_AUTHORITY.to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        assert!(ImageRecords::<T>::contains_key(hash));
+    }
+
+    /// As above, plus registering the authority named by the submission
+    #[benchmark]
+    fn submit_image_record_new_authority() {
+        let caller = aggregator::<T>();
+        let next_id = NextAuthorityId::<T>::get();
+        let hash = seed_hash(0);
+
+        #[extrinsic_call]
+        submit_image_record(
+            RawOrigin::Signed(caller),
+            hash.to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            BENCH_AUTHORITY.to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        assert!(ImageRecords::<T>::contains_key(hash));
+        assert!(NextAuthorityId::<T>::get() > next_id);
+    }
+
+    /// `n` records without a parent and `p` records naming one, which adds the
+    /// parent lookup and child count
+    #[benchmark]
+    fn submit_image_batch(
+        n: Linear<0, { T::MaxBatchSize::get() / 2 }>,
+        p: Linear<1, { T::MaxBatchSize::get() / 2 }>,
+    ) {
+        let caller = aggregator::<T>();
+        Pallet::<T>::register_or_get_authority(BENCH_AUTHORITY.to_vec()).unwrap();
+        // Parents take `seed_hash(0..p)`; the batch uses the hashes after them
+        seed_chain::<T>(p);
+        let records: Vec<BatchRecordOf<T>> = (0..n + p)
+            .map(|i| {
+                let parent = (i < p).then(|| seed_hash(i).to_vec().try_into().unwrap());
+                BatchRecord {
+                    image_hash: seed_hash(p + i).to_vec().try_into().unwrap(),
+                    submission_type: SubmissionType::Software,
+                    modification_level: if parent.is_some() { 2 } else { 0 },
+                    parent,
+                    authority: BENCH_AUTHORITY.to_vec().try_into().unwrap(),
+                }
+            })
+            .collect();
+        let records = BoundedVec::try_from(records).unwrap();
+
+        #[extrinsic_call]
+        submit_image_batch(RawOrigin::Signed(caller), records);
+
+        assert_eq!(ImageRecords::<T>::iter().count() as u32, n + 2 * p);
+    }
+
+    /// One `TombstonesByBlock` entry and one `Tombstones` entry removed per tombstone
+    #[benchmark]
+    fn prune_tombstones(n: Linear<1, { T::MaxPrunedPerBlock::get() }>) {
+        let removed_at: BlockNumberFor<T> = One::one();
+        for i in 0..n {
+            let hash = seed_hash(i);
+            Tombstones::<T>::insert(hash, Tombstone { removed_at, reason_code: 0 });
+            TombstonesByBlock::<T>::insert(removed_at, hash, ());
+        }
+        TombstonePruneCursor::<T>::put(removed_at);
+        let now = T::TombstoneRetention::get().saturating_add(removed_at).saturating_add(One::one());
+
+        #[block]
+        {
+            Pallet::<T>::prune_tombstones(now);
+        }
+
+        assert_eq!(Tombstones::<T>::iter().count(), 0);
+    }
+
+    /// One `RecordsByBlock` entry removed per record indexed in an expired block
+    #[benchmark]
+    fn prune_block_index(n: Linear<1, { T::MaxBlockIndexPrunedPerBlock::get() }>) {
+        let block: BlockNumberFor<T> = One::one();
+        for i in 0..n {
+            RecordsByBlock::<T>::insert(block, seed_hash(i), ());
+        }
+        BlockIndexPruneCursor::<T>::put(block);
+        let now = T::BlockIndexRetention::get().saturating_add(block).saturating_add(One::one());
+
+        #[block]
+        {
+            Pallet::<T>::prune_block_index(now);
+        }
+
+        assert_eq!(RecordsByBlock::<T>::iter_prefix(block).count(), 0);
+    }
+
+    /// Validation, name lookup and registration per name; none registered before
+    #[benchmark]
+    fn register_authorities_batch(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let next_id = NextAuthorityId::<T>::get();
+        // Distinct printable names: "BENCH" followed by the nibbles of `i` as letters
+        let names: Vec<BoundedVec<u8, T::MaxAuthorityIdLength>> = (0..n)
+            .map(|i| {
+                let mut name = b"BENCH".to_vec();
+                for byte in i.to_be_bytes() {
+                    name.extend([b'A' + (byte >> 4), b'A' + (byte & 0xf)]);
+                }
+                name.try_into().unwrap()
+            })
+            .collect();
+        let names = BoundedVec::try_from(names).unwrap();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, names);
+
+        assert_eq!(NextAuthorityId::<T>::get(), next_id.max(T::FirstUserAuthorityId::get()) + n);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{new_test_ext, MaxProvenanceDepth, Test};
+
+    #[test]
+    fn provenance_benchmark_scales_with_depth() {
+        new_test_ext().execute_with(|| {
+            let max = MaxProvenanceDepth::get();
+            for depth in [1, max / 2, max] {
+                let leaf = seed_chain::<Test>(depth);
+                assert_eq!(Pallet::<Test>::provenance_chain(&leaf).len(), depth as usize);
+            }
+
+            // Longer chains are cut off, so the top of the range bounds the cost
+            let leaf = seed_chain::<Test>(max + 4);
+            assert_eq!(Pallet::<Test>::provenance_chain(&leaf).len(), max as usize);
+        });
+    }
+}
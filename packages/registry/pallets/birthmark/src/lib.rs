@@ -19,11 +19,71 @@
 //! ### Dispatchable Functions
 //!
 //! - `submit_image_record` - Submit a new image authentication record (restricted)
+//! - `submit_image_record_idempotent` - Like `submit_image_record`, but an identical
+//!   resubmission succeeds as a no-op (for retrying aggregators)
+//! - `submit_image_record_inferred` - Like `submit_image_record`, with the submission
+//!   type optional and inferred from the modification level
+//! - `force_submit_image_record` - Submit a record in the operational class (governance)
 //! - `submit_image_batch` - Submit multiple records in a single transaction (gas efficient)
+//! - `submit_image_batch_with_refs` - Batch submission naming authorities by ID or name
+//! - `register_authority` - Pre-register an authority name (governance)
+//! - `register_authorities_batch` - Register many authority names at once (governance)
+//! - `force_register_authority` - Register a system authority under a reserved ID (governance)
+//! - `deactivate_authority` - Block new submissions for an authority (governance)
+//! - `reactivate_authority` - Re-enable a previously deactivated authority (governance)
+//! - `prune_authority` - Delete an authority with no records (governance)
+//! - `amend_record` - Correct a record's level or type within `MutabilityWindow` (submitter)
+//! - `link_alternate_hash` - Declare a record the same image as another (e.g. other algorithm)
+//! - `set_authority_quota` - Cap or uncap an authority's record count (governance)
+//! - `rename_authority` - Change an authority's name, keeping its ID and records (governance)
+//! - `seal_authority` - Permanently freeze an authority's records (governance)
+//! - `set_activation_block` - Gate submissions until a given block (root)
+//! - `reclassify_record` - Correct a record's modification level after review (removal origin)
+//! - `reassign_authority` - Re-attribute a record to another authority (governance)
+//! - `snapshot_statistics` - Record current counters for transparency reporting (root)
+//! - `remove_record` - Take down a record, leaving a tombstone (removal origin)
+//! - `set_aggregators` - Replace the authorized aggregator set (governance)
+//! - `set_modification_label` - Relabel a modification level (governance)
+//! - `set_authority_verified` - Mark or unmark an authority as verified (governance)
+//! - `set_require_verified_authority` - Only accept verified authorities (governance)
+//!
+//! ### Hooks
+//!
+//! - `on_initialize` - Prune tombstones older than `TombstoneRetention` and block-index
+//!   entries older than `BlockIndexRetention` (both bounded per block)
 //!
 //! ### Public Functions
 //!
 //! - `get_image_record` - Query storage for an image record by hash
+//! - `record_block` - Block a record was stored in, if present
+//! - `genesis_authorities` - Authority IDs seeded by the genesis config
+//! - `is_authority_sealed` - Whether an authority's records are frozen
+//! - `recent_records` - Newest record hashes, newest first (for "latest" feeds)
+//! - `authority_trust` - 0-100 score from verification, activity and record count
+//! - `modification_label` - Human-readable label of a modification level
+//! - `resubmission_conflict` - Detect a duplicate submission claiming a different authority
+//! - `note_resubmission` - Emit `ConflictingResubmission` for a rejected conflicting duplicate
+//! - `get_record_with_authority` - Query a record and its authority name in one call
+//! - `provenance_report` - Walk a record's ancestors with authority names resolved
+//! - `images_exist` - Check many hashes in one call (results are positional)
+//! - `get_records` - Fetch many records in one call (results are positional)
+//! - `get_records_with_authority` - Fetch many records with authority names (positional)
+//! - `authority_topic` - Event topic under which an authority's submissions are indexed
+//! - `record_status` - Distinguish present, tombstoned and never-registered hashes
+//! - `level_histogram` - Per-level counts from snapshots in a block range
+//! - `submissions_by` - Number of accepted records submitted by an account
+//! - `linked_records` - Records declared the same image as a hash, both directions
+//! - `authority_name_history` - Names an authority has had, current one last
+//! - `config_constants` - Batch, name, hash and provenance limits for clients
+//! - `storage_footprint` - Record/authority counts and estimated state size (capacity planning)
+//! - `note_rejection` / `rejection_stats` - Count failed submissions by error (for monitoring)
+//! - `records_in_range` - Record hashes stored within a block range (paged)
+//! - `records_merkle_root` - Merkle root of the records in a block range (for anchoring)
+//! - `list_records` - Page through all records in storage order (for mirrors)
+//! - `records_by_submission_type` - Record hashes of camera or software submissions (paged)
+//! - `records_with_prefix` - Record hashes in one first-byte shard (paged, if indexed)
+//! - `records_by_submitter` - Record hashes submitted by one account (paged, for audits)
+//! - `proof::verify_record_proof` - Check a storage proof for a record against a state root (std)
 //!
 //! ## Privacy Architecture
 //!
@@ -33,15 +93,58 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+#[cfg(feature = "std")]
+pub mod proof;
+pub mod weights;
+
 #[cfg(test)]
 mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use crate::weights::WeightInfo;
+    use codec::{Compact, HasCompact};
+    use frame_support::{pallet_prelude::*, traits::FindAuthor};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::UniqueSaturatedInto;
-    use sp_std::vec::Vec;
+    use sp_runtime::{
+        traits::{BlakeTwo256, Hash as HashT, One, Saturating, UniqueSaturatedInto, Zero},
+        ModuleError, PerThing, Percent,
+    };
+    use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+    /// Source of on-chain identity judgements for submitter authorization
+    ///
+    /// Runtimes with `pallet_identity` implement this by checking the account's
+    /// registration for a `Reasonable` or `KnownGood` judgement. `()` knows no
+    /// identities, so nobody passes.
+    pub trait IdentityVerifier<AccountId> {
+        /// Whether `who` holds an identity judged good by a registrar
+        fn has_good_identity(who: &AccountId) -> bool;
+    }
+
+    impl<AccountId> IdentityVerifier<AccountId> for () {
+        fn has_good_identity(_who: &AccountId) -> bool {
+            false
+        }
+    }
+
+    /// Additional acceptance logic applied to every record before it is stored
+    ///
+    /// Lets deployments add checks (e.g. sanctions screening of the authority)
+    /// without forking the pallet. `()` accepts every record.
+    pub trait ValidateRecord<T: Config> {
+        /// Return an error to reject `record` submitted by `submitter`
+        fn validate(submitter: &T::AccountId, record: &ImageRecord) -> Result<(), DispatchError>;
+    }
+
+    impl<T: Config> ValidateRecord<T> for () {
+        fn validate(_submitter: &T::AccountId, _record: &ImageRecord) -> Result<(), DispatchError> {
+            Ok(())
+        }
+    }
 
     /// The pallet's configuration trait.
     #[pallet::config]
@@ -53,12 +156,244 @@ pub mod pallet {
         #[pallet::constant]
         type MaxAuthorityIdLength: Get<u32>;
 
-        /// Maximum length for image hash (SHA-256 = 64 hex chars)
+        /// Minimum length for authority names (after normalization)
+        #[pallet::constant]
+        type MinAuthorityNameLength: Get<u32>;
+
+        /// Maximum length for image hash input (SHA-256 = 64 hex chars, 66 with a `0x` prefix)
         #[pallet::constant]
         type MaxImageHashLength: Get<u32>;
+
+        /// Maximum number of authorities that may ever be registered
+        ///
+        /// Keeps the registry curated; values above `AuthorityId::MAX` have no extra effect.
+        /// Deactivated authorities still count towards the cap.
+        #[pallet::constant]
+        type MaxAuthorities: Get<u32>;
+
+        /// Share of the authority cap at which `AuthorityCapacityLow` is emitted
+        ///
+        /// The cap is `MaxAuthorities` or `AuthorityId::MAX`, whichever is lower. The
+        /// event fires once, when registration first reaches the threshold. Zero
+        /// disables it.
+        #[pallet::constant]
+        type AuthorityCapacityWarning: Get<Percent>;
+
+        /// Lowest authority ID handed out by registration
+        ///
+        /// IDs below it are reserved for governance-curated system authorities and
+        /// can only be assigned through `force_register_authority`. Reserved IDs
+        /// still count towards `MaxAuthorities`.
+        #[pallet::constant]
+        type FirstUserAuthorityId: Get<AuthorityId>;
+
+        /// Whether submissions may create authorities for unknown names
+        ///
+        /// When `false`, only names registered through `register_authority` are
+        /// accepted, keeping the registry curated by governance. Ignored (treated
+        /// as `false`) on chains in `ProductionMode`.
+        #[pallet::constant]
+        type AllowAuthorityAutoRegistration: Get<bool>;
+
+        /// Whether authority names are normalized before lookup and registration
+        ///
+        /// When enabled, leading/trailing ASCII whitespace is trimmed and ASCII
+        /// letters are lowercased, so "Canon" and " canon " share one authority ID.
+        /// Normalization runs before length validation. Names registered before the
+        /// flag was enabled are not rewritten.
+        #[pallet::constant]
+        type NormalizeAuthorityNames: Get<bool>;
+
+        /// Whether authority names must be valid UTF-8
+        ///
+        /// Public deployments should enable this: names are shown to people, and
+        /// RPC clients can only render invalid bytes as hex. Disable it only when
+        /// authority names are opaque binary identifiers; such names are stored
+        /// as-is. Control characters are rejected in either mode.
+        #[pallet::constant]
+        type ValidateUtf8AuthorityNames: Get<bool>;
+
+        /// Whether edited records (modification level 1 or 2) must name a parent
+        ///
+        /// An edit implies a source image, so strict deployments can reject
+        /// edited records that do not link to one. Raw records never need a parent.
+        /// Always enforced on chains in `ProductionMode`.
+        #[pallet::constant]
+        type RequireParentForEdits: Get<bool>;
+
+        /// Maximum number of records returned when walking a provenance chain
+        #[pallet::constant]
+        type MaxProvenanceDepth: Get<u32>;
+
+        /// Maximum number of records that may name the same parent
+        ///
+        /// Bounds the fan-out below a single popular image, so per-parent
+        /// listings stay bounded however often it is derived from.
+        #[pallet::constant]
+        type MaxChildrenPerParent: Get<u32>;
+
+        /// Maximum number of records in a single batch submission
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Whether batches also emit `BatchDetail` listing every stored hash
+        ///
+        /// Lets indexers pick up batch contents from events alone, at the cost of
+        /// up to `MaxBatchSize` hashes of event data per batch.
+        #[pallet::constant]
+        type EmitBatchDetail: Get<bool>;
+
+        /// Maximum number of hashes answered by a single bulk query
+        #[pallet::constant]
+        type MaxBulkQuerySize: Get<u32>;
+
+        /// Whether record hashes are also indexed by their first byte
+        ///
+        /// The index lets auditors sample one of 256 shards without iterating
+        /// every record, at the cost of one extra write per submission. Enabling
+        /// it on a live chain only indexes new records; existing ones are
+        /// backfilled by the `v8` migration.
+        #[pallet::constant]
+        type IndexRecordsByHashPrefix: Get<bool>;
+
+        /// Maximum length in bytes of a free-form takedown reason
+        #[pallet::constant]
+        type MaxReasonLength: Get<u32>;
+
+        /// Number of blocks a tombstone is kept before `on_initialize` prunes it
+        ///
+        /// Once pruned, the hash is reported as absent and may be submitted again.
+        #[pallet::constant]
+        type TombstoneRetention: Get<BlockNumberFor<Self>>;
+
+        /// Most expired tombstones removed by `on_initialize` in a single block
+        ///
+        /// Each removal costs two storage writes and each removal block without
+        /// tombstones uses up one unit as well, so this bounds the hook's share of
+        /// the block weight. Leftovers are pruned in later blocks.
+        #[pallet::constant]
+        type MaxPrunedPerBlock: Get<u32>;
+
+        /// Number of blocks after storage during which the submitter may amend a record
+        ///
+        /// Gives aggregators a short window to fix a wrong modification level or
+        /// submission type. Afterwards the record is immutable except through
+        /// `RemovalOrigin`.
+        #[pallet::constant]
+        type MutabilityWindow: Get<BlockNumberFor<Self>>;
+
+        /// Minimum number of blocks between two submissions from the same account
+        ///
+        /// Spreads an aggregator's load across blocks. A batch counts as one
+        /// submission. Zero disables the cooldown.
+        #[pallet::constant]
+        type SubmissionCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Number of recent blocks kept in the `RecordsByBlock` index
+        ///
+        /// Older index entries are pruned by `on_initialize`; the records stay
+        /// queryable by hash, but block and range queries no longer list them.
+        #[pallet::constant]
+        type BlockIndexRetention: Get<BlockNumberFor<Self>>;
+
+        /// Most block-index entries removed by `on_initialize` in a single block
+        ///
+        /// Each visited block without entries uses up one unit as well, so this
+        /// bounds the hook's share of the block weight. Leftovers are pruned in
+        /// later blocks.
+        #[pallet::constant]
+        type MaxBlockIndexPrunedPerBlock: Get<u32>;
+
+        /// Resolves the block author's index in the validator set from pre-runtime digests
+        ///
+        /// Set to `Aura` in the runtime; `()` records no validator.
+        type FindAuthor: FindAuthor<u32>;
+
+        /// Whether submitters are authorized by identity judgement instead of the allowlist
+        ///
+        /// When `true`, any account `IdentityVerifier` vouches for may submit and
+        /// `AuthorizedAggregators` is ignored. Deployments without an identity
+        /// pallet keep this `false`.
+        #[pallet::constant]
+        type UseIdentityAuthorization: Get<bool>;
+
+        /// Identity judgements consulted when `UseIdentityAuthorization` is on
+        type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+
+        /// External acceptance check run on each record before insertion
+        type RecordValidator: ValidateRecord<Self>;
+
+        /// Origin allowed to manage the authority registry (root or governance council)
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to remove or reclassify records
+        ///
+        /// Separate from `GovernanceOrigin` so coalitions can demand a stronger
+        /// threshold (e.g. a two-thirds council vote) for editing the record set.
+        type RemovalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weights of the benchmarked code paths
+        type WeightInfo: WeightInfo;
     }
 
+    /// Upper bound on the authorized aggregator set
+    ///
+    /// Keeps `set_aggregators` able to clear the previous set in one bounded pass.
+    pub type MaxAggregators = ConstU32<100>;
+
+    /// Upper bound on a modification level label, in bytes
+    pub type MaxLabelLength = ConstU32<32>;
+
+    /// Labels written at genesis for levels 0, 1 and 2
+    pub const DEFAULT_MODIFICATION_LABELS: [&[u8]; 3] = [b"raw", b"validated", b"modified"];
+
+    /// Upper bound on the authorities seeded at genesis
+    pub type MaxGenesisAuthorities = ConstU32<100>;
+
+    /// Number of names kept per authority in `AuthorityNameHistory`
+    pub type MaxNameHistory = ConstU32<8>;
+
+    /// Number of newest record hashes kept in `RecentRecords`
+    pub type MaxRecentRecords = ConstU32<256>;
+
+    /// Registry index of an authority
+    ///
+    /// Widened from `u16` in storage version 10 so the registry can outgrow
+    /// 65,535 authorities; records store it compact-encoded.
+    pub type AuthorityId = u32;
+
+    /// Widest block range (`to - from`) accepted by `records_in_range` and `records_merkle_root`
+    pub const MAX_BLOCK_RANGE: u32 = 14_400;
+
+    /// Most record hashes `records_merkle_root` will take into one root
+    ///
+    /// Bounds the memory of the call: the hashes are held and sorted in full.
+    pub const MAX_MERKLE_LEAVES: u32 = 65_536;
+
+    /// Prefix hashed with a record hash to form a `records_merkle_root` leaf
+    pub const MERKLE_LEAF_TAG: u8 = 0x00;
+
+    /// Prefix hashed with two child nodes to form a `records_merkle_root` parent
+    pub const MERKLE_NODE_TAG: u8 = 0x01;
+
+    /// Most blocks `records_in_range` visits in one call before returning a cursor
+    pub const MAX_BLOCKS_PER_RANGE_PAGE: u32 = 1_000;
+
+    /// Position to resume `records_in_range` from: a block and the last hash
+    /// already returned from it, or `None` to start at the beginning of the block
+    pub type RangeCursor<BlockNumber> = (BlockNumber, Option<[u8; 32]>);
+
+    /// Schema of newly stored `ImageRecord`s
+    ///
+    /// Bump when the meaning of a record field changes, so clients can tell how
+    /// to read optional fields. Records stored before the field existed carry 0.
+    pub const RECORD_SCHEMA: u8 = 1;
+
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Submission type for image records
@@ -80,8 +415,10 @@ pub mod pallet {
         pub modification_level: u8,
         /// Hash of parent image (for provenance chain)
         pub parent_image_hash: Option<[u8; 32]>,
-        /// Authority identifier (lookup table index - 2 bytes instead of variable string)
-        pub authority_id: u16,
+        /// Authority identifier (lookup table index instead of variable string)
+        /// Using compact encoding: 1-2 bytes for the first 16,384 authorities
+        #[codec(compact)]
+        pub authority_id: AuthorityId,
         /// Timestamp when record was submitted to blockchain (NOT capture time)
         /// Using compact encoding: typically 2-3 bytes instead of 8
         #[codec(compact)]
@@ -90,11 +427,160 @@ pub mod pallet {
         /// Using compact encoding: typically 2-3 bytes instead of 4
         #[codec(compact)]
         pub block_number: u32,
+        /// Index of the validator that authored the record's block (informational)
+        /// `None` for records stored before attribution or without a slot digest
+        pub validator_index: Option<u32>,
+        /// Image width in pixels (advisory, not part of provenance)
+        pub width: Option<Compact<u32>>,
+        /// Image height in pixels (advisory, not part of provenance)
+        pub height: Option<Compact<u32>>,
+        /// File size in bytes (advisory, not part of provenance)
+        pub byte_size: Option<Compact<u32>>,
+        /// Record schema at insert time (`RECORD_SCHEMA`; 0 for migrated records)
+        pub schema: u8,
+    }
+
+    /// Point-in-time registry statistics for transparency reporting
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct StatsSnapshot {
+        /// Total records stored at snapshot time
+        pub total_records: u64,
+        /// Records per modification level: [raw, validated, modified]
+        pub records_per_level: [u64; 3],
+    }
+
+    /// Estimated size of the registry's state, for capacity planning
+    ///
+    /// `estimated_bytes` is an upper bound on the values of `ImageRecords` and
+    /// `AuthorityRegistry` from `MaxEncodedLen`; keys and secondary indexes are
+    /// not included.
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct StorageFootprint {
+        /// Records currently stored
+        pub record_count: u64,
+        /// Registered authorities, active or not
+        pub authority_count: u32,
+        /// Estimated bytes used by record and authority values
+        pub estimated_bytes: u64,
+    }
+
+    /// Submission limits configured in the runtime, for client discovery
+    ///
+    /// Lets SDKs size batches and validate input against the chain they talk
+    /// to instead of hard-coded values that go stale after an upgrade.
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct BirthmarkConstants {
+        /// `MaxBatchSize`: records accepted per batch extrinsic
+        pub max_batch_size: u32,
+        /// `MaxAuthorityIdLength`: longest authority name, in bytes
+        pub max_authority_id_length: u32,
+        /// `MaxImageHashLength`: longest accepted image hash input, in bytes
+        pub max_image_hash_length: u32,
+        /// `MaxProvenanceDepth`: ancestors followed by provenance queries
+        pub max_provenance_depth: u32,
+    }
+
+    /// Marker left behind when a record is removed for a legal takedown
+    ///
+    /// Keeps proof that the hash was once registered without retaining the record.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Tombstone<BlockNumber: HasCompact + MaxEncodedLen> {
+        /// Block in which the record was removed
+        #[codec(compact)]
+        pub removed_at: BlockNumber,
+        /// Governance-defined takedown reason
+        pub reason_code: u8,
+    }
+
+    /// Lifecycle state of an image hash
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RecordStatus {
+        /// A record is stored under the hash
+        Present,
+        /// The record was removed; only its tombstone remains
+        Tombstoned,
+        /// The hash was never registered
+        Absent,
+    }
+
+    /// A single entry of `submit_image_batch`
+    ///
+    /// Hash fields accept binary (32 bytes) or hex (64 chars) like `submit_image_record`.
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxHashLength, MaxNameLength))]
+    #[codec(mel_bound())]
+    pub struct BatchRecord<MaxHashLength: Get<u32>, MaxNameLength: Get<u32>> {
+        /// SHA-256 hash of the image
+        pub image_hash: BoundedVec<u8, MaxHashLength>,
+        /// Type of submission (camera or software)
+        pub submission_type: SubmissionType,
+        /// Modification level: 0 = raw sensor, 1 = validated/minor edits, 2 = modified
+        pub modification_level: u8,
+        /// Hash of the parent image, which must already be stored
+        pub parent: Option<BoundedVec<u8, MaxHashLength>>,
+        /// Authority name, registered on first use
+        pub authority: BoundedVec<u8, MaxNameLength>,
+    }
+
+    /// [`BatchRecord`] with the bounds of a pallet configuration
+    pub type BatchRecordOf<T> =
+        BatchRecord<<T as Config>::MaxImageHashLength, <T as Config>::MaxAuthorityIdLength>;
+
+    /// A single entry of `submit_image_batch_with_refs`
+    ///
+    /// Like [`BatchRecord`], but the authority is given by registry ID or by name.
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(MaxHashLength))]
+    pub struct BatchRecordWithRef<MaxHashLength: Get<u32>> {
+        /// SHA-256 hash of the image
+        pub image_hash: BoundedVec<u8, MaxHashLength>,
+        /// Type of submission (camera or software)
+        pub submission_type: SubmissionType,
+        /// Modification level: 0 = raw sensor, 1 = validated/minor edits, 2 = modified
+        pub modification_level: u8,
+        /// Hash of the parent image, which must already be stored
+        pub parent: Option<BoundedVec<u8, MaxHashLength>>,
+        /// Authority ID, or name registered on first use
+        pub authority: AuthorityRef,
+    }
+
+    /// [`BatchRecordWithRef`] with the bounds of a pallet configuration
+    pub type BatchRecordWithRefOf<T> = BatchRecordWithRef<<T as Config>::MaxImageHashLength>;
+
+    /// Reference to an authority in batch submissions
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo)]
+    pub enum AuthorityRef {
+        /// Existing registry ID (must be registered and active)
+        Id(AuthorityId),
+        /// Authority name, registered on first use
+        Name(Vec<u8>),
     }
 
     // Note: owner_hash field removed in this optimization
     // Can be added via runtime upgrade when attribution feature is needed
 
+    /// Authority registry entry
+    ///
+    /// Deactivating an authority blocks new submissions attributed to it while
+    /// keeping its name resolvable for historical records.
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxNameLength))]
+    #[codec(mel_bound())]
+    pub struct AuthorityInfo<MaxNameLength: Get<u32>> {
+        /// Manufacturer or software developer name
+        pub name: BoundedVec<u8, MaxNameLength>,
+        /// Whether new submissions may be attributed to this authority
+        pub active: bool,
+    }
+
     /// Storage map from image hash to authentication record
     ///
     /// This is the primary storage for all authenticated images. Each hash can only
@@ -111,8 +597,8 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Authority registry: Maps authority ID (u16) to authority name
-    /// This allows us to store a 2-byte index instead of variable-length strings
+    /// Authority registry: Maps authority ID to authority info
+    /// This allows us to store a compact index instead of variable-length strings
     ///
     /// Example: Sony -> 0, Canon -> 1, Adobe Photoshop -> 2, etc.
     #[pallet::storage]
@@ -120,142 +606,2389 @@ pub mod pallet {
     pub type AuthorityRegistry<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
-        u16,
+        AuthorityId,
+        AuthorityInfo<T::MaxAuthorityIdLength>,
+        OptionQuery,
+    >;
+
+    /// Tombstones of removed records, keyed by image hash
+    ///
+    /// A tombstoned hash cannot be submitted again while its tombstone exists.
+    /// `on_initialize` prunes tombstones `TombstoneRetention` blocks after the
+    /// removal, after which the hash reads as absent and may be resubmitted.
+    #[pallet::storage]
+    #[pallet::getter(fn tombstones)]
+    pub type Tombstones<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], Tombstone<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Index of tombstoned hashes by the block their record was removed in
+    ///
+    /// Lets `on_initialize` find expired tombstones without scanning `Tombstones`.
+    #[pallet::storage]
+    pub type TombstonesByBlock<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery,
+    >;
+
+    /// Oldest removal block whose tombstones may not have been pruned yet
+    #[pallet::storage]
+    pub type TombstonePruneCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Reverse authority index: Maps authority name to its ID
+    ///
+    /// Lets `register_or_get_authority` resolve names without iterating the registry.
+    /// Always holds exactly one entry per `AuthorityRegistry` entry.
+    #[pallet::storage]
+    #[pallet::getter(fn authority_id_by_name)]
+    pub type AuthorityIdByName<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
         BoundedVec<u8, T::MaxAuthorityIdLength>,
+        AuthorityId,
         OptionQuery,
     >;
 
     /// Next authority ID to assign
     #[pallet::storage]
     #[pallet::getter(fn next_authority_id)]
-    pub type NextAuthorityId<T: Config> = StorageValue<_, u16, ValueQuery>;
+    pub type NextAuthorityId<T: Config> = StorageValue<_, AuthorityId, ValueQuery>;
+
+    /// Whether `AuthorityCapacityLow` has been emitted
+    #[pallet::storage]
+    pub type AuthorityCapacityWarned<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     /// Count of total image records stored (for statistics)
     #[pallet::storage]
     #[pallet::getter(fn total_records)]
     pub type TotalRecords<T: Config> = StorageValue<_, u64, ValueQuery>;
 
-    /// Genesis configuration for the pallet
-    #[pallet::genesis_config]
-    #[derive(frame_support::DefaultNoBound)]
-    pub struct GenesisConfig<T: Config> {
-        #[serde(skip)]
-        pub _phantom: PhantomData<T>,
-    }
+    /// Number of records attributed to each authority
+    ///
+    /// Kept in step with `ImageRecords` so per-authority counts never require iteration.
+    #[pallet::storage]
+    #[pallet::getter(fn records_per_authority)]
+    pub type RecordsPerAuthority<T: Config> = StorageMap<_, Blake2_128Concat, AuthorityId, u64, ValueQuery>;
 
-    #[pallet::genesis_build]
-    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-        fn build(&self) {
-            // Initialize total records to 0
-            TotalRecords::<T>::put(0u64);
-            // Initialize next authority ID to 0
-            NextAuthorityId::<T>::put(0u16);
-        }
-    }
+    /// Index of record hashes by the block they were stored in
+    ///
+    /// Lets auditors list records for a block range without scanning `ImageRecords`.
+    /// Only the last `BlockIndexRetention` blocks are kept.
+    #[pallet::storage]
+    pub type RecordsByBlock<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery,
+    >;
 
-    /// Events emitted by the pallet
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// An image record was successfully submitted
-        ImageRecordSubmitted {
-            image_hash: [u8; 32],
-            authority_id: u16,
-            modification_level: u8,
-        },
-        /// Multiple image records were submitted in a batch
-        ImageBatchSubmitted {
-            count: u32,
-        },
-        /// A new authority was registered
-        AuthorityRegistered {
-            authority_id: u16,
-            authority_name: BoundedVec<u8, T::MaxAuthorityIdLength>,
-        },
-    }
+    /// Index of record hashes by submission type
+    ///
+    /// The registry has no finer content classification, so dashboards filter
+    /// camera-originated against software-originated records through this index.
+    #[pallet::storage]
+    pub type RecordsBySubmissionType<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SubmissionType,
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery,
+    >;
 
-    /// Errors that can occur in the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// The provided image hash has invalid length (must be 32 bytes binary or 64 hex chars)
-        InvalidHashLength,
-        /// The modification level is invalid (must be 0, 1, or 2)
-        InvalidModificationLevel,
-        /// The authority name exceeds maximum length
-        AuthorityNameTooLong,
-        /// This image hash already exists in storage (duplicate submission)
-        HashAlreadyExists,
-        /// The parent image hash was not found in storage
-        ParentHashNotFound,
-        /// The parent image hash has invalid length
-        InvalidParentHashLength,
-        /// Batch submission is empty
-        EmptyBatch,
-        /// Batch submission exceeds maximum size
-        BatchTooLarge,
-        /// Authority ID not found in registry
-        AuthorityNotFound,
-        /// Maximum number of authorities reached (u16::MAX)
-        TooManyAuthorities,
-    }
+    /// Index of record hashes by their first byte
+    ///
+    /// Only populated while `IndexRecordsByHashPrefix` is enabled.
+    #[pallet::storage]
+    pub type RecordsByHashPrefix<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u8, Blake2_128Concat, [u8; 32], (), OptionQuery>;
 
-    /// Dispatchable functions (extrinsics)
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Submit a new image authentication record to the blockchain (OPTIMIZED).
-        ///
-        /// This function is restricted to authorized aggregator nodes. It stores
-        /// the image hash along with authentication metadata permanently on-chain.
-        ///
-        /// OPTIMIZATION NOTES:
-        /// - Accepts hex (64 chars) or binary (32 bytes) image hashes
-        /// - Automatically registers authorities in lookup table (2 bytes vs variable)
-        /// - Uses compact encoding for timestamps and block numbers
-        /// - Removed owner_hash field (can be added via runtime upgrade if needed)
-        ///
-        /// # Arguments
-        ///
+    /// Oldest block whose `RecordsByBlock` entries may not have been pruned yet
+    #[pallet::storage]
+    pub type BlockIndexPruneCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Number of accepted records submitted by each account
+    ///
+    /// Input for aggregator reputation scoring; batch submissions count every record
+    /// and removed records stop counting. Records stored before this counter was
+    /// introduced are not attributed to anyone.
+    #[pallet::storage]
+    #[pallet::getter(fn submissions_by_account)]
+    pub type SubmissionsByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Number of stored records naming each hash as their parent
+    ///
+    /// Checked against `MaxChildrenPerParent`.
+    #[pallet::storage]
+    pub type ChildCount<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], u32, ValueQuery>;
+
+    /// Index of record hashes by the account that submitted them
+    ///
+    /// Lets auditors review a single aggregator's output. Entries are removed
+    /// together with their record, and records stored before this index was
+    /// introduced are not attributed to anyone.
+    #[pallet::storage]
+    pub type RecordsBySubmitter<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery,
+    >;
+
+    /// Account that submitted each record, for clearing `RecordsBySubmitter` on removal
+    #[pallet::storage]
+    pub type RecordSubmitter<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], T::AccountId, OptionQuery>;
+
+    /// Maximum number of records governance allows each authority
+    ///
+    /// Keeps a single authority from dominating state. Authorities without an
+    /// entry are unlimited.
+    #[pallet::storage]
+    pub type AuthorityQuota<T: Config> =
+        StorageMap<_, Blake2_128Concat, AuthorityId, u64, OptionQuery>;
+
+    /// Block of each account's latest submission, for `SubmissionCooldown`
+    ///
+    /// Not written while the cooldown is zero.
+    #[pallet::storage]
+    pub type LastSubmission<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Names an authority has had, oldest first, ending with the current one
+    ///
+    /// Only written on rename, so authorities that were never renamed have no
+    /// entry. Keeps the last `MaxNameHistory` names.
+    #[pallet::storage]
+    pub type AuthorityNameHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AuthorityId,
+        BoundedVec<BoundedVec<u8, T::MaxAuthorityIdLength>, MaxNameHistory>,
+        ValueQuery,
+    >;
+
+    /// Declared links from a record to an earlier record of the same image
+    ///
+    /// Lets an image hashed with two algorithms (e.g. SHA-256 and BLAKE3) keep one
+    /// provenance history. Records do not carry their algorithm, so the link is
+    /// the submitter's claim; the chain only checks that both records exist.
+    #[pallet::storage]
+    pub type AltHashLink<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], [u8; 32], OptionQuery>;
+
+    /// Reverse of `AltHashLink`: linked-to hash, then the hash linking to it
+    #[pallet::storage]
+    pub type AltHashLinkedFrom<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery,
+    >;
+
+    /// Number of failed submissions per reason code
+    ///
+    /// The code is the index of the pallet `Error` variant the submission failed
+    /// with. A failed extrinsic rolls back its own writes, so the pallet cannot
+    /// count here; the runtime calls `note_rejection` from a transaction extension's
+    /// `post_dispatch`, which runs outside the call's storage layer. Transactions
+    /// dropped by the pool before inclusion are not counted.
+    #[pallet::storage]
+    pub type RejectionCounts<T: Config> = StorageMap<_, Blake2_128Concat, u8, u64, ValueQuery>;
+
+    /// Number of records per modification level: [raw, validated, modified]
+    #[pallet::storage]
+    #[pallet::getter(fn records_per_level)]
+    pub type RecordsPerLevel<T: Config> = StorageValue<_, [u64; 3], ValueQuery>;
+
+    /// Statistics snapshots keyed by the block they were taken in
+    #[pallet::storage]
+    #[pallet::getter(fn snapshots)]
+    pub type Snapshots<T: Config> =
+        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, StatsSnapshot, OptionQuery>;
+
+    /// Block from which submissions are accepted
+    ///
+    /// `None` means the registry is open. Reads and verification are never gated.
+    #[pallet::storage]
+    #[pallet::getter(fn activation_block)]
+    pub type ActivationBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Authorities vouched for by governance
+    ///
+    /// Verification is independent of the `active` flag in the registry.
+    #[pallet::storage]
+    #[pallet::getter(fn verified_authorities)]
+    pub type VerifiedAuthorities<T: Config> = StorageMap<_, Blake2_128Concat, AuthorityId, (), OptionQuery>;
+
+    /// Authorities whose records governance has frozen for good
+    ///
+    /// Records of a sealed authority cannot be amended, reclassified, reassigned
+    /// or removed, and no record can be reassigned to it. There is no unseal.
+    #[pallet::storage]
+    pub type SealedAuthorities<T: Config> = StorageMap<_, Blake2_128Concat, AuthorityId, (), OptionQuery>;
+
+    /// Whether submissions must be attributed to a verified authority
+    ///
+    /// Off by default. While on, new names cannot be auto-registered through
+    /// submissions, since a fresh authority is never verified.
+    #[pallet::storage]
+    #[pallet::getter(fn require_verified_authority)]
+    pub type RequireVerifiedAuthority<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Whether the chain was launched with the production safety checks
+    ///
+    /// Set once at genesis. While on, edited records need a parent and names are
+    /// never auto-registered, whatever the runtime constants say. Verification is
+    /// required from genesis too, but stays switchable by governance.
+    #[pallet::storage]
+    pub type ProductionMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Accounts allowed to submit records (aggregator nodes)
+    ///
+    /// Never holds more than `MaxAggregators` entries. Seeded at genesis; chains
+    /// upgrading from a version without the allowlist start empty and must call
+    /// `set_aggregators` before submissions resume.
+    #[pallet::storage]
+    #[pallet::getter(fn authorized_aggregators)]
+    pub type AuthorizedAggregators<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Human-readable label for each modification level
+    ///
+    /// Seeded with `DEFAULT_MODIFICATION_LABELS` at genesis, or by the version
+    /// 13 migration on upgraded chains; governance may relabel levels to match a
+    /// chain's own semantics.
+    #[pallet::storage]
+    pub type ModificationLabels<T: Config> =
+        StorageMap<_, Blake2_128Concat, u8, BoundedVec<u8, MaxLabelLength>, OptionQuery>;
+
+    /// IDs of the authorities seeded by the genesis config
+    ///
+    /// Distinguishes the curated-from-start registry from authorities added later.
+    #[pallet::storage]
+    pub type GenesisAuthorities<T: Config> =
+        StorageValue<_, BoundedVec<AuthorityId, MaxGenesisAuthorities>, ValueQuery>;
+
+    /// Hashes of the most recently stored records, oldest first
+    ///
+    /// A ring buffer of `MaxRecentRecords` entries: each insert evicts the oldest
+    /// hash once full. Removed records are dropped from it.
+    #[pallet::storage]
+    pub type RecentRecords<T: Config> =
+        StorageValue<_, BoundedVec<[u8; 32], MaxRecentRecords>, ValueQuery>;
+
+    /// Genesis configuration for the pallet
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial authorized aggregators (at most `MaxAggregators`)
+        pub aggregators: Vec<T::AccountId>,
+        /// Authority names registered at genesis (at most `MaxGenesisAuthorities`)
+        ///
+        /// They receive sequential IDs from `FirstUserAuthorityId`, in order.
+        pub authorities: Vec<Vec<u8>>,
+        /// Turn on the production safety checks together
+        ///
+        /// Sets `ProductionMode` and `RequireVerifiedAuthority`, and marks the
+        /// genesis `authorities` as verified so they can submit from block one.
+        /// Any other authority needs `set_authority_verified`.
+        pub production_mode: bool,
+        #[serde(skip)]
+        pub _phantom: PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            // Initialize total records to 0
+            TotalRecords::<T>::put(0u64);
+            // Sequential IDs start above the reserved range
+            NextAuthorityId::<T>::put(T::FirstUserAuthorityId::get());
+
+            assert!(
+                self.aggregators.len() as u32 <= MaxAggregators::get(),
+                "too many genesis aggregators"
+            );
+            for account in &self.aggregators {
+                AuthorizedAggregators::<T>::insert(account, ());
+            }
+
+            for (level, label) in DEFAULT_MODIFICATION_LABELS.iter().enumerate() {
+                let label: BoundedVec<u8, MaxLabelLength> =
+                    label.to_vec().try_into().expect("default labels fit the bound");
+                ModificationLabels::<T>::insert(level as u8, label);
+            }
+
+            // Catch malformed seed lists at launch: every name must be new (after
+            // normalization) and the IDs must run gap-free from `FirstUserAuthorityId`
+            let first_id = T::FirstUserAuthorityId::get();
+            let ids: Vec<AuthorityId> = self
+                .authorities
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    let bounded_name = Pallet::<T>::validate_authority_name(name.clone())
+                        .unwrap_or_else(|e| {
+                            panic!("invalid genesis authority name at index {}: {:?}", index, e)
+                        });
+                    if let Some(existing) = AuthorityIdByName::<T>::get(&bounded_name) {
+                        panic!(
+                            "duplicate genesis authority name {:?} at index {}: already seeded as authority {}",
+                            core::str::from_utf8(&bounded_name).unwrap_or("<non-UTF-8>"),
+                            index,
+                            existing,
+                        );
+                    }
+                    let id = Pallet::<T>::insert_authority(bounded_name)
+                        .expect("genesis authorities exceed the authority cap");
+                    assert_eq!(
+                        id,
+                        first_id.saturating_add(index as AuthorityId),
+                        "genesis authority IDs must be contiguous from FirstUserAuthorityId"
+                    );
+                    id
+                })
+                .collect();
+            assert_eq!(
+                NextAuthorityId::<T>::get(),
+                first_id.saturating_add(ids.len() as AuthorityId),
+                "NextAuthorityId must follow the last genesis authority"
+            );
+            let ids: BoundedVec<AuthorityId, MaxGenesisAuthorities> =
+                ids.try_into().expect("too many genesis authorities");
+
+            if self.production_mode {
+                ProductionMode::<T>::put(true);
+                RequireVerifiedAuthority::<T>::put(true);
+                // The seed list is vetted with the chain spec
+                for id in &ids {
+                    VerifiedAuthorities::<T>::insert(id, ());
+                }
+            }
+            GenesisAuthorities::<T>::put(ids);
+        }
+    }
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An image record was successfully submitted
+        ///
+        /// Deposited under the topic `authority_topic(authority_id)`.
+        ImageRecordSubmitted {
+            image_hash: [u8; 32],
+            authority_id: AuthorityId,
+            modification_level: u8,
+        },
+        /// Multiple image records were submitted in a batch
+        ImageBatchSubmitted {
+            count: u32,
+        },
+        /// A new authority was registered
+        ///
+        /// Emitted once per authority: batches resolve each distinct name once,
+        /// and later submissions find it through `AuthorityIdByName`.
+        AuthorityRegistered {
+            authority_id: AuthorityId,
+            authority_name: BoundedVec<u8, T::MaxAuthorityIdLength>,
+        },
+        /// Governance changed the label of a modification level
+        ModificationLabelSet {
+            level: u8,
+            label: BoundedVec<u8, MaxLabelLength>,
+        },
+        /// Authority ID usage reached `AuthorityCapacityWarning` of the cap
+        AuthorityCapacityLow {
+            remaining: u32,
+        },
+        /// An authority was deactivated; its existing records remain queryable
+        AuthorityDeactivated {
+            authority_id: AuthorityId,
+        },
+        /// A previously deactivated authority was reactivated
+        AuthorityReactivated {
+            authority_id: AuthorityId,
+        },
+        /// An authority without records was deleted from the registry
+        AuthorityPruned {
+            authority_id: AuthorityId,
+        },
+        /// The submission activation block was set or cleared
+        ActivationBlockSet {
+            block: Option<BlockNumberFor<T>>,
+        },
+        /// A record's modification level was corrected after review
+        RecordReclassified {
+            image_hash: [u8; 32],
+            old_level: u8,
+            new_level: u8,
+        },
+        /// A record was re-attributed to another authority
+        RecordReassigned {
+            image_hash: [u8; 32],
+            old_authority: AuthorityId,
+            new_authority: AuthorityId,
+        },
+        /// An idempotent submission matched the stored record; nothing was written
+        RecordAlreadyPresent { image_hash: [u8; 32] },
+        /// A record was removed and replaced by a tombstone
+        RecordRemoved {
+            image_hash: [u8; 32],
+            reason_code: u8,
+            reason: BoundedVec<u8, T::MaxReasonLength>,
+        },
+        /// Governance registered several authorities in one call
+        ///
+        /// Lists only newly assigned IDs; names that already existed are skipped.
+        AuthoritiesRegistered {
+            ids: Vec<AuthorityId>,
+        },
+        /// Governance marked an authority as verified or revoked its verification
+        AuthorityVerificationSet {
+            authority_id: AuthorityId,
+            verified: bool,
+        },
+        /// The verified-authority requirement for submissions was toggled
+        RequireVerifiedAuthoritySet {
+            required: bool,
+        },
+        /// Expired tombstones were pruned by `on_initialize`
+        TombstonesPruned {
+            count: u32,
+        },
+        /// The authorized aggregator set was replaced
+        AggregatorsReset {
+            count: u32,
+        },
+        /// Registry statistics were snapshotted
+        StatisticsSnapshot {
+            block: BlockNumberFor<T>,
+            snapshot: StatsSnapshot,
+        },
+        /// The submitter corrected a record within the mutability window
+        RecordAmended {
+            image_hash: [u8; 32],
+            old_level: u8,
+            new_level: u8,
+            submission_type: SubmissionType,
+        },
+        /// A record was declared the same image as an existing record
+        AlternateHashLinked {
+            image_hash: [u8; 32],
+            linked_to: [u8; 32],
+        },
+        /// Governance set or cleared an authority's record quota
+        AuthorityQuotaSet {
+            authority_id: AuthorityId,
+            quota: Option<u64>,
+        },
+        /// Governance renamed an authority; its ID and records are unchanged
+        AuthorityRenamed {
+            authority_id: AuthorityId,
+            old_name: BoundedVec<u8, T::MaxAuthorityIdLength>,
+            new_name: BoundedVec<u8, T::MaxAuthorityIdLength>,
+        },
+        /// Governance sealed an authority; its records can no longer change
+        AuthoritySealed { authority_id: AuthorityId },
+        /// Hashes stored by a batch, in submission order (only with `EmitBatchDetail`)
+        ///
+        /// Follows the batch's `ImageBatchSubmitted`.
+        BatchDetail {
+            hashes: BoundedVec<[u8; 32], T::MaxBatchSize>,
+        },
+        /// A rejected resubmission claimed a different authority than the stored record
+        ///
+        /// `attempted_authority` is `None` if the claimed name is not registered.
+        /// Deposited by the runtime after the failed dispatch (see
+        /// [`Pallet::note_resubmission`]), as the call's own events are reverted.
+        ConflictingResubmission {
+            hash: [u8; 32],
+            stored_authority: AuthorityId,
+            attempted_authority: Option<AuthorityId>,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The provided image hash has invalid length (must be 32 bytes binary or 64 hex chars)
+        InvalidHashLength,
+        /// The modification level is invalid (must be 0, 1, or 2)
+        InvalidModificationLevel,
+        /// The authority name exceeds maximum length
+        AuthorityNameTooLong,
+        /// An authority with this name is already registered
+        AuthorityAlreadyRegistered,
+        /// The authority ID is not in the reserved range below `FirstUserAuthorityId`
+        AuthorityIdNotReserved,
+        /// An authority is already registered under this ID
+        AuthorityIdTaken,
+        /// The authority name is shorter than `MinAuthorityNameLength`
+        AuthorityNameTooShort,
+        /// The authority name contains control characters
+        AuthorityNameInvalidChars,
+        /// The authority name is not valid UTF-8 (see `ValidateUtf8AuthorityNames`)
+        AuthorityNameNotUtf8,
+        /// This image hash already exists in storage (duplicate submission)
+        HashAlreadyExists,
+        /// The parent image hash was not found in storage
+        ParentHashNotFound,
+        /// The parent image hash has invalid length
+        InvalidParentHashLength,
+        /// Batch submission is empty
+        EmptyBatch,
+        /// Batch submission exceeds maximum size
+        BatchTooLarge,
+        /// Authority ID not found in registry
+        AuthorityNotFound,
+        /// Maximum number of authorities reached (`MaxAuthorities` or `AuthorityId::MAX`)
+        TooManyAuthorities,
+        /// The authority has been deactivated and cannot be used for new submissions
+        AuthorityInactive,
+        /// Submissions are not accepted before the activation block
+        RegistryNotActive,
+        /// The submitting account is not an authorized aggregator
+        NotAuthorizedAggregator,
+        /// The modification level label is longer than `MaxLabelLength`
+        LabelTooLong,
+        /// The takedown reason is longer than `MaxReasonLength`
+        ReasonTooLong,
+        /// An edited record has no parent while `RequireParentForEdits` is on
+        MissingParentForEdit,
+        /// The authority still has records attributed to it
+        AuthorityHasRecords,
+        /// No record is stored under the given image hash
+        RecordNotFound,
+        /// The image hash was removed by a takedown and cannot be resubmitted
+        HashTombstoned,
+        /// Submissions require a verified authority and this one is not verified
+        AuthorityNotVerified,
+        /// The record is older than `MutabilityWindow` and can no longer be amended
+        MutabilityWindowClosed,
+        /// Only the account that submitted a record may amend it
+        NotOriginalSubmitter,
+        /// The record already declares an alternate-hash link
+        AlreadyLinked,
+        /// A record cannot be linked to itself
+        CannotLinkToSelf,
+        /// The authority has reached its record quota
+        AuthorityQuotaExceeded,
+        /// The account submitted less than `SubmissionCooldown` blocks ago
+        SubmissionCooldown,
+        /// The authority is sealed, so its records (and the authority) are frozen
+        AuthoritySealed,
+        /// The authority has already been sealed
+        AuthorityAlreadySealed,
+        /// The parent already has `MaxChildrenPerParent` children
+        TooManyChildren,
+    }
+
+    /// Dispatchable functions (extrinsics)
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit a new image authentication record to the blockchain (OPTIMIZED).
+        ///
+        /// This function is restricted to authorized aggregator nodes. It stores
+        /// the image hash along with authentication metadata permanently on-chain.
+        ///
+        /// OPTIMIZATION NOTES:
+        /// - Accepts hex (64 chars) or binary (32 bytes) image hashes
+        /// - Automatically registers authorities in lookup table (2 bytes vs variable)
+        /// - Uses compact encoding for timestamps and block numbers
+        /// - Removed owner_hash field (can be added via runtime upgrade if needed)
+        ///
+        /// # Arguments
+        ///
         /// * `origin` - Must be signed by an authorized aggregator account
         /// * `image_hash` - SHA-256 hash (64 hex chars OR 32 binary bytes)
         /// * `submission_type` - Whether from camera or software
         /// * `modification_level` - 0 (raw), 1 (validated), or 2 (modified)
         /// * `parent_image_hash` - Optional hash of parent image for provenance
         /// * `authority_name` - Manufacturer or software developer name (auto-registered)
+        /// * `width` / `height` - Optional pixel dimensions, stored as reported
+        /// * `byte_size` - Optional file size in bytes, stored as reported
+        ///
+        /// The dimensions and size are advisory: verifiers may use them to flag obvious
+        /// mismatches, but they are not checked on-chain.
         ///
         /// # Errors
         ///
         /// Returns error if:
+        /// - Submitter is not an authorized aggregator
+        /// - Registry has not reached its activation block
         /// - Hash length is not 32 or 64 bytes
         /// - Modification level is not 0-2
+        /// - Modification level is 1-2 without a parent while `RequireParentForEdits` is on
         /// - Hash already exists in storage
         /// - Parent hash doesn't exist (if specified)
+        /// - Parent already has `MaxChildrenPerParent` children
         /// - Authority name exceeds max length
+        /// - Authority is not verified while `RequireVerifiedAuthority` is on
+        /// - Authority has reached its `AuthorityQuota`
+        ///
+        /// # Weight
+        ///
+        /// Weight is calculated based on:
+        /// - One storage read (check for duplicate)
+        /// - One storage write (insert record)
+        /// - One storage read+write (increment counter)
+        /// - Optional: authority registration (if new)
+        ///
+        /// The declared weight assumes a new authority is registered; submissions
+        /// naming an existing authority are refunded down to
+        /// `submit_image_record_existing_authority`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(Pallet::<T>::submit_image_record_new_authority())]
+        pub fn submit_image_record(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            submission_type: SubmissionType,
+            modification_level: u8,
+            parent_image_hash: Option<Vec<u8>>,
+            authority_name: Vec<u8>,
+            width: Option<u32>,
+            height: Option<u32>,
+            byte_size: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            // Verify origin is signed by an authorized aggregator
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_authorized_aggregator(&who)?;
+            Self::ensure_registry_active()?;
+            Self::note_submission(&who)?;
+
+            Self::do_submit_record(
+                Some(&who),
+                image_hash,
+                submission_type,
+                modification_level,
+                parent_image_hash,
+                authority_name,
+                (width, height, byte_size),
+            )
+        }
+
+        /// Submit an image record with at-least-once semantics.
+        ///
+        /// Behaves like `submit_image_record`, except that resubmitting a record
+        /// identical to the stored one succeeds without changing state and emits
+        /// `RecordAlreadyPresent`. Identical means the same authority, modification
+        /// level and parent; the submission type and advisory dimensions are not
+        /// compared. Aggregators that retry after network failures can use it
+        /// without special-casing `HashAlreadyExists`.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Submitter is not an authorized aggregator
+        /// - The hash exists with a different authority, level or parent
+        ///   (`HashAlreadyExists`)
+        /// - Any check of `submit_image_record` fails for a new hash
+        #[pallet::call_index(17)]
+        #[pallet::weight(Pallet::<T>::submit_image_record_new_authority())]
+        pub fn submit_image_record_idempotent(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            submission_type: SubmissionType,
+            modification_level: u8,
+            parent_image_hash: Option<Vec<u8>>,
+            authority_name: Vec<u8>,
+            width: Option<u32>,
+            height: Option<u32>,
+            byte_size: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin.clone())?;
+            Self::ensure_authorized_aggregator(&who)?;
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+            if Self::is_identical_resubmission(
+                &binary_hash,
+                modification_level,
+                parent_image_hash.as_deref(),
+                &authority_name,
+            ) {
+                Self::deposit_event(Event::RecordAlreadyPresent { image_hash: binary_hash });
+                return Ok(Some(Self::submit_image_record_existing_authority()).into());
+            }
+
+            Self::submit_image_record(
+                origin,
+                image_hash,
+                submission_type,
+                modification_level,
+                parent_image_hash,
+                authority_name,
+                width,
+                height,
+                byte_size,
+            )
+        }
+
+        /// Submit an image record, inferring the submission type when omitted.
+        ///
+        /// Identical to `submit_image_record` except that `submission_type` is
+        /// optional. When it is `None` the type is guessed from the modification
+        /// level (see [`Pallet::inferred_submission_type`]): level 0 becomes
+        /// `Camera`, levels 1 and 2 become `Software`. This is only a heuristic, as
+        /// a camera can also produce edited output; clients that know the source
+        /// should pass it explicitly.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if any check of `submit_image_record` fails.
+        #[pallet::call_index(24)]
+        #[pallet::weight(Pallet::<T>::submit_image_record_new_authority())]
+        pub fn submit_image_record_inferred(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            submission_type: Option<SubmissionType>,
+            modification_level: u8,
+            parent_image_hash: Option<Vec<u8>>,
+            authority_name: Vec<u8>,
+            width: Option<u32>,
+            height: Option<u32>,
+            byte_size: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            let submission_type = submission_type
+                .unwrap_or_else(|| Self::inferred_submission_type(modification_level));
+
+            Self::submit_image_record(
+                origin,
+                image_hash,
+                submission_type,
+                modification_level,
+                parent_image_hash,
+                authority_name,
+                width,
+                height,
+                byte_size,
+            )
+        }
+
+        /// Submit an image record as governance, in the operational dispatch class.
+        ///
+        /// For records that must land even when blocks are full of aggregator
+        /// traffic, such as a legally required entry. Operational extrinsics draw
+        /// on the block's operational reserve (`BlockWeightsConfig`), which normal
+        /// submissions cannot use, so only `GovernanceOrigin` may call this.
+        ///
+        /// The record is checked like `submit_image_record`, except that the
+        /// activation block, submission cooldown and `RecordValidator` do not
+        /// apply and the record is not attributed to a submitting account.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Any record check of `submit_image_record` fails
+        #[pallet::call_index(25)]
+        #[pallet::weight((Pallet::<T>::submit_image_record_new_authority(), DispatchClass::Operational))]
+        pub fn force_submit_image_record(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            submission_type: SubmissionType,
+            modification_level: u8,
+            parent_image_hash: Option<Vec<u8>>,
+            authority_name: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            Self::do_submit_record(
+                None,
+                image_hash,
+                submission_type,
+                modification_level,
+                parent_image_hash,
+                authority_name,
+                (None, None, None),
+            )
+        }
+
+        /// Submit multiple image records in a single transaction (batch submission - OPTIMIZED).
+        ///
+        /// This is more gas-efficient than individual submissions when aggregators
+        /// have accumulated multiple validated images.
+        ///
+        /// OPTIMIZATION NOTES:
+        /// - Accepts hex or binary hashes
+        /// - Automatically registers authorities in lookup table
+        /// - Uses compact encoding for all numeric fields
+        /// - Removed owner_hash field
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - Must be signed by an authorized aggregator account
+        /// * `records` - Batch entries (at most `MaxBatchSize`, enforced when decoding)
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Submitter is not an authorized aggregator
+        /// - Registry has not reached its activation block
+        /// - Batch is empty
+        /// - Any individual record validation fails
+        ///
+        /// Note: This is an atomic operation - all records succeed or all fail.
+        ///
+        /// # Weight
+        ///
+        /// Scales with the number of records, with records that name a parent
+        /// charged for the parent lookup (see `batch_weight`).
+        #[pallet::call_index(1)]
+        #[pallet::weight(Pallet::<T>::batch_weight(
+            records.len(),
+            records.iter().filter(|record| record.parent.is_some()).count(),
+        ))]
+        pub fn submit_image_batch(
+            origin: OriginFor<T>,
+            records: BoundedVec<BatchRecordOf<T>, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let records = records
+                .into_iter()
+                .map(|record| {
+                    (
+                        record.image_hash.into_inner(),
+                        record.submission_type,
+                        record.modification_level,
+                        record.parent.map(BoundedVec::into_inner),
+                        AuthorityRef::Name(record.authority.into_inner()),
+                    )
+                })
+                .collect();
+
+            Self::do_submit_batch(who, records)
+        }
+
+        /// Submit a batch whose authorities are given by registry ID or by name.
+        ///
+        /// Aggregators that already know an authority's ID can skip the name lookup
+        /// for steady-state submissions; names still register new authorities.
+        ///
+        /// # Arguments
+        ///
+        /// * `origin` - Must be signed by an authorized aggregator account
+        /// * `records` - Batch entries (at most `MaxBatchSize`, enforced when decoding)
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Any condition rejected by `submit_image_batch` applies
+        /// - An authority ID is not in the registry
+        /// - A referenced authority is deactivated
+        ///
+        /// Note: This is an atomic operation - all records succeed or all fail.
+        #[pallet::call_index(7)]
+        #[pallet::weight(Pallet::<T>::batch_weight(
+            records.len(),
+            records.iter().filter(|record| record.parent.is_some()).count(),
+        ))]
+        pub fn submit_image_batch_with_refs(
+            origin: OriginFor<T>,
+            records: BoundedVec<BatchRecordWithRefOf<T>, T::MaxBatchSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let records = records
+                .into_iter()
+                .map(|record| {
+                    (
+                        record.image_hash.into_inner(),
+                        record.submission_type,
+                        record.modification_level,
+                        record.parent.map(BoundedVec::into_inner),
+                        record.authority,
+                    )
+                })
+                .collect();
+
+            Self::do_submit_batch(who, records)
+        }
+
+        /// Deactivate an authority (governance only).
+        ///
+        /// Used when an authority's signing key is compromised. New submissions
+        /// attributed to the authority are rejected, but its historical records
+        /// and registry entry are kept intact.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        #[pallet::call_index(2)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn deactivate_authority(origin: OriginFor<T>, authority_id: AuthorityId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            Self::set_authority_active(authority_id, false)?;
+
+            Self::deposit_event(Event::AuthorityDeactivated { authority_id });
+
+            Ok(())
+        }
+
+        /// Reactivate a previously deactivated authority (governance only).
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        #[pallet::call_index(3)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn reactivate_authority(origin: OriginFor<T>, authority_id: AuthorityId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            Self::set_authority_active(authority_id, true)?;
+
+            Self::deposit_event(Event::AuthorityReactivated { authority_id });
+
+            Ok(())
+        }
+
+        /// Delete an authority that has no records (governance only).
+        ///
+        /// Removes the registry entry, the name index, any verification mark and its
+        /// place among the genesis authorities, so the name can be registered again.
+        /// The ID itself is not reused. Registration
+        /// takes no deposit, so there is nothing to release.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        /// - Records are still attributed to the authority
+        /// - The authority is sealed
+        #[pallet::call_index(18)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn prune_authority(origin: OriginFor<T>, authority_id: AuthorityId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let info = AuthorityRegistry::<T>::get(authority_id)
+                .ok_or(Error::<T>::AuthorityNotFound)?;
+            ensure!(
+                RecordsPerAuthority::<T>::get(authority_id) == 0,
+                Error::<T>::AuthorityHasRecords
+            );
+            Self::ensure_not_sealed(authority_id)?;
+
+            AuthorityRegistry::<T>::remove(authority_id);
+            AuthorityIdByName::<T>::remove(&info.name);
+            VerifiedAuthorities::<T>::remove(authority_id);
+            RecordsPerAuthority::<T>::remove(authority_id);
+            AuthorityQuota::<T>::remove(authority_id);
+            AuthorityNameHistory::<T>::remove(authority_id);
+            GenesisAuthorities::<T>::mutate(|ids| ids.retain(|id| *id != authority_id));
+
+            Self::deposit_event(Event::AuthorityPruned { authority_id });
+
+            Ok(())
+        }
+
+        /// Correct a record's modification level and submission type (original submitter).
+        ///
+        /// Allowed until `MutabilityWindow` blocks after the record's block. The
+        /// amended record goes through the same level, parent and `RecordValidator`
+        /// checks as a new submission. Records stored before submitters were
+        /// indexed cannot be amended.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Hash length is not 32 or 64 bytes
+        /// - No record exists for the hash
+        /// - Caller did not submit the record
+        /// - The record's authority is sealed
+        /// - The mutability window has passed
+        /// - New level is not 0-2, or 1-2 without a parent while `RequireParentForEdits` is on
+        /// - The runtime's `RecordValidator` rejects the amended record
+        #[pallet::call_index(19)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn amend_record(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            new_level: u8,
+            new_submission_type: SubmissionType,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+            let mut record =
+                ImageRecords::<T>::get(binary_hash).ok_or(Error::<T>::RecordNotFound)?;
+            ensure!(
+                RecordsBySubmitter::<T>::contains_key(&who, binary_hash),
+                Error::<T>::NotOriginalSubmitter
+            );
+            Self::ensure_not_sealed(record.authority_id)?;
+            let closes_at = BlockNumberFor::<T>::from(record.block_number)
+                .saturating_add(T::MutabilityWindow::get());
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= closes_at,
+                Error::<T>::MutabilityWindowClosed
+            );
+
+            ensure!(new_level <= 2, Error::<T>::InvalidModificationLevel);
+            Self::ensure_parent_for_edit(new_level, record.parent_image_hash.is_some())?;
+
+            let old_level = core::mem::replace(&mut record.modification_level, new_level);
+            let old_type =
+                core::mem::replace(&mut record.submission_type, new_submission_type.clone());
+            T::RecordValidator::validate(&who, &record)?;
+
+            RecordsPerLevel::<T>::mutate(|counts| {
+                if let Some(count) = counts.get_mut(old_level as usize) {
+                    *count = count.saturating_sub(1);
+                }
+                if let Some(count) = counts.get_mut(new_level as usize) {
+                    *count = count.saturating_add(1);
+                }
+            });
+            RecordsBySubmissionType::<T>::remove(&old_type, binary_hash);
+            RecordsBySubmissionType::<T>::insert(&new_submission_type, binary_hash, ());
+            ImageRecords::<T>::insert(binary_hash, record);
+
+            Self::deposit_event(Event::RecordAmended {
+                image_hash: binary_hash,
+                old_level,
+                new_level,
+                submission_type: new_submission_type,
+            });
+
+            Ok(())
+        }
+
+        /// Declare a record to be the same image as an existing record (original submitter).
+        ///
+        /// Used when an image is registered under a second hash algorithm. Each
+        /// record may link to one other record; a record may be linked to by many.
+        /// Links are removed together with either record.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Submitter is not an authorized aggregator
+        /// - Either hash is not 32 or 64 bytes, or both are the same
+        /// - Either record does not exist
+        /// - Caller did not submit `image_hash`
+        /// - `image_hash` is already linked
+        #[pallet::call_index(20)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn link_alternate_hash(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            linked_to: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_authorized_aggregator(&who)?;
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+            let linked_to = Self::parse_image_hash(&linked_to)?;
+            ensure!(binary_hash != linked_to, Error::<T>::CannotLinkToSelf);
+            ensure!(
+                ImageRecords::<T>::contains_key(binary_hash)
+                    && ImageRecords::<T>::contains_key(linked_to),
+                Error::<T>::RecordNotFound
+            );
+            ensure!(
+                RecordsBySubmitter::<T>::contains_key(&who, binary_hash),
+                Error::<T>::NotOriginalSubmitter
+            );
+            ensure!(!AltHashLink::<T>::contains_key(binary_hash), Error::<T>::AlreadyLinked);
+
+            AltHashLink::<T>::insert(binary_hash, linked_to);
+            AltHashLinkedFrom::<T>::insert(linked_to, binary_hash, ());
+
+            Self::deposit_event(Event::AlternateHashLinked { image_hash: binary_hash, linked_to });
+
+            Ok(())
+        }
+
+        /// Set or clear the maximum number of records of an authority (governance only).
+        ///
+        /// `None` removes the cap. A quota below the current count blocks further
+        /// submissions but leaves existing records in place.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        #[pallet::call_index(21)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_authority_quota(
+            origin: OriginFor<T>,
+            authority_id: AuthorityId,
+            quota: Option<u64>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AuthorityRegistry::<T>::contains_key(authority_id),
+                Error::<T>::AuthorityNotFound
+            );
+
+            AuthorityQuota::<T>::set(authority_id, quota);
+
+            Self::deposit_event(Event::AuthorityQuotaSet { authority_id, quota });
+
+            Ok(())
+        }
+
+        /// Give an authority a new name (governance only).
+        ///
+        /// For rebrands and corrected spellings. The ID, and so every record
+        /// attributed to it, stays the same; the old name becomes free to register.
+        /// Both names are kept in `AuthorityNameHistory`.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        /// - New name is too short, too long or has invalid characters
+        /// - An authority with the (normalized) new name already exists
+        #[pallet::call_index(22)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn rename_authority(
+            origin: OriginFor<T>,
+            authority_id: AuthorityId,
+            new_name: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let new_name = Self::validate_authority_name(new_name)?;
+            ensure!(
+                !AuthorityIdByName::<T>::contains_key(&new_name),
+                Error::<T>::AuthorityAlreadyRegistered
+            );
+
+            let old_name = AuthorityRegistry::<T>::try_mutate(authority_id, |maybe_info| {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AuthorityNotFound)?;
+                Ok::<_, Error<T>>(core::mem::replace(&mut info.name, new_name.clone()))
+            })?;
+            AuthorityIdByName::<T>::remove(&old_name);
+            AuthorityIdByName::<T>::insert(&new_name, authority_id);
+
+            AuthorityNameHistory::<T>::mutate(authority_id, |history| {
+                if history.is_empty() {
+                    let _ = history.try_push(old_name.clone());
+                }
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(new_name.clone());
+            });
+
+            Self::deposit_event(Event::AuthorityRenamed { authority_id, old_name, new_name });
+
+            Ok(())
+        }
+
+        /// Permanently freeze an authority's records (governance only).
+        ///
+        /// Meant for decommissioned authorities: afterwards none of its records can
+        /// be amended, reclassified, reassigned or removed, no record can be
+        /// reassigned to it, and it cannot be pruned. Sealing cannot be undone.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        /// - The authority is already sealed
+        #[pallet::call_index(23)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn seal_authority(origin: OriginFor<T>, authority_id: AuthorityId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AuthorityRegistry::<T>::contains_key(authority_id),
+                Error::<T>::AuthorityNotFound
+            );
+            ensure!(
+                !SealedAuthorities::<T>::contains_key(authority_id),
+                Error::<T>::AuthorityAlreadySealed
+            );
+
+            SealedAuthorities::<T>::insert(authority_id, ());
+
+            Self::deposit_event(Event::AuthoritySealed { authority_id });
+
+            Ok(())
+        }
+
+        /// Set or clear the block from which submissions are accepted (root only).
+        ///
+        /// Lets a coalition seed authorities before opening the registry. Passing
+        /// `None` removes the gate.
+        #[pallet::call_index(4)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_activation_block(
+            origin: OriginFor<T>,
+            block: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ActivationBlock::<T>::set(block);
+
+            Self::deposit_event(Event::ActivationBlockSet { block });
+
+            Ok(())
+        }
+
+        /// Reclassify a record's modification level after human review (`RemovalOrigin`).
+        ///
+        /// Only `modification_level` changes; the image hash, parent link and
+        /// attribution stay untouched.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not `RemovalOrigin`
+        /// - Hash length is not 32 or 64 bytes
+        /// - New level is not 0-2
+        /// - No record exists for the hash
+        /// - The record's authority is sealed
+        /// - Parents are required for edits and a parentless record would move above level 0
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn reclassify_record(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            new_level: u8,
+        ) -> DispatchResult {
+            T::RemovalOrigin::ensure_origin(origin)?;
+
+            ensure!(new_level <= 2, Error::<T>::InvalidModificationLevel);
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+
+            let old_level = ImageRecords::<T>::try_mutate(binary_hash, |maybe_record| {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+                Self::ensure_not_sealed(record.authority_id)?;
+                Self::ensure_parent_for_edit(new_level, record.parent_image_hash.is_some())?;
+                Ok::<_, Error<T>>(core::mem::replace(&mut record.modification_level, new_level))
+            })?;
+
+            // Move the record between per-level counters
+            RecordsPerLevel::<T>::mutate(|counts| {
+                if let Some(count) = counts.get_mut(old_level as usize) {
+                    *count = count.saturating_sub(1);
+                }
+                if let Some(count) = counts.get_mut(new_level as usize) {
+                    *count = count.saturating_add(1);
+                }
+            });
+
+            Self::deposit_event(Event::RecordReclassified {
+                image_hash: binary_hash,
+                old_level,
+                new_level,
+            });
+
+            Ok(())
+        }
+
+        /// Snapshot the current record counters (root only).
+        ///
+        /// Intended to be dispatched periodically (e.g. weekly) by a scheduler or
+        /// governance with root origin. A second snapshot in the same block
+        /// overwrites the first.
+        #[pallet::call_index(6)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn snapshot_statistics(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let block = frame_system::Pallet::<T>::block_number();
+            let snapshot = StatsSnapshot {
+                total_records: TotalRecords::<T>::get(),
+                records_per_level: RecordsPerLevel::<T>::get(),
+            };
+
+            Snapshots::<T>::insert(block, snapshot.clone());
+
+            Self::deposit_event(Event::StatisticsSnapshot { block, snapshot });
+
+            Ok(())
+        }
+
+        /// Atomically replace the authorized aggregator set (governance only).
+        ///
+        /// Used to rotate a coalition's whole aggregator fleet. Previous members
+        /// lose submission rights in the same block the new set takes effect.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_aggregators(
+            origin: OriginFor<T>,
+            accounts: BoundedVec<T::AccountId, MaxAggregators>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            // The set never exceeds `MaxAggregators`, so this clears it completely
+            let _ = AuthorizedAggregators::<T>::clear(MaxAggregators::get(), None);
+            for account in &accounts {
+                AuthorizedAggregators::<T>::insert(account, ());
+            }
+
+            Self::deposit_event(Event::AggregatorsReset {
+                count: accounts.len() as u32,
+            });
+
+            Ok(())
+        }
+
+        /// Register an authority ahead of any submission (governance only).
+        ///
+        /// Works whether or not auto-registration is enabled; the name is validated
+        /// and normalized exactly as on submission.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Name is too short, too long or has invalid characters
+        /// - An authority with the (normalized) name already exists
+        /// - The authority cap is reached
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn register_authority(origin: OriginFor<T>, authority_name: Vec<u8>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let bounded_name = Self::validate_authority_name(authority_name)?;
+            ensure!(
+                !AuthorityIdByName::<T>::contains_key(&bounded_name),
+                Error::<T>::AuthorityAlreadyRegistered
+            );
+
+            Self::insert_authority(bounded_name)?;
+
+            Ok(())
+        }
+
+        /// Re-attribute a record to another registered authority (governance only).
+        ///
+        /// For authority mergers and corrected attribution errors. Per-authority
+        /// record counts move with the record; the image hash, level and parent
+        /// link are unchanged. The target may be inactive, since its existing
+        /// records stay valid.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Hash length is not 32 or 64 bytes
+        /// - `new_authority_id` is not in the registry
+        /// - No record exists for the hash
+        /// - The current or the new authority is sealed
+        #[pallet::call_index(16)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn reassign_authority(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            new_authority_id: AuthorityId,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+            ensure!(
+                AuthorityRegistry::<T>::contains_key(new_authority_id),
+                Error::<T>::AuthorityNotFound
+            );
+            Self::ensure_not_sealed(new_authority_id)?;
+
+            let old_authority = ImageRecords::<T>::try_mutate(binary_hash, |maybe_record| {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+                Self::ensure_not_sealed(record.authority_id)?;
+                Ok::<_, Error<T>>(core::mem::replace(&mut record.authority_id, new_authority_id))
+            })?;
+
+            // Move the record between per-authority counters
+            RecordsPerAuthority::<T>::mutate(old_authority, |count| {
+                *count = count.saturating_sub(1);
+            });
+            RecordsPerAuthority::<T>::mutate(new_authority_id, |count| {
+                *count = count.saturating_add(1);
+            });
+
+            Self::deposit_event(Event::RecordReassigned {
+                image_hash: binary_hash,
+                old_authority,
+                new_authority: new_authority_id,
+            });
+
+            Ok(())
+        }
+
+        /// Relabel a modification level (governance only).
+        ///
+        /// Labels are descriptive only; they do not change how levels are validated.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Level is not 0-2
+        /// - Label exceeds `MaxLabelLength`
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_modification_label(
+            origin: OriginFor<T>,
+            level: u8,
+            label: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(level <= 2, Error::<T>::InvalidModificationLevel);
+            let label: BoundedVec<u8, MaxLabelLength> =
+                label.try_into().map_err(|_| Error::<T>::LabelTooLong)?;
+
+            ModificationLabels::<T>::insert(level, label.clone());
+
+            Self::deposit_event(Event::ModificationLabelSet { level, label });
+
+            Ok(())
+        }
+
+        /// Register many authorities in one call (governance only).
+        ///
+        /// Each name is validated and normalized as in `register_authority`.
+        /// Names that are already registered are skipped rather than rejected, so
+        /// the call can be re-run with an overlapping list. Any invalid name fails
+        /// the whole call.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - A name is too short, too long or has invalid characters
+        /// - The authority cap is reached
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::register_authorities_batch(names.len() as u32))]
+        pub fn register_authorities_batch(
+            origin: OriginFor<T>,
+            names: BoundedVec<BoundedVec<u8, T::MaxAuthorityIdLength>, ConstU32<100>>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            let mut ids = Vec::new();
+            for name in names {
+                let bounded_name = Self::validate_authority_name(name.into_inner())?;
+                if AuthorityIdByName::<T>::contains_key(&bounded_name) {
+                    continue;
+                }
+                ids.push(Self::insert_authority(bounded_name)?);
+            }
+
+            Self::deposit_event(Event::AuthoritiesRegistered { ids });
+
+            Ok(())
+        }
+
+        /// Register a system authority under a reserved ID (governance only).
+        ///
+        /// Only IDs below `FirstUserAuthorityId` may be assigned this way; the name
+        /// is validated and normalized as in `register_authority`.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - `authority_id` is not below `FirstUserAuthorityId`
+        /// - The ID is already in use
+        /// - Name is too short, too long or has invalid characters
+        /// - An authority with the (normalized) name already exists
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn force_register_authority(
+            origin: OriginFor<T>,
+            authority_id: AuthorityId,
+            authority_name: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                authority_id < T::FirstUserAuthorityId::get(),
+                Error::<T>::AuthorityIdNotReserved
+            );
+            ensure!(
+                !AuthorityRegistry::<T>::contains_key(authority_id),
+                Error::<T>::AuthorityIdTaken
+            );
+
+            let bounded_name = Self::validate_authority_name(authority_name)?;
+            ensure!(
+                !AuthorityIdByName::<T>::contains_key(&bounded_name),
+                Error::<T>::AuthorityAlreadyRegistered
+            );
+
+            Self::write_authority(authority_id, bounded_name);
+
+            Ok(())
+        }
+
+        /// Mark an authority as verified, or revoke its verification (governance only).
+        ///
+        /// Only matters while `RequireVerifiedAuthority` is on; existing records
+        /// are unaffected either way.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        /// - Authority ID is not in the registry
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_authority_verified(
+            origin: OriginFor<T>,
+            authority_id: AuthorityId,
+            verified: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                AuthorityRegistry::<T>::contains_key(authority_id),
+                Error::<T>::AuthorityNotFound
+            );
+
+            if verified {
+                VerifiedAuthorities::<T>::insert(authority_id, ());
+            } else {
+                VerifiedAuthorities::<T>::remove(authority_id);
+            }
+
+            Self::deposit_event(Event::AuthorityVerificationSet { authority_id, verified });
+
+            Ok(())
+        }
+
+        /// Require (or stop requiring) verified authorities for submissions (governance only).
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not the governance origin
+        #[pallet::call_index(12)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn set_require_verified_authority(
+            origin: OriginFor<T>,
+            required: bool,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            RequireVerifiedAuthority::<T>::put(required);
+
+            Self::deposit_event(Event::RequireVerifiedAuthoritySet { required });
+
+            Ok(())
+        }
+
+        /// Remove a record for a legal takedown, leaving a tombstone (`RemovalOrigin`).
+        ///
+        /// The record and its index entries are deleted and the counters updated.
+        /// The tombstone keeps proof of prior existence and blocks resubmission
+        /// until it is pruned after `TombstoneRetention` blocks.
+        /// The submitter's `RecordsBySubmitter` entry and submission count are
+        /// cleared as well, so a later resubmission is not theirs to amend.
+        ///
+        /// `reason` is a free-form explanation for the public record. It is only
+        /// emitted in `RecordRemoved`; the tombstone keeps just `reason_code`.
+        ///
+        /// # Errors
+        ///
+        /// Returns error if:
+        /// - Origin is not `RemovalOrigin`
+        /// - Hash length is not 32 or 64 bytes
+        /// - `reason` exceeds `MaxReasonLength`
+        /// - No record exists for the hash
+        /// - The record's authority is sealed
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)] // TODO: Proper weight calculation
+        pub fn remove_record(
+            origin: OriginFor<T>,
+            image_hash: Vec<u8>,
+            reason_code: u8,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::RemovalOrigin::ensure_origin(origin)?;
+
+            let binary_hash = Self::parse_image_hash(&image_hash)?;
+            let reason: BoundedVec<u8, T::MaxReasonLength> =
+                reason.try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+            let record = ImageRecords::<T>::take(binary_hash).ok_or(Error::<T>::RecordNotFound)?;
+            Self::ensure_not_sealed(record.authority_id)?;
+
+            // Reverse the bookkeeping done in `store_record`
+            if let Some(parent) = record.parent_image_hash {
+                ChildCount::<T>::mutate(parent, |count| *count = count.saturating_sub(1));
+            }
+            RecordsPerAuthority::<T>::mutate(record.authority_id, |count| {
+                *count = count.saturating_sub(1);
+            });
+            RecordsPerLevel::<T>::mutate(|counts| {
+                if let Some(count) = counts.get_mut(record.modification_level as usize) {
+                    *count = count.saturating_sub(1);
+                }
+            });
+            TotalRecords::<T>::mutate(|count| {
+                *count = count.saturating_sub(1);
+            });
+            RecordsByBlock::<T>::remove(BlockNumberFor::<T>::from(record.block_number), binary_hash);
+            RecordsBySubmissionType::<T>::remove(&record.submission_type, binary_hash);
+            RecordsByHashPrefix::<T>::remove(binary_hash[0], binary_hash);
+            RecentRecords::<T>::mutate(|recent| recent.retain(|hash| *hash != binary_hash));
+            if let Some(linked_to) = AltHashLink::<T>::take(binary_hash) {
+                AltHashLinkedFrom::<T>::remove(linked_to, binary_hash);
+            }
+            for (source, ()) in AltHashLinkedFrom::<T>::drain_prefix(binary_hash) {
+                AltHashLink::<T>::remove(source);
+            }
+            if let Some(who) = RecordSubmitter::<T>::take(binary_hash) {
+                RecordsBySubmitter::<T>::remove(&who, binary_hash);
+                SubmissionsByAccount::<T>::mutate(&who, |count| {
+                    *count = count.saturating_sub(1);
+                });
+            }
+
+            let removed_at = frame_system::Pallet::<T>::block_number();
+            Tombstones::<T>::insert(binary_hash, Tombstone { removed_at, reason_code });
+            TombstonesByBlock::<T>::insert(removed_at, binary_hash, ());
+
+            Self::deposit_event(Event::RecordRemoved {
+                image_hash: binary_hash,
+                reason_code,
+                reason,
+            });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prune expired tombstones and block-index entries
+        ///
+        /// Both passes are bounded per block; any remainder is picked up in the
+        /// following blocks.
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::prune_tombstones(now).saturating_add(Self::prune_block_index(now))
+        }
+    }
+
+    /// Public helper functions (not dispatchable)
+    impl<T: Config> Pallet<T> {
+        /// Remove tombstones older than `TombstoneRetention`
+        ///
+        /// Walks `TombstonesByBlock` forward from `TombstonePruneCursor`, spending at
+        /// most `MaxPrunedPerBlock` on removed tombstones and visited blocks combined.
+        pub(crate) fn prune_tombstones(now: BlockNumberFor<T>) -> Weight {
+            let retention = T::TombstoneRetention::get();
+            if now <= retention {
+                return Weight::zero();
+            }
+            let cutoff = now.saturating_sub(retention);
+
+            let mut cursor = TombstonePruneCursor::<T>::get();
+            let max = T::MaxPrunedPerBlock::get();
+            let mut budget = max;
+            let mut count = 0u32;
+            while cursor < cutoff && budget > 0 {
+                let mut pruned = 0u32;
+                let expired = TombstonesByBlock::<T>::drain_prefix(cursor).take(budget as usize);
+                for (hash, ()) in expired {
+                    Tombstones::<T>::remove(hash);
+                    pruned += 1;
+                }
+                count = count.saturating_add(pruned);
+                if pruned == budget {
+                    // Block possibly only partly pruned; resume from it next time
+                    budget = 0;
+                    break;
+                }
+                budget = budget.saturating_sub(pruned.max(1));
+                cursor = cursor.saturating_add(One::one());
+            }
+            TombstonePruneCursor::<T>::put(cursor);
+
+            if count > 0 {
+                Self::deposit_event(Event::TombstonesPruned { count });
+            }
+
+            // Visited blocks without tombstones are charged like a removal
+            T::WeightInfo::prune_tombstones(max.saturating_sub(budget))
+        }
+
+        /// Drop `RecordsByBlock` entries for blocks older than `BlockIndexRetention`
+        ///
+        /// Walks forward from `BlockIndexPruneCursor`, spending at most
+        /// `MaxBlockIndexPrunedPerBlock` on removed entries and visited blocks
+        /// combined. Records themselves are untouched.
+        pub(crate) fn prune_block_index(now: BlockNumberFor<T>) -> Weight {
+            let retention = T::BlockIndexRetention::get();
+            if now <= retention {
+                return Weight::zero();
+            }
+            let cutoff = now.saturating_sub(retention);
+
+            let mut cursor = BlockIndexPruneCursor::<T>::get();
+            let max = T::MaxBlockIndexPrunedPerBlock::get();
+            let mut budget = max;
+            while cursor < cutoff && budget > 0 {
+                let result = RecordsByBlock::<T>::clear_prefix(cursor, budget, None);
+                budget = budget.saturating_sub(result.backend.max(1));
+                if result.maybe_cursor.is_some() {
+                    // Block only partly cleared; resume from it next time
+                    break;
+                }
+                cursor = cursor.saturating_add(One::one());
+            }
+            BlockIndexPruneCursor::<T>::put(cursor);
+
+            // Visited blocks without entries are charged like a removal
+            T::WeightInfo::prune_block_index(max.saturating_sub(budget))
+        }
+
+        /// Weight of a batch submission of `records` entries, `parents` of which name a parent
+        ///
+        /// Records naming a parent are charged at the benchmarked cost of the
+        /// parent lookup and child count, so parent-heavy batches pay for them
+        /// instead of sharing the plain per-record weight.
+        pub fn batch_weight(records: usize, parents: usize) -> Weight {
+            let parents = parents.min(records);
+            T::WeightInfo::submit_image_batch(
+                records.saturating_sub(parents) as u32,
+                parents as u32,
+            )
+        }
+
+        /// Weight of `submit_image_record` when the authority is already registered
+        pub fn submit_image_record_existing_authority() -> Weight {
+            T::WeightInfo::submit_image_record_existing_authority()
+        }
+
+        /// Weight of `submit_image_record` when it auto-registers a new authority
+        ///
+        /// Registration additionally reads `NextAuthorityId` and writes the
+        /// registry entry, the reverse name index and the advanced ID counter.
+        pub fn submit_image_record_new_authority() -> Weight {
+            T::WeightInfo::submit_image_record_new_authority()
+        }
+
+        /// Convert hex string to binary hash [u8; 32]
+        ///
+        /// Accepts binary data (32 bytes) and hex strings (64 chars, optional `0x`
+        /// prefix). Delegates to `birthmark_verify` so off-chain verifiers parse
+        /// hashes exactly like the runtime does.
+        pub fn parse_image_hash(hash: &[u8]) -> Result<[u8; 32], Error<T>> {
+            birthmark_verify::parse_hash(hash).map_err(|_| Error::<T>::InvalidHashLength)
+        }
+
+        /// Validate and store a batch of records submitted by `who`
+        fn do_submit_batch(
+            who: T::AccountId,
+            records: Vec<(Vec<u8>, SubmissionType, u8, Option<Vec<u8>>, AuthorityRef)>,
+        ) -> DispatchResult {
+            Self::ensure_authorized_aggregator(&who)?;
+            Self::ensure_registry_active()?;
+            Self::note_submission(&who)?;
+
+            // Validate batch constraints
+            ensure!(!records.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(
+                records.len() as u32 <= T::MaxBatchSize::get(),
+                Error::<T>::BatchTooLarge
+            );
+
+            let count = records.len() as u32;
+
+            // Get timestamp and block number once for the entire batch
+            let timestamp = pallet_timestamp::Pallet::<T>::get();
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let timestamp_u32: u32 = timestamp.unique_saturated_into();
+            let block_number_u32: u32 = block_number.unique_saturated_into();
+            let validator_index = Self::current_validator_index();
+            let emit_detail = T::EmitBatchDetail::get();
+            let mut stored = Vec::new();
+
+            // Resolve each distinct authority once, so a new name repeated across
+            // the batch touches `NextAuthorityId` and the registry a single time
+            let mut authority_ids = BTreeMap::new();
+            for (_, _, _, _, authority) in records.iter() {
+                if !authority_ids.contains_key(authority) {
+                    let id = Self::resolve_authority(authority.clone())?;
+                    authority_ids.insert(authority.clone(), id);
+                }
+            }
+
+            // Process each record
+            for (image_hash, submission_type, modification_level, parent_image_hash, authority) in records {
+                // Validate modification level
+                ensure!(modification_level <= 2, Error::<T>::InvalidModificationLevel);
+                Self::ensure_parent_for_edit(modification_level, parent_image_hash.is_some())?;
+
+                // Parse image hash (accepts hex or binary)
+                let binary_hash = Self::parse_image_hash(&image_hash)?;
+
+                // Validate parent hash if provided
+                let parent_hash = if let Some(parent) = parent_image_hash {
+                    let parsed_parent = Self::parse_image_hash(&parent)?;
+                    ensure!(
+                        ImageRecords::<T>::contains_key(&parsed_parent),
+                        Error::<T>::ParentHashNotFound
+                    );
+                    // Siblings stored earlier in this batch already count
+                    Self::ensure_child_capacity(&parsed_parent)?;
+                    Some(parsed_parent)
+                } else {
+                    None
+                };
+
+                // Ensure hash doesn't already exist
+                ensure!(
+                    !ImageRecords::<T>::contains_key(&binary_hash),
+                    Error::<T>::HashAlreadyExists
+                );
+                ensure!(
+                    !Tombstones::<T>::contains_key(&binary_hash),
+                    Error::<T>::HashTombstoned
+                );
+
+                // Look up the pre-resolved authority ID
+                let authority_id = authority_ids
+                    .get(&authority)
+                    .copied()
+                    .ok_or(Error::<T>::AuthorityNotFound)?;
+
+                // Create record
+                let record = ImageRecord {
+                    image_hash: binary_hash,
+                    submission_type,
+                    modification_level,
+                    parent_image_hash: parent_hash,
+                    authority_id,
+                    timestamp: timestamp_u32,
+                    block_number: block_number_u32,
+                    validator_index,
+                    width: None,
+                    height: None,
+                    byte_size: None,
+                    schema: RECORD_SCHEMA,
+                };
+
+                // Earlier records of the batch already count towards the quota
+                Self::ensure_within_quota(authority_id)?;
+
+                // Apply deployment-specific acceptance checks
+                T::RecordValidator::validate(&who, &record)?;
+
+                // Store record and update counters
+                Self::store_record(Some(&who), record);
+                if emit_detail {
+                    stored.push(binary_hash);
+                }
+            }
+
+            Self::deposit_event(Event::ImageBatchSubmitted { count });
+            if emit_detail {
+                // At most `MaxBatchSize` records were accepted above
+                let hashes = BoundedVec::truncate_from(stored);
+                Self::deposit_event(Event::BatchDetail { hashes });
+            }
+
+            Ok(())
+        }
+
+        /// Resolve an authority reference to its registry ID
+        ///
+        /// IDs must already be registered and active; names go through
+        /// `register_or_get_authority`.
+        pub fn resolve_authority(authority: AuthorityRef) -> Result<AuthorityId, Error<T>> {
+            match authority {
+                AuthorityRef::Id(id) => {
+                    let info = AuthorityRegistry::<T>::get(id).ok_or(Error::<T>::AuthorityNotFound)?;
+                    ensure!(info.active, Error::<T>::AuthorityInactive);
+                    Self::ensure_authority_verified(id)?;
+                    Ok(id)
+                }
+                AuthorityRef::Name(name) => {
+                    Self::register_or_get_authority(name).map(|(id, _)| id)
+                }
+            }
+        }
+
+        /// Register a new authority or get existing authority ID
+        ///
+        /// This function searches for an existing authority with the same name.
+        /// If found, returns the existing ID. If not found, registers a new authority
+        /// unless `AllowAuthorityAutoRegistration` is off or the chain is in
+        /// `ProductionMode`, in which case unknown names fail with `AuthorityNotFound`.
+        /// Deactivated authorities are never reused for new submissions, and
+        /// unverified ones are refused while `RequireVerifiedAuthority` is on.
+        ///
+        /// The name is normalized first (see `NormalizeAuthorityNames`), so length
+        /// and charset limits apply to the normalized form. Names must be UTF-8
+        /// without control characters.
+        ///
+        /// Returns the authority ID and whether this call registered it.
+        pub fn register_or_get_authority(
+            authority_name: Vec<u8>,
+        ) -> Result<(AuthorityId, bool), Error<T>> {
+            let bounded_name = Self::validate_authority_name(authority_name)?;
+
+            // Look up existing authority
+            if let Some(id) = AuthorityIdByName::<T>::get(&bounded_name) {
+                ensure!(Self::is_authority_active(id), Error::<T>::AuthorityInactive);
+                Self::ensure_authority_verified(id)?;
+                return Ok((id, false));
+            }
+
+            // Strict deployments only accept governance-registered names
+            ensure!(
+                T::AllowAuthorityAutoRegistration::get() && !ProductionMode::<T>::get(),
+                Error::<T>::AuthorityNotFound
+            );
+            // A newly registered authority is never verified
+            ensure!(
+                !RequireVerifiedAuthority::<T>::get(),
+                Error::<T>::AuthorityNotVerified
+            );
+
+            Self::insert_authority(bounded_name).map(|id| (id, true))
+        }
+
+        /// Normalize an authority name and check its length and charset
+        fn validate_authority_name(
+            authority_name: Vec<u8>,
+        ) -> Result<BoundedVec<u8, T::MaxAuthorityIdLength>, Error<T>> {
+            let authority_name = Self::normalize_authority_name(authority_name);
+
+            // Validate length
+            ensure!(
+                authority_name.len() as u32 <= T::MaxAuthorityIdLength::get(),
+                Error::<T>::AuthorityNameTooLong
+            );
+            ensure!(
+                authority_name.len() as u32 >= T::MinAuthorityNameLength::get(),
+                Error::<T>::AuthorityNameTooShort
+            );
+
+            // Validate charset: no control characters, and UTF-8 unless opted out
+            match core::str::from_utf8(&authority_name) {
+                Ok(name) => ensure!(
+                    !name.chars().any(char::is_control),
+                    Error::<T>::AuthorityNameInvalidChars
+                ),
+                Err(_) => ensure!(
+                    !T::ValidateUtf8AuthorityNames::get(),
+                    Error::<T>::AuthorityNameNotUtf8
+                ),
+            }
+
+            authority_name
+                .try_into()
+                .map_err(|_| Error::<T>::AuthorityNameTooLong)
+        }
+
+        /// Assign the next ID to a validated, not yet registered name
+        ///
+        /// Never assigns an ID in the reserved range, even if `NextAuthorityId`
+        /// predates `FirstUserAuthorityId`.
+        fn insert_authority(
+            bounded_name: BoundedVec<u8, T::MaxAuthorityIdLength>,
+        ) -> Result<AuthorityId, Error<T>> {
+            let new_id = NextAuthorityId::<T>::get().max(T::FirstUserAuthorityId::get());
+            ensure!(new_id < AuthorityId::MAX, Error::<T>::TooManyAuthorities);
+            ensure!(new_id < T::MaxAuthorities::get(), Error::<T>::TooManyAuthorities);
+
+            NextAuthorityId::<T>::put(new_id.saturating_add(1));
+            Self::write_authority(new_id, bounded_name);
+            Self::warn_on_low_capacity();
+
+            Ok(new_id)
+        }
+
+        /// Emit `AuthorityCapacityLow` the first time ID usage reaches the warning threshold
+        ///
+        /// Every ID below `NextAuthorityId` counts as used, and so does the reserved
+        /// range below `FirstUserAuthorityId`, so `remaining` is what registration
+        /// can still hand out. `AuthorityCapacityWarned` limits the event to one
+        /// emission, even when the reserved range alone is past the threshold.
+        fn warn_on_low_capacity() {
+            let cap = T::MaxAuthorities::get().min(AuthorityId::MAX);
+            let threshold = T::AuthorityCapacityWarning::get().mul_ceil(cap);
+            // A zero threshold disables the warning
+            if threshold.is_zero() || AuthorityCapacityWarned::<T>::get() {
+                return;
+            }
+
+            let used = NextAuthorityId::<T>::get().max(T::FirstUserAuthorityId::get());
+            if used >= threshold {
+                AuthorityCapacityWarned::<T>::put(true);
+                Self::deposit_event(Event::AuthorityCapacityLow {
+                    remaining: cap.saturating_sub(used),
+                });
+            }
+        }
+
+        /// Store an active authority under `id`, index its name and emit the event
+        fn write_authority(id: AuthorityId, bounded_name: BoundedVec<u8, T::MaxAuthorityIdLength>) {
+            AuthorityRegistry::<T>::insert(
+                id,
+                AuthorityInfo {
+                    name: bounded_name.clone(),
+                    active: true,
+                },
+            );
+            AuthorityIdByName::<T>::insert(&bounded_name, id);
+
+            // Emit event
+            Self::deposit_event(Event::AuthorityRegistered {
+                authority_id: id,
+                authority_name: bounded_name,
+            });
+        }
+
+        /// Apply the configured authority-name normalization
+        ///
+        /// Trims leading/trailing ASCII whitespace and lowercases ASCII letters when
+        /// `NormalizeAuthorityNames` is enabled; returns the name unchanged otherwise.
+        pub fn normalize_authority_name(mut name: Vec<u8>) -> Vec<u8> {
+            if !T::NormalizeAuthorityNames::get() {
+                return name;
+            }
+
+            let start = name
+                .iter()
+                .position(|b| !b.is_ascii_whitespace())
+                .unwrap_or(name.len());
+            let end = name
+                .iter()
+                .rposition(|b| !b.is_ascii_whitespace())
+                .map_or(start, |last| last + 1);
+            name.truncate(end);
+            name.drain(..start);
+            name.make_ascii_lowercase();
+            name
+        }
+
+        /// Query an image record by its hash (public query function)
+        ///
+        /// This is used by RPC endpoints for fast verification queries.
+        pub fn get_image_record(hash: &[u8; 32]) -> Option<ImageRecord> {
+            ImageRecords::<T>::get(hash)
+        }
+
+        /// Compare a resubmission's authority with the one on the stored record
+        ///
+        /// Returns `(stored_authority, attempted_authority)` when a record exists
+        /// under `hash` and `authority_name` does not resolve to its authority.
+        /// `attempted_authority` is `None` if the name is not registered at all.
+        /// A mismatch may indicate someone trying to re-attribute an image.
+        pub fn resubmission_conflict(
+            hash: &[u8; 32],
+            authority_name: &[u8],
+        ) -> Option<(AuthorityId, Option<AuthorityId>)> {
+            let stored = ImageRecords::<T>::get(hash)?.authority_id;
+            let attempted = BoundedVec::<u8, T::MaxAuthorityIdLength>::try_from(
+                Self::normalize_authority_name(authority_name.to_vec()),
+            )
+            .ok()
+            .and_then(AuthorityIdByName::<T>::get);
+
+            (attempted != Some(stored)).then_some((stored, attempted))
+        }
+
+        /// Whether a resubmission matches the record stored under `hash`
+        ///
+        /// Compares the authority, modification level and parent. `false` if no
+        /// record exists or the parent hash does not parse.
+        pub fn is_identical_resubmission(
+            hash: &[u8; 32],
+            modification_level: u8,
+            parent_image_hash: Option<&[u8]>,
+            authority_name: &[u8],
+        ) -> bool {
+            let Some(stored) = ImageRecords::<T>::get(hash) else {
+                return false;
+            };
+            let parent = match parent_image_hash.map(Self::parse_image_hash).transpose() {
+                Ok(parent) => parent,
+                Err(_) => return false,
+            };
+
+            stored.modification_level == modification_level
+                && stored.parent_image_hash == parent
+                && Self::resubmission_conflict(hash, authority_name).is_none()
+        }
+
+        /// Human-readable label of a modification level, if one is set
+        pub fn modification_label(level: u8) -> Option<Vec<u8>> {
+            ModificationLabels::<T>::get(level).map(BoundedVec::into_inner)
+        }
+
+        /// IDs of the authorities seeded at genesis, in registration order
+        pub fn genesis_authorities() -> Vec<AuthorityId> {
+            GenesisAuthorities::<T>::get().into_inner()
+        }
+
+        /// Hashes of the `n` most recently stored records, newest first
+        ///
+        /// At most `MaxRecentRecords` are available.
+        pub fn recent_records(n: u32) -> Vec<[u8; 32]> {
+            RecentRecords::<T>::get().into_iter().rev().take(n as usize).collect()
+        }
+
+        /// Block in which a record was stored, without fetching the whole record
+        pub fn record_block(hash: &[u8; 32]) -> Option<u32> {
+            ImageRecords::<T>::get(hash).map(|record| record.block_number)
+        }
+
+        /// Query a record together with its authority's name
+        ///
+        /// Saves verifiers a second lookup. The name is `None` only if the registry
+        /// entry is missing, which would indicate corrupted state.
+        pub fn get_record_with_authority(hash: &[u8; 32]) -> Option<(ImageRecord, Option<Vec<u8>>)> {
+            let record = ImageRecords::<T>::get(hash)?;
+            let name = Self::get_authority_name(record.authority_id).map(|name| name.into_inner());
+            Some((record, name))
+        }
+
+        /// Walk parent links from a record towards its root, child first
+        ///
+        /// Stops at a missing (or removed) ancestor or after `MaxProvenanceDepth`
+        /// records. Returns an empty list if `hash` itself is not stored.
+        pub fn provenance_chain(hash: &[u8; 32]) -> Vec<ImageRecord> {
+            let max_depth = T::MaxProvenanceDepth::get() as usize;
+            let mut chain = Vec::new();
+            let mut next = Some(*hash);
+
+            while let Some(current) = next {
+                if chain.len() >= max_depth {
+                    break;
+                }
+                let Some(record) = ImageRecords::<T>::get(current) else {
+                    break;
+                };
+                next = record.parent_image_hash;
+                chain.push(record);
+            }
+
+            chain
+        }
+
+        /// Provenance chain with each record's authority name resolved
+        pub fn provenance_report(hash: &[u8; 32]) -> Vec<(ImageRecord, Option<Vec<u8>>)> {
+            Self::provenance_chain(hash)
+                .into_iter()
+                .map(|record| {
+                    let name = Self::get_authority_name(record.authority_id).map(|name| name.into_inner());
+                    (record, name)
+                })
+                .collect()
+        }
+
+        /// Event topic for an authority: `T::Hashing` over the SCALE-encoded `AuthorityId`
+        ///
+        /// Indexers look up `System::EventTopics` with this value to find an
+        /// authority's submissions without scanning every event.
+        pub fn authority_topic(authority_id: AuthorityId) -> T::Hash {
+            T::Hashing::hash_of(&authority_id)
+        }
+
+        /// Deposit an event indexed under an authority's topic
+        fn deposit_event_for_authority(authority_id: AuthorityId, event: Event<T>) {
+            let event = <T as Config>::RuntimeEvent::from(event);
+            frame_system::Pallet::<T>::deposit_event_indexed(
+                &[Self::authority_topic(authority_id)],
+                event.into(),
+            );
+        }
+
+        /// Index of the current block's author, read from the slot pre-runtime digest
+        fn current_validator_index() -> Option<u32> {
+            let digest = frame_system::Pallet::<T>::digest();
+            let pre_runtime_digests = digest.logs().iter().filter_map(|d| d.as_pre_runtime());
+            T::FindAuthor::find_author(pre_runtime_digests)
+        }
+
+        /// Check whether an account may submit records
+        ///
+        /// Consults the identity verifier or the aggregator allowlist, depending
+        /// on `UseIdentityAuthorization`.
+        pub fn is_authorized_aggregator(who: &T::AccountId) -> bool {
+            if T::UseIdentityAuthorization::get() {
+                T::IdentityVerifier::has_good_identity(who)
+            } else {
+                AuthorizedAggregators::<T>::contains_key(who)
+            }
+        }
+
+        /// Reject submissions from accounts outside the aggregator set
+        fn ensure_authorized_aggregator(who: &T::AccountId) -> Result<(), Error<T>> {
+            ensure!(Self::is_authorized_aggregator(who), Error::<T>::NotAuthorizedAggregator);
+            Ok(())
+        }
+
+        /// Reject submissions before the configured activation block
+        fn ensure_registry_active() -> Result<(), Error<T>> {
+            if let Some(activation) = ActivationBlock::<T>::get() {
+                ensure!(
+                    frame_system::Pallet::<T>::block_number() >= activation,
+                    Error::<T>::RegistryNotActive
+                );
+            }
+            Ok(())
+        }
+
+        /// Insert a validated record and update the record counters
+        ///
+        /// Per-account counts and the submitter index are skipped when there is
+        /// no submitting account (governance submissions).
+        fn store_record(submitter: Option<&T::AccountId>, record: ImageRecord) {
+            if let Some(who) = submitter {
+                SubmissionsByAccount::<T>::mutate(who, |count| {
+                    *count = count.saturating_add(1);
+                });
+                RecordsBySubmitter::<T>::insert(who, record.image_hash, ());
+                RecordSubmitter::<T>::insert(record.image_hash, who);
+            }
+            if let Some(parent) = record.parent_image_hash {
+                ChildCount::<T>::mutate(parent, |count| *count = count.saturating_add(1));
+            }
+            RecordsPerAuthority::<T>::mutate(record.authority_id, |count| {
+                *count = count.saturating_add(1);
+            });
+            RecordsPerLevel::<T>::mutate(|counts| {
+                if let Some(count) = counts.get_mut(record.modification_level as usize) {
+                    *count = count.saturating_add(1);
+                }
+            });
+            TotalRecords::<T>::mutate(|count| {
+                *count = count.saturating_add(1);
+            });
+            RecordsByBlock::<T>::insert(
+                frame_system::Pallet::<T>::block_number(),
+                record.image_hash,
+                (),
+            );
+            RecordsBySubmissionType::<T>::insert(&record.submission_type, record.image_hash, ());
+            if T::IndexRecordsByHashPrefix::get() {
+                RecordsByHashPrefix::<T>::insert(record.image_hash[0], record.image_hash, ());
+            }
+            RecentRecords::<T>::mutate(|recent| {
+                if recent.is_full() {
+                    recent.remove(0);
+                }
+                let _ = recent.try_push(record.image_hash);
+            });
+            ImageRecords::<T>::insert(record.image_hash, record);
+        }
+
+        /// Storage key of the `ImageRecords` entry for an image hash
+        ///
+        /// Light clients request a storage proof for this key and verify it against
+        /// a trusted state root (see [`crate::proof::verify_record_proof`]).
+        pub fn record_storage_key(hash: &[u8; 32]) -> Vec<u8> {
+            ImageRecords::<T>::hashed_key_for(hash)
+        }
+
+        /// Get authority name by ID
+        ///
+        /// Names resolve regardless of whether the authority is still active.
+        pub fn get_authority_name(id: AuthorityId) -> Option<BoundedVec<u8, T::MaxAuthorityIdLength>> {
+            AuthorityRegistry::<T>::get(id).map(|info| info.name)
+        }
+
+        /// Check whether an authority exists and accepts new submissions
+        pub fn is_authority_active(id: AuthorityId) -> bool {
+            AuthorityRegistry::<T>::get(id).is_some_and(|info| info.active)
+        }
+
+        /// Whether governance has verified an authority
+        pub fn is_authority_verified(id: AuthorityId) -> bool {
+            VerifiedAuthorities::<T>::contains_key(id)
+        }
+
+        /// Submission type assumed for a modification level when none is given
         ///
-        /// # Weight
+        /// Unmodified (level 0) images are taken to come straight from a camera;
+        /// validated or modified ones (levels 1-2) from editing software.
+        pub fn inferred_submission_type(modification_level: u8) -> SubmissionType {
+            if modification_level == 0 {
+                SubmissionType::Camera
+            } else {
+                SubmissionType::Software
+            }
+        }
+
+        /// Whether governance has sealed an authority, freezing its records
+        pub fn is_authority_sealed(id: AuthorityId) -> bool {
+            SealedAuthorities::<T>::contains_key(id)
+        }
+
+        /// Fail with `AuthoritySealed` if `id` has been sealed
+        fn ensure_not_sealed(id: AuthorityId) -> Result<(), Error<T>> {
+            ensure!(!Self::is_authority_sealed(id), Error::<T>::AuthoritySealed);
+            Ok(())
+        }
+
+        /// Fail with `MissingParentForEdit` if an edited record lacks a required parent
+        fn ensure_parent_for_edit(
+            modification_level: u8,
+            has_parent: bool,
+        ) -> Result<(), Error<T>> {
+            let required = T::RequireParentForEdits::get() || ProductionMode::<T>::get();
+            ensure!(
+                modification_level == 0 || has_parent || !required,
+                Error::<T>::MissingParentForEdit
+            );
+            Ok(())
+        }
+
+        /// Validate and store a single record, shared by the aggregator and governance paths
         ///
-        /// Weight is calculated based on:
-        /// - One storage read (check for duplicate)
-        /// - One storage write (insert record)
-        /// - One storage read+write (increment counter)
-        /// - Optional: authority registration (if new)
-        #[pallet::call_index(0)]
-        #[pallet::weight(10_000)] // TODO: Proper weight calculation
-        pub fn submit_image_record(
-            origin: OriginFor<T>,
+        /// `submitter` is `None` for governance submissions, which skip
+        /// `RecordValidator` and are not attributed to an account. The caller
+        /// checks the origin. `dimensions` is `(width, height, byte_size)`.
+        fn do_submit_record(
+            submitter: Option<&T::AccountId>,
             image_hash: Vec<u8>,
             submission_type: SubmissionType,
             modification_level: u8,
             parent_image_hash: Option<Vec<u8>>,
             authority_name: Vec<u8>,
-        ) -> DispatchResult {
-            // Verify origin is signed (authorization logic can be added via custom origin)
-            let _who = ensure_signed(origin)?;
-
+            (width, height, byte_size): (Option<u32>, Option<u32>, Option<u32>),
+        ) -> DispatchResultWithPostInfo {
             // Validate modification level
             ensure!(
                 modification_level <= 2,
                 Error::<T>::InvalidModificationLevel
             );
+            Self::ensure_parent_for_edit(modification_level, parent_image_hash.is_some())?;
 
             // Parse image hash (accepts hex or binary)
             let binary_hash = Self::parse_image_hash(&image_hash)?;
@@ -264,11 +2997,12 @@ pub mod pallet {
             let parent_hash = if let Some(parent) = parent_image_hash {
                 let parsed_parent = Self::parse_image_hash(&parent)?;
 
-                // Ensure parent exists in storage
+                // Ensure parent exists in storage and can take another child
                 ensure!(
                     ImageRecords::<T>::contains_key(&parsed_parent),
                     Error::<T>::ParentHashNotFound
                 );
+                Self::ensure_child_capacity(&parsed_parent)?;
 
                 Some(parsed_parent)
             } else {
@@ -280,9 +3014,13 @@ pub mod pallet {
                 !ImageRecords::<T>::contains_key(&binary_hash),
                 Error::<T>::HashAlreadyExists
             );
+            ensure!(
+                !Tombstones::<T>::contains_key(&binary_hash),
+                Error::<T>::HashTombstoned
+            );
 
-            // Register or lookup authority (returns u16 ID)
-            let authority_id = Self::register_or_get_authority(authority_name)?;
+            // Register or lookup authority (returns its ID and whether it is new)
+            let (authority_id, registered) = Self::register_or_get_authority(authority_name)?;
 
             // Get current timestamp and block number
             let timestamp = pallet_timestamp::Pallet::<T>::get();
@@ -301,217 +3039,582 @@ pub mod pallet {
                 authority_id,
                 timestamp: timestamp_u32,
                 block_number: block_number_u32,
+                validator_index: Self::current_validator_index(),
+                width: width.map(Compact),
+                height: height.map(Compact),
+                byte_size: byte_size.map(Compact),
+                schema: RECORD_SCHEMA,
             };
 
-            // Store record
-            ImageRecords::<T>::insert(&binary_hash, record);
+            Self::ensure_within_quota(authority_id)?;
 
-            // Increment total count
-            TotalRecords::<T>::mutate(|count| {
-                *count = count.saturating_add(1);
-            });
+            // Apply deployment-specific acceptance checks to aggregator submissions
+            if let Some(who) = submitter {
+                T::RecordValidator::validate(who, &record)?;
+            }
 
-            // Emit event
-            Self::deposit_event(Event::ImageRecordSubmitted {
-                image_hash: binary_hash,
+            // Store record and update counters
+            Self::store_record(submitter, record);
+
+            // Emit event, indexed by authority for topic subscribers
+            Self::deposit_event_for_authority(
                 authority_id,
-                modification_level,
-            });
+                Event::ImageRecordSubmitted {
+                    image_hash: binary_hash,
+                    authority_id,
+                    modification_level,
+                },
+            );
+
+            let actual_weight = if registered {
+                Self::submit_image_record_new_authority()
+            } else {
+                Self::submit_image_record_existing_authority()
+            };
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Fail with `SubmissionCooldown` if `who` submitted too recently, else
+        /// remember this block as its latest submission
+        fn note_submission(who: &T::AccountId) -> Result<(), Error<T>> {
+            let cooldown = T::SubmissionCooldown::get();
+            if cooldown.is_zero() {
+                return Ok(());
+            }
 
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                !LastSubmission::<T>::get(who).is_some_and(|last| now < last.saturating_add(cooldown)),
+                Error::<T>::SubmissionCooldown
+            );
+            LastSubmission::<T>::insert(who, now);
             Ok(())
         }
 
-        /// Submit multiple image records in a single transaction (batch submission - OPTIMIZED).
+        /// Fail with `TooManyChildren` if `parent` cannot take another child
+        fn ensure_child_capacity(parent: &[u8; 32]) -> Result<(), Error<T>> {
+            ensure!(
+                ChildCount::<T>::get(parent) < T::MaxChildrenPerParent::get(),
+                Error::<T>::TooManyChildren
+            );
+            Ok(())
+        }
+
+        /// Fail with `AuthorityQuotaExceeded` if `id` cannot take another record
+        fn ensure_within_quota(id: AuthorityId) -> Result<(), Error<T>> {
+            let count = RecordsPerAuthority::<T>::get(id);
+            ensure!(
+                !AuthorityQuota::<T>::get(id).is_some_and(|quota| count >= quota),
+                Error::<T>::AuthorityQuotaExceeded
+            );
+            Ok(())
+        }
+
+        /// Fail with `AuthorityNotVerified` if verification is required and `id` lacks it
+        fn ensure_authority_verified(id: AuthorityId) -> Result<(), Error<T>> {
+            ensure!(
+                !RequireVerifiedAuthority::<T>::get() || Self::is_authority_verified(id),
+                Error::<T>::AuthorityNotVerified
+            );
+            Ok(())
+        }
+
+        /// Flip the active flag of a registered authority
+        fn set_authority_active(id: AuthorityId, active: bool) -> Result<(), Error<T>> {
+            AuthorityRegistry::<T>::try_mutate(id, |maybe_info| {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::AuthorityNotFound)?;
+                info.active = active;
+                Ok(())
+            })
+        }
+
+        /// Report whether a hash is present, tombstoned or was never registered
+        pub fn record_status(hash: &[u8; 32]) -> RecordStatus {
+            if ImageRecords::<T>::contains_key(hash) {
+                RecordStatus::Present
+            } else if Tombstones::<T>::contains_key(hash) {
+                RecordStatus::Tombstoned
+            } else {
+                RecordStatus::Absent
+            }
+        }
+
+        /// Check if an image hash exists in storage
+        pub fn image_exists(hash: &[u8; 32]) -> bool {
+            ImageRecords::<T>::contains_key(hash)
+        }
+
+        /// Check existence of many image hashes at once
         ///
-        /// This is more gas-efficient than individual submissions when aggregators
-        /// have accumulated multiple validated images.
+        /// Results are positional: `result[i]` answers `hashes[i]`. Only the first
+        /// `MaxBulkQuerySize` hashes are checked, so callers should compare lengths.
+        pub fn images_exist(hashes: &[[u8; 32]]) -> Vec<bool> {
+            hashes
+                .iter()
+                .take(T::MaxBulkQuerySize::get() as usize)
+                .map(Self::image_exists)
+                .collect()
+        }
+
+        /// Fetch the records for many image hashes at once
         ///
-        /// OPTIMIZATION NOTES:
-        /// - Accepts hex or binary hashes
-        /// - Automatically registers authorities in lookup table
-        /// - Uses compact encoding for all numeric fields
-        /// - Removed owner_hash field
+        /// Positional like `images_exist`: `result[i]` is the record for `hashes[i]`,
+        /// or `None` if absent. Only the first `MaxBulkQuerySize` hashes are read.
+        pub fn get_records(hashes: &[[u8; 32]]) -> Vec<Option<ImageRecord>> {
+            hashes
+                .iter()
+                .take(T::MaxBulkQuerySize::get() as usize)
+                .map(ImageRecords::<T>::get)
+                .collect()
+        }
+
+        /// Fetch many records together with their authority names
         ///
-        /// # Arguments
+        /// Positional and capped at `MaxBulkQuerySize` like `get_records`; serves
+        /// bulk verification, e.g. of every image in an article.
+        pub fn get_records_with_authority(
+            hashes: &[[u8; 32]],
+        ) -> Vec<Option<(ImageRecord, Option<Vec<u8>>)>> {
+            hashes
+                .iter()
+                .take(T::MaxBulkQuerySize::get() as usize)
+                .map(Self::get_record_with_authority)
+                .collect()
+        }
+
+        /// On-chain storage version of the pallet
         ///
-        /// * `origin` - Must be signed by an authorized aggregator account
-        /// * `records` - Vector of record data (max 100 records per batch)
+        /// Lets upgrade tooling assert pre/post migration versions without decoding
+        /// raw storage.
+        pub fn storage_version() -> u16 {
+            Self::on_chain_storage_version().into()
+        }
+
+        /// Get the total number of records stored
+        pub fn get_total_records() -> u64 {
+            TotalRecords::<T>::get()
+        }
+
+        /// Get the statistics snapshot taken at a block
+        pub fn get_snapshot(block: BlockNumberFor<T>) -> Option<StatsSnapshot> {
+            Snapshots::<T>::get(block)
+        }
+
+        /// List all statistics snapshots, oldest first
+        pub fn list_snapshots() -> Vec<(BlockNumberFor<T>, StatsSnapshot)> {
+            let mut snapshots: Vec<_> = Snapshots::<T>::iter().collect();
+            snapshots.sort_by_key(|(block, _)| *block);
+            snapshots
+        }
+
+        /// Per-level record counts from each snapshot taken in `from..=to`, oldest first
         ///
-        /// # Errors
+        /// Entries are `(block, [raw, validated, modified])`; blocks without a
+        /// snapshot are skipped.
+        pub fn level_histogram(
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> Vec<(BlockNumberFor<T>, [u64; 3])> {
+            let mut histogram: Vec<_> = Snapshots::<T>::iter()
+                .filter(|(block, _)| *block >= from && *block <= to)
+                .map(|(block, snapshot)| (block, snapshot.records_per_level))
+                .collect();
+            histogram.sort_by_key(|(block, _)| *block);
+            histogram
+        }
+
+        /// Get the number of records attributed to an authority
+        pub fn records_count_for_authority(id: AuthorityId) -> u64 {
+            RecordsPerAuthority::<T>::get(id)
+        }
+
+        /// Summary trust score (0-100) of a registered authority
         ///
-        /// Returns error if:
-        /// - Batch is empty
-        /// - Batch exceeds maximum size (100 records)
-        /// - Any individual record validation fails
+        /// Computed on read, never stored:
+        /// - 50 points if governance has verified the authority
+        /// - 20 points if it is active
+        /// - 10 points for each order of magnitude of records, starting at 1,
+        ///   100 and 10,000 records (at most 30)
         ///
-        /// Note: This is an atomic operation - all records succeed or all fail.
-        #[pallet::call_index(1)]
-        #[pallet::weight(10_000 * records.len() as u64)] // TODO: Proper weight calculation
-        pub fn submit_image_batch(
-            origin: OriginFor<T>,
-            records: Vec<(
-                Vec<u8>,                // image_hash (hex or binary)
-                SubmissionType,         // submission_type
-                u8,                     // modification_level
-                Option<Vec<u8>>,        // parent_image_hash
-                Vec<u8>,                // authority_name
-            )>,
-        ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+        /// Deactivated authorities score 0 regardless. `None` if `id` is not registered.
+        pub fn authority_trust(id: AuthorityId) -> Option<u8> {
+            let info = AuthorityRegistry::<T>::get(id)?;
+            if !info.active {
+                return Some(0);
+            }
 
-            // Validate batch constraints
-            ensure!(!records.is_empty(), Error::<T>::EmptyBatch);
-            ensure!(records.len() <= 100, Error::<T>::BatchTooLarge);
+            let verified = if Self::is_authority_verified(id) { 50 } else { 0 };
+            let activity = match Self::records_count_for_authority(id) {
+                0 => 0,
+                1..=99 => 10,
+                100..=9_999 => 20,
+                _ => 30,
+            };
 
-            let count = records.len() as u32;
+            Some(20 + verified + activity)
+        }
 
-            // Get timestamp and block number once for the entire batch
-            let timestamp = pallet_timestamp::Pallet::<T>::get();
-            let block_number = frame_system::Pallet::<T>::block_number();
-            let timestamp_u32: u32 = timestamp.unique_saturated_into();
-            let block_number_u32: u32 = block_number.unique_saturated_into();
+        /// List the hashes of all records stored in a block
+        ///
+        /// Order within a block follows the storage hasher, not submission order.
+        /// Empty for blocks that fell out of `BlockIndexRetention`.
+        pub fn records_in_block(block: BlockNumberFor<T>) -> Vec<[u8; 32]> {
+            RecordsByBlock::<T>::iter_key_prefix(block).collect()
+        }
 
-            // Process each record
-            for (image_hash, submission_type, modification_level, parent_image_hash, authority_name) in records {
-                // Validate modification level
-                ensure!(modification_level <= 2, Error::<T>::InvalidModificationLevel);
+        /// List record hashes stored between `from` and `to` (inclusive), a page at a time
+        ///
+        /// Walks the block index in ascending block order from `start`, or from
+        /// `from` if `start` is `None`. Stops after `limit` hashes (capped at
+        /// `MaxBulkQuerySize`) or `MAX_BLOCKS_PER_RANGE_PAGE` visited blocks and
+        /// returns the cursor to pass as `start` for the next page, or `None` once
+        /// `to` is reached. Order within a block follows the storage hasher.
+        ///
+        /// Returns `None` if the range spans more than `MAX_BLOCK_RANGE` blocks.
+        pub fn records_in_range(
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+            start: Option<RangeCursor<BlockNumberFor<T>>>,
+            limit: u32,
+        ) -> Option<(Vec<[u8; 32]>, Option<RangeCursor<BlockNumberFor<T>>>)> {
+            if to.saturating_sub(from) > BlockNumberFor::<T>::from(MAX_BLOCK_RANGE) {
+                return None;
+            }
+            let limit = limit.min(T::MaxBulkQuerySize::get()) as usize;
+            let (mut block, mut after) = start.unwrap_or((from, None));
+            let mut hashes = Vec::new();
+            let mut visited = 0u32;
 
-                // Parse image hash (accepts hex or binary)
-                let binary_hash = Self::parse_image_hash(&image_hash)?;
+            while block <= to {
+                if hashes.len() == limit || visited == MAX_BLOCKS_PER_RANGE_PAGE {
+                    return Some((hashes, Some((block, after))));
+                }
+                visited += 1;
 
-                // Validate parent hash if provided
-                let parent_hash = if let Some(parent) = parent_image_hash {
-                    let parsed_parent = Self::parse_image_hash(&parent)?;
-                    ensure!(
-                        ImageRecords::<T>::contains_key(&parsed_parent),
-                        Error::<T>::ParentHashNotFound
-                    );
-                    Some(parsed_parent)
-                } else {
-                    None
+                let iter = match after.take() {
+                    Some(last) => {
+                        let start_key = RecordsByBlock::<T>::hashed_key_for(block, last);
+                        RecordsByBlock::<T>::iter_key_prefix_from(block, start_key)
+                    }
+                    None => RecordsByBlock::<T>::iter_key_prefix(block),
                 };
+                // Read one extra entry to learn whether the block has more
+                let room = limit - hashes.len();
+                let mut page: Vec<[u8; 32]> = iter.take(room.saturating_add(1)).collect();
+                if page.len() > room {
+                    page.truncate(room);
+                    hashes.extend(page);
+                    let last = hashes.last().copied();
+                    return Some((hashes, Some((block, last))));
+                }
+                hashes.extend(page);
 
-                // Ensure hash doesn't already exist
-                ensure!(
-                    !ImageRecords::<T>::contains_key(&binary_hash),
-                    Error::<T>::HashAlreadyExists
-                );
+                if block == to {
+                    break;
+                }
+                block = block.saturating_add(One::one());
+            }
 
-                // Register or lookup authority
-                let authority_id = Self::register_or_get_authority(authority_name)?;
+            Some((hashes, None))
+        }
 
-                // Create record
-                let record = ImageRecord {
-                    image_hash: binary_hash,
-                    submission_type,
-                    modification_level,
-                    parent_image_hash: parent_hash,
-                    authority_id,
-                    timestamp: timestamp_u32,
-                    block_number: block_number_u32,
-                };
+        /// Merkle root over every record hash stored between `from` and `to` (inclusive)
+        ///
+        /// Hashes are taken from the block index and sorted, so the root depends
+        /// only on the set of records. Each leaf is the BlakeTwo256 hash of
+        /// `MERKLE_LEAF_TAG` and a record hash; each level pairs adjacent nodes and
+        /// hashes `MERKLE_NODE_TAG` with their concatenation, so a node can never
+        /// pass for a leaf. An odd last node moves up unchanged. An empty range
+        /// yields all zeroes.
+        ///
+        /// The root reflects the block index at the queried block. Once blocks
+        /// fall out of `BlockIndexRetention` or a record is taken down with
+        /// `remove_record`, a previously published root for the range can no
+        /// longer be recomputed from chain state; anchor the root together with
+        /// the hashes it covers.
+        ///
+        /// Returns `None` if the range spans more than `MAX_BLOCK_RANGE` blocks or
+        /// holds more than `MAX_MERKLE_LEAVES` records.
+        pub fn records_merkle_root(
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> Option<[u8; 32]> {
+            if to.saturating_sub(from) > BlockNumberFor::<T>::from(MAX_BLOCK_RANGE) {
+                return None;
+            }
 
-                // Store record
-                ImageRecords::<T>::insert(&binary_hash, record);
-                TotalRecords::<T>::mutate(|c| *c = c.saturating_add(1));
+            let mut nodes: Vec<[u8; 32]> = Vec::new();
+            let mut block = from;
+            while block <= to {
+                for hash in RecordsByBlock::<T>::iter_key_prefix(block) {
+                    // Give up before the buffer outgrows the bound
+                    if nodes.len() >= MAX_MERKLE_LEAVES as usize {
+                        return None;
+                    }
+                    nodes.push(hash);
+                }
+                if block == to {
+                    break;
+                }
+                block = block.saturating_add(One::one());
             }
+            nodes.sort_unstable();
 
-            Self::deposit_event(Event::ImageBatchSubmitted { count });
+            if nodes.is_empty() {
+                return Some([0u8; 32]);
+            }
+            // Hash leaves, then fold each level into the front of the same buffer
+            for node in nodes.iter_mut() {
+                *node = BlakeTwo256::hash(&[&[MERKLE_LEAF_TAG][..], &node[..]].concat()).0;
+            }
+            let mut width = nodes.len();
+            while width > 1 {
+                for i in 0..width.div_ceil(2) {
+                    let (left, right) = (2 * i, 2 * i + 1);
+                    let parent = if right < width {
+                        let pair = [&[MERKLE_NODE_TAG][..], &nodes[left][..], &nodes[right][..]];
+                        BlakeTwo256::hash(&pair.concat()).0
+                    } else {
+                        nodes[left]
+                    };
+                    nodes[i] = parent;
+                }
+                width = width.div_ceil(2);
+            }
 
-            Ok(())
+            Some(nodes[0])
         }
-    }
 
-    /// Public helper functions (not dispatchable)
-    impl<T: Config> Pallet<T> {
-        /// Convert hex string to binary hash [u8; 32]
+        /// List record hashes of one submission type, a page at a time
         ///
-        /// Accepts both hex strings (64 chars) and binary data (32 bytes)
-        pub fn parse_image_hash(hash: &[u8]) -> Result<[u8; 32], Error<T>> {
-            match hash.len() {
-                32 => {
-                    // Already binary
-                    let mut result = [0u8; 32];
-                    result.copy_from_slice(hash);
-                    Ok(result)
+        /// Returns at most `limit` hashes (capped at `MaxBulkQuerySize`) following
+        /// `start`, or from the beginning if `start` is `None`. Pass the last hash
+        /// of a page as `start` to fetch the next one. Order follows the storage
+        /// hasher, not submission order.
+        pub fn records_by_submission_type(
+            kind: SubmissionType,
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<[u8; 32]> {
+            let limit = limit.min(T::MaxBulkQuerySize::get()) as usize;
+
+            match start {
+                Some(start) => {
+                    let start_key = RecordsBySubmissionType::<T>::hashed_key_for(&kind, start);
+                    RecordsBySubmissionType::<T>::iter_key_prefix_from(&kind, start_key)
+                        .take(limit)
+                        .collect()
                 }
-                64 => {
-                    // Hex string - convert to binary
-                    let mut result = [0u8; 32];
-                    for i in 0..32 {
-                        let byte_str = &hash[i * 2..i * 2 + 2];
-                        let byte = u8::from_str_radix(
-                            core::str::from_utf8(byte_str).map_err(|_| Error::<T>::InvalidHashLength)?,
-                            16,
-                        )
-                        .map_err(|_| Error::<T>::InvalidHashLength)?;
-                        result[i] = byte;
-                    }
-                    Ok(result)
+                None => RecordsBySubmissionType::<T>::iter_key_prefix(&kind).take(limit).collect(),
+            }
+        }
+
+        /// List record hashes starting with `prefix`, a page at a time
+        ///
+        /// Paging works like `records_by_submission_type`. Always empty while
+        /// `IndexRecordsByHashPrefix` is disabled.
+        pub fn records_with_prefix(
+            prefix: u8,
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<[u8; 32]> {
+            let limit = limit.min(T::MaxBulkQuerySize::get()) as usize;
+
+            match start {
+                Some(start) => {
+                    let start_key = RecordsByHashPrefix::<T>::hashed_key_for(prefix, start);
+                    RecordsByHashPrefix::<T>::iter_key_prefix_from(prefix, start_key)
+                        .take(limit)
+                        .collect()
                 }
-                _ => Err(Error::<T>::InvalidHashLength),
+                None => RecordsByHashPrefix::<T>::iter_key_prefix(prefix).take(limit).collect(),
             }
         }
 
-        /// Register a new authority or get existing authority ID
+        /// List hashes of records submitted by `who`, a page at a time
         ///
-        /// This function searches for an existing authority with the same name.
-        /// If found, returns the existing ID. If not found, registers a new authority.
-        pub fn register_or_get_authority(authority_name: Vec<u8>) -> Result<u16, Error<T>> {
-            // Validate length
-            ensure!(
-                authority_name.len() as u32 <= T::MaxAuthorityIdLength::get(),
-                Error::<T>::AuthorityNameTooLong
-            );
+        /// Paging works like `records_by_submission_type`. Records removed since
+        /// submission are no longer listed.
+        pub fn records_by_submitter(
+            who: &T::AccountId,
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<[u8; 32]> {
+            let limit = limit.min(T::MaxBulkQuerySize::get()) as usize;
 
-            let bounded_name: BoundedVec<u8, T::MaxAuthorityIdLength> = authority_name
-                .clone()
-                .try_into()
-                .map_err(|_| Error::<T>::AuthorityNameTooLong)?;
+            match start {
+                Some(start) => {
+                    let start_key = RecordsBySubmitter::<T>::hashed_key_for(who, start);
+                    RecordsBySubmitter::<T>::iter_key_prefix_from(who, start_key)
+                        .take(limit)
+                        .collect()
+                }
+                None => RecordsBySubmitter::<T>::iter_key_prefix(who).take(limit).collect(),
+            }
+        }
 
-            // Search for existing authority
-            for (id, stored_name) in AuthorityRegistry::<T>::iter() {
-                if stored_name == bounded_name {
-                    return Ok(id);
+        /// Page through every stored record in storage-key order
+        ///
+        /// Returns at most `limit` records (capped at `MaxBulkQuerySize`) after
+        /// `start`, or from the first key if `start` is `None`, together with the
+        /// key to resume from. The resume key is `None` once the map is exhausted.
+        pub fn list_records(
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> (Vec<ImageRecord>, Option<[u8; 32]>) {
+            let limit = limit.min(T::MaxBulkQuerySize::get()) as usize;
+            let iter = match start {
+                Some(start) => {
+                    ImageRecords::<T>::iter_from(ImageRecords::<T>::hashed_key_for(start))
                 }
+                None => ImageRecords::<T>::iter(),
+            };
+
+            // Read one extra entry to learn whether another page follows
+            let mut records: Vec<ImageRecord> =
+                iter.take(limit.saturating_add(1)).map(|(_, record)| record).collect();
+            let next = if records.len() > limit {
+                records.truncate(limit);
+                records.last().map(|record| record.image_hash)
+            } else {
+                None
+            };
+
+            (records, next)
+        }
+
+        /// Get the number of accepted records submitted by an account
+        pub fn submissions_by(who: &T::AccountId) -> u64 {
+            SubmissionsByAccount::<T>::get(who)
+        }
+
+        /// Every name an authority has had, oldest first; the last is its current name
+        ///
+        /// Empty if the authority is not registered. Only the last
+        /// `MaxNameHistory` names are kept.
+        pub fn authority_name_history(id: AuthorityId) -> Vec<Vec<u8>> {
+            let history = AuthorityNameHistory::<T>::get(id);
+            if history.is_empty() {
+                return Self::get_authority_name(id).map(|name| name.into_inner()).into_iter().collect();
             }
+            history.into_iter().map(BoundedVec::into_inner).collect()
+        }
 
-            // Register new authority
-            let new_id = NextAuthorityId::<T>::get();
-            ensure!(new_id < u16::MAX, Error::<T>::TooManyAuthorities);
+        /// Estimate how much state the registry occupies
+        ///
+        /// Iterates the authority registry (bounded by `MaxAuthorities`), so it
+        /// is meant for runtime API calls rather than dispatch.
+        pub fn storage_footprint() -> StorageFootprint {
+            let record_count = TotalRecords::<T>::get();
+            let authority_count = AuthorityRegistry::<T>::iter_keys().count() as u32;
 
-            AuthorityRegistry::<T>::insert(new_id, bounded_name.clone());
-            NextAuthorityId::<T>::put(new_id.saturating_add(1));
+            let record_bytes = ImageRecord::max_encoded_len() as u64;
+            let authority_bytes =
+                AuthorityInfo::<T::MaxAuthorityIdLength>::max_encoded_len() as u64;
 
-            // Emit event
-            Self::deposit_event(Event::AuthorityRegistered {
-                authority_id: new_id,
-                authority_name: bounded_name,
-            });
+            StorageFootprint {
+                record_count,
+                authority_count,
+                estimated_bytes: record_count
+                    .saturating_mul(record_bytes)
+                    .saturating_add(u64::from(authority_count).saturating_mul(authority_bytes)),
+            }
+        }
 
-            Ok(new_id)
+        /// The runtime's configured submission limits
+        pub fn config_constants() -> BirthmarkConstants {
+            BirthmarkConstants {
+                max_batch_size: T::MaxBatchSize::get(),
+                max_authority_id_length: T::MaxAuthorityIdLength::get(),
+                max_image_hash_length: T::MaxImageHashLength::get(),
+                max_provenance_depth: T::MaxProvenanceDepth::get(),
+            }
         }
 
-        /// Query an image record by its hash (public query function)
+        /// Hashes declared to be the same image as `hash`, in either direction
         ///
-        /// This is used by RPC endpoints for fast verification queries.
-        pub fn get_image_record(hash: &[u8; 32]) -> Option<ImageRecord> {
-            ImageRecords::<T>::get(hash)
+        /// The record `hash` links to (if any) comes first, followed by every
+        /// record linking to `hash`. Links are not followed transitively.
+        pub fn linked_records(hash: &[u8; 32]) -> Vec<[u8; 32]> {
+            AltHashLink::<T>::get(hash)
+                .into_iter()
+                .chain(AltHashLinkedFrom::<T>::iter_key_prefix(hash))
+                .collect()
         }
 
-        /// Get authority name by ID
-        pub fn get_authority_name(id: u16) -> Option<BoundedVec<u8, T::MaxAuthorityIdLength>> {
-            AuthorityRegistry::<T>::get(id)
+        /// Count a failed submission under its reason code in `RejectionCounts`
+        ///
+        /// Only errors raised by this pallet are counted; bad origins and other
+        /// pallets' errors are ignored. Must be called outside the failed call's
+        /// storage layer, or the increment is rolled back with it.
+        pub fn note_rejection(error: &DispatchError) {
+            if let DispatchError::Module(ModuleError { index, error, .. }) = error {
+                if usize::from(*index) == <Self as PalletInfoAccess>::index() {
+                    RejectionCounts::<T>::mutate(error[0], |count| {
+                        *count = count.saturating_add(1);
+                    });
+                }
+            }
         }
 
-        /// Check if an image hash exists in storage
-        pub fn image_exists(hash: &[u8; 32]) -> bool {
-            ImageRecords::<T>::contains_key(hash)
+        /// Emit `ConflictingResubmission` if a rejected resubmission claimed another authority
+        ///
+        /// For submissions that failed with `HashAlreadyExists`; does nothing if
+        /// `image_hash` does not parse or the claimed authority matches. Like
+        /// [`Pallet::note_rejection`], must be called outside the failed call's
+        /// storage layer, or the event is reverted with it.
+        pub fn note_resubmission(image_hash: &[u8], authority_name: &[u8]) {
+            let Ok(hash) = Self::parse_image_hash(image_hash) else {
+                return;
+            };
+            if let Some((stored_authority, attempted_authority)) =
+                Self::resubmission_conflict(&hash, authority_name)
+            {
+                Self::deposit_event(Event::ConflictingResubmission {
+                    hash,
+                    stored_authority,
+                    attempted_authority,
+                });
+            }
         }
 
-        /// Get the total number of records stored
-        pub fn get_total_records() -> u64 {
-            TotalRecords::<T>::get()
+        /// `(reason code, count)` for every recorded rejection reason, by code
+        pub fn rejection_stats() -> Vec<(u8, u64)> {
+            // Blake2 keys iterate in hash order; sort for a stable listing
+            let mut stats: Vec<(u8, u64)> = RejectionCounts::<T>::iter().collect();
+            stats.sort_unstable();
+            stats
+        }
+    }
+
+    impl<T: Config> Call<T> {
+        /// Whether this call submits records, and so needs an authorized aggregator
+        ///
+        /// Lets runtime transaction extensions single out submissions without
+        /// listing every submit variant themselves.
+        pub fn is_submission(&self) -> bool {
+            matches!(
+                self,
+                Call::submit_image_record { .. }
+                    | Call::submit_image_record_idempotent { .. }
+                    | Call::submit_image_record_inferred { .. }
+                    | Call::submit_image_batch { .. }
+                    | Call::submit_image_batch_with_refs { .. }
+            )
+        }
+
+        /// Image hash and claimed authority name of a single-record submission
+        ///
+        /// `None` for batches and every other call.
+        pub fn submitted_record(&self) -> Option<(&[u8], &[u8])> {
+            match self {
+                Call::submit_image_record { image_hash, authority_name, .. }
+                | Call::submit_image_record_idempotent { image_hash, authority_name, .. }
+                | Call::submit_image_record_inferred { image_hash, authority_name, .. } => {
+                    Some((image_hash.as_slice(), authority_name.as_slice()))
+                }
+                _ => None,
+            }
         }
     }
 }
@@ -0,0 +1,696 @@
+//! Storage migrations for the Birthmark pallet.
+//!
+//! Each module migrates storage from the previous version to the one in its name
+//! and is wrapped in a [`VersionedMigration`] so it only runs once.
+
+use super::*;
+use codec::Compact;
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, storage::unhashed,
+    traits::UncheckedOnRuntimeUpgrade,
+};
+
+/// Version 1: authority registry entries carry an `active` flag.
+pub mod v1 {
+    use super::*;
+    use frame_support::storage_alias;
+
+    /// `AuthorityRegistry` keyed by the pre-version 10 `u16` ID
+    #[storage_alias]
+    pub type AuthorityRegistry<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        u16,
+        AuthorityInfo<<T as Config>::MaxAuthorityIdLength>,
+        OptionQuery,
+    >;
+
+    /// Wraps bare authority names in [`AuthorityInfo`], marking every existing authority active.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            AuthorityRegistry::<T>::translate::<BoundedVec<u8, T::MaxAuthorityIdLength>, _>(
+                |_, name| {
+                    translated += 1;
+                    Some(AuthorityInfo { name, active: true })
+                },
+            );
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] guarded by the on-chain storage version.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 2: per-authority record counters.
+pub mod v2 {
+    use super::*;
+    use frame_support::storage_alias;
+    use v5::OldImageRecord;
+
+    /// `RecordsPerAuthority` keyed by the pre-version 10 `u16` ID
+    #[storage_alias]
+    pub type RecordsPerAuthority<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u16, u64, ValueQuery>;
+
+    /// Recomputes `RecordsPerAuthority` from the records already in storage.
+    ///
+    /// Iterates every record once; intended for the registry's early, small state.
+    /// Records are still in the version 4 layout here, so they are read raw.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let _ = RecordsPerAuthority::<T>::clear(u32::MAX, None);
+
+            let mut records = 0u64;
+            for hash in ImageRecords::<T>::iter_keys() {
+                records += 1;
+                let key = ImageRecords::<T>::hashed_key_for(hash);
+                if let Some(record) = unhashed::get::<OldImageRecord>(&key) {
+                    RecordsPerAuthority::<T>::mutate(record.authority_id, |count| {
+                        *count = count.saturating_add(1);
+                    });
+                }
+            }
+
+            T::DbWeight::get().reads_writes(records.saturating_mul(2), records)
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] guarded by the on-chain storage version.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 3: name-to-id reverse authority index.
+pub mod v3 {
+    use super::*;
+    use frame_support::storage_alias;
+    use v1::AuthorityRegistry;
+
+    /// `AuthorityIdByName` holding the pre-version 10 `u16` ID
+    #[storage_alias]
+    pub type AuthorityIdByName<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BoundedVec<u8, <T as Config>::MaxAuthorityIdLength>,
+        u16,
+        OptionQuery,
+    >;
+
+    /// Populates `AuthorityIdByName` from the existing registry entries.
+    ///
+    /// Names are indexed as stored; normalization is not applied retroactively.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut indexed = 0u64;
+            for (id, info) in AuthorityRegistry::<T>::iter() {
+                indexed += 1;
+                AuthorityIdByName::<T>::insert(&info.name, id);
+            }
+
+            T::DbWeight::get().reads_writes(indexed, indexed)
+        }
+    }
+
+    /// [`InnerMigrateV2ToV3`] guarded by the on-chain storage version.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 4: block-number index of record hashes.
+pub mod v4 {
+    use super::*;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use v5::OldImageRecord;
+
+    /// Backfills `RecordsByBlock` from the block number kept in each record.
+    ///
+    /// Records are still in the version 4 layout here, so they are read raw.
+    pub struct InnerMigrateV3ToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut records = 0u64;
+            for hash in ImageRecords::<T>::iter_keys() {
+                records += 1;
+                let key = ImageRecords::<T>::hashed_key_for(hash);
+                if let Some(record) = unhashed::get::<OldImageRecord>(&key) {
+                    RecordsByBlock::<T>::insert(
+                        BlockNumberFor::<T>::from(record.block_number),
+                        hash,
+                        (),
+                    );
+                }
+            }
+
+            T::DbWeight::get().reads_writes(records, records)
+        }
+    }
+
+    /// [`InnerMigrateV3ToV4`] guarded by the on-chain storage version.
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 5: records carry the authoring validator's index.
+pub mod v5 {
+    use super::*;
+
+    /// `ImageRecord` layout before `validator_index` was added
+    #[derive(Encode, Decode)]
+    pub struct OldImageRecord {
+        pub image_hash: [u8; 32],
+        pub submission_type: SubmissionType,
+        pub modification_level: u8,
+        pub parent_image_hash: Option<[u8; 32]>,
+        pub authority_id: u16,
+        #[codec(compact)]
+        pub timestamp: u32,
+        #[codec(compact)]
+        pub block_number: u32,
+    }
+
+    /// `ImageRecord` layout as of version 5
+    #[derive(Encode, Decode)]
+    pub struct ImageRecordV5 {
+        pub image_hash: [u8; 32],
+        pub submission_type: SubmissionType,
+        pub modification_level: u8,
+        pub parent_image_hash: Option<[u8; 32]>,
+        pub authority_id: u16,
+        #[codec(compact)]
+        pub timestamp: u32,
+        #[codec(compact)]
+        pub block_number: u32,
+        pub validator_index: Option<u32>,
+    }
+
+    /// Re-encodes every record with `validator_index: None`.
+    ///
+    /// The author of past blocks is not recoverable from state, so existing
+    /// records stay unattributed.
+    pub struct InnerMigrateV4ToV5<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            ImageRecords::<T>::translate::<OldImageRecord, _>(|_, old| {
+                translated += 1;
+                // Stored in the version 5 layout so that `v6` can pick it up
+                let record = ImageRecordV5 {
+                    image_hash: old.image_hash,
+                    submission_type: old.submission_type,
+                    modification_level: old.modification_level,
+                    parent_image_hash: old.parent_image_hash,
+                    authority_id: old.authority_id,
+                    timestamp: old.timestamp,
+                    block_number: old.block_number,
+                    validator_index: None,
+                };
+                Some(record)
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV4ToV5`] guarded by the on-chain storage version.
+    pub type MigrateV4ToV5<T> = VersionedMigration<
+        4,
+        5,
+        InnerMigrateV4ToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 6: optional image dimensions and file size.
+pub mod v6 {
+    use super::*;
+    use v5::ImageRecordV5;
+
+    /// `ImageRecord` layout as of version 6
+    #[derive(Encode, Decode)]
+    pub struct ImageRecordV6 {
+        pub image_hash: [u8; 32],
+        pub submission_type: SubmissionType,
+        pub modification_level: u8,
+        pub parent_image_hash: Option<[u8; 32]>,
+        pub authority_id: u16,
+        #[codec(compact)]
+        pub timestamp: u32,
+        #[codec(compact)]
+        pub block_number: u32,
+        pub validator_index: Option<u32>,
+        pub width: Option<Compact<u32>>,
+        pub height: Option<Compact<u32>>,
+        pub byte_size: Option<Compact<u32>>,
+    }
+
+    /// Re-encodes every record with no dimensions or size.
+    pub struct InnerMigrateV5ToV6<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            ImageRecords::<T>::translate::<ImageRecordV5, _>(|_, old| {
+                translated += 1;
+                Some(ImageRecordV6 {
+                    image_hash: old.image_hash,
+                    submission_type: old.submission_type,
+                    modification_level: old.modification_level,
+                    parent_image_hash: old.parent_image_hash,
+                    authority_id: old.authority_id,
+                    timestamp: old.timestamp,
+                    block_number: old.block_number,
+                    validator_index: old.validator_index,
+                    width: None,
+                    height: None,
+                    byte_size: None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV5ToV6`] guarded by the on-chain storage version.
+    pub type MigrateV5ToV6<T> = VersionedMigration<
+        5,
+        6,
+        InnerMigrateV5ToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 7: submission-type index of record hashes.
+pub mod v7 {
+    use super::*;
+
+    /// Backfills `RecordsBySubmissionType` from the records already in storage.
+    ///
+    /// Records are still in the version 6 layout here, so they are read raw.
+    pub struct InnerMigrateV6ToV7<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV6ToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut records = 0u64;
+            for hash in ImageRecords::<T>::iter_keys() {
+                records += 1;
+                let key = ImageRecords::<T>::hashed_key_for(hash);
+                if let Some(record) = unhashed::get::<v6::ImageRecordV6>(&key) {
+                    RecordsBySubmissionType::<T>::insert(&record.submission_type, hash, ());
+                }
+            }
+
+            T::DbWeight::get().reads_writes(records, records)
+        }
+    }
+
+    /// [`InnerMigrateV6ToV7`] guarded by the on-chain storage version.
+    pub type MigrateV6ToV7<T> = VersionedMigration<
+        6,
+        7,
+        InnerMigrateV6ToV7<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 8: first-byte shard index of record hashes.
+pub mod v8 {
+    use super::*;
+
+    /// Backfills `RecordsByHashPrefix` when `IndexRecordsByHashPrefix` is enabled.
+    pub struct InnerMigrateV7ToV8<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV7ToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if !T::IndexRecordsByHashPrefix::get() {
+                return Weight::zero();
+            }
+
+            let mut records = 0u64;
+            for hash in ImageRecords::<T>::iter_keys() {
+                records += 1;
+                RecordsByHashPrefix::<T>::insert(hash[0], hash, ());
+            }
+
+            T::DbWeight::get().reads_writes(records, records)
+        }
+    }
+
+    /// [`InnerMigrateV7ToV8`] guarded by the on-chain storage version.
+    pub type MigrateV7ToV8<T> = VersionedMigration<
+        7,
+        8,
+        InnerMigrateV7ToV8<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 9: records carry a schema version.
+pub mod v9 {
+    use super::*;
+    use v6::ImageRecordV6;
+
+    /// `ImageRecord` layout as of version 9
+    #[derive(Encode, Decode)]
+    pub struct ImageRecordV9 {
+        pub image_hash: [u8; 32],
+        pub submission_type: SubmissionType,
+        pub modification_level: u8,
+        pub parent_image_hash: Option<[u8; 32]>,
+        pub authority_id: u16,
+        #[codec(compact)]
+        pub timestamp: u32,
+        #[codec(compact)]
+        pub block_number: u32,
+        pub validator_index: Option<u32>,
+        pub width: Option<Compact<u32>>,
+        pub height: Option<Compact<u32>>,
+        pub byte_size: Option<Compact<u32>>,
+        pub schema: u8,
+    }
+
+    /// Re-encodes every record with `schema: 0`.
+    pub struct InnerMigrateV8ToV9<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV8ToV9<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            ImageRecords::<T>::translate::<ImageRecordV6, _>(|_, old| {
+                translated += 1;
+                // Stored in the version 9 layout so that `v10` can pick it up
+                let record = ImageRecordV9 {
+                    image_hash: old.image_hash,
+                    submission_type: old.submission_type,
+                    modification_level: old.modification_level,
+                    parent_image_hash: old.parent_image_hash,
+                    authority_id: old.authority_id,
+                    timestamp: old.timestamp,
+                    block_number: old.block_number,
+                    validator_index: old.validator_index,
+                    width: old.width,
+                    height: old.height,
+                    byte_size: old.byte_size,
+                    schema: 0,
+                };
+                Some(record)
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV8ToV9`] guarded by the on-chain storage version.
+    pub type MigrateV8ToV9<T> = VersionedMigration<
+        8,
+        9,
+        InnerMigrateV8ToV9<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 10: authority IDs widened from `u16` to [`AuthorityId`].
+pub mod v10 {
+    use super::*;
+    use frame_support::storage_alias;
+    use sp_std::vec::Vec;
+    use v9::ImageRecordV9;
+
+    type GenesisIdsV9 = BoundedVec<u16, MaxGenesisAuthorities>;
+
+    /// `AuthorityRegistry` keyed by the version 9 `u16` ID
+    #[storage_alias]
+    pub type AuthorityRegistry<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        u16,
+        AuthorityInfo<<T as Config>::MaxAuthorityIdLength>,
+        OptionQuery,
+    >;
+
+    /// `RecordsPerAuthority` keyed by the version 9 `u16` ID
+    #[storage_alias]
+    pub type RecordsPerAuthority<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u16, u64, ValueQuery>;
+
+    /// `VerifiedAuthorities` keyed by the version 9 `u16` ID
+    #[storage_alias]
+    pub type VerifiedAuthorities<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, u16, (), OptionQuery>;
+
+    /// Re-keys every authority-indexed map and re-encodes records, names and
+    /// genesis IDs with the wider ID.
+    ///
+    /// Old entries are collected before re-insertion because the old and new
+    /// keys share a prefix. The registry is bounded by `MaxAuthorities`, so
+    /// holding it in memory is fine. Existing IDs keep their value, which moves
+    /// their event topic: `authority_topic` now hashes four bytes, not two.
+    pub struct InnerMigrateV9ToV10<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV9ToV10<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut records = 0u64;
+            ImageRecords::<T>::translate::<ImageRecordV9, _>(|_, old| {
+                records += 1;
+                Some(ImageRecord {
+                    image_hash: old.image_hash,
+                    submission_type: old.submission_type,
+                    modification_level: old.modification_level,
+                    parent_image_hash: old.parent_image_hash,
+                    authority_id: old.authority_id.into(),
+                    timestamp: old.timestamp,
+                    block_number: old.block_number,
+                    validator_index: old.validator_index,
+                    width: old.width,
+                    height: old.height,
+                    byte_size: old.byte_size,
+                    schema: old.schema,
+                })
+            });
+
+            let registry: Vec<_> = AuthorityRegistry::<T>::drain().collect();
+            let counts: Vec<_> = RecordsPerAuthority::<T>::drain().collect();
+            let verified: Vec<_> = VerifiedAuthorities::<T>::drain().collect();
+            let authorities = registry.len() as u64;
+            let entries = authorities
+                .saturating_add(counts.len() as u64)
+                .saturating_add(verified.len() as u64);
+
+            for (id, info) in registry {
+                crate::AuthorityRegistry::<T>::insert(AuthorityId::from(id), info);
+            }
+            for (id, count) in counts {
+                crate::RecordsPerAuthority::<T>::insert(AuthorityId::from(id), count);
+            }
+            for (id, ()) in verified {
+                crate::VerifiedAuthorities::<T>::insert(AuthorityId::from(id), ());
+            }
+
+            AuthorityIdByName::<T>::translate::<u16, _>(|_, id| Some(id.into()));
+            let _ = NextAuthorityId::<T>::translate::<u16, _>(|id| id.map(Into::into));
+            let _ = GenesisAuthorities::<T>::translate::<GenesisIdsV9, _>(|ids| {
+                let ids: Vec<AuthorityId> = ids?.into_iter().map(Into::into).collect();
+                // Same length as before, so always within the bound
+                Some(BoundedVec::truncate_from(ids))
+            });
+
+            let reads =
+                records.saturating_add(entries).saturating_add(authorities).saturating_add(2);
+            let writes = records
+                .saturating_add(entries.saturating_mul(2))
+                .saturating_add(authorities)
+                .saturating_add(2);
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    /// [`InnerMigrateV9ToV10`] guarded by the on-chain storage version.
+    pub type MigrateV9ToV10<T> = VersionedMigration<
+        9,
+        10,
+        InnerMigrateV9ToV10<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 11: removal-block index of tombstones, and pruning cursors.
+pub mod v11 {
+    use super::*;
+
+    /// Backfills `TombstonesByBlock` and starts `TombstonePruneCursor` at the
+    /// oldest tombstone, or at the current block if there is none.
+    ///
+    /// `BlockIndexPruneCursor` likewise starts at the oldest block in
+    /// `RecordsByBlock`, so the pruning hook does not walk up from block zero.
+    pub struct InnerMigrateV10ToV11<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV10ToV11<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut cursor = frame_system::Pallet::<T>::block_number();
+            let mut tombstones = 0u64;
+            for (hash, tombstone) in Tombstones::<T>::iter() {
+                tombstones += 1;
+                TombstonesByBlock::<T>::insert(tombstone.removed_at, hash, ());
+                cursor = cursor.min(tombstone.removed_at);
+            }
+            TombstonePruneCursor::<T>::put(cursor);
+
+            let mut index_cursor = frame_system::Pallet::<T>::block_number();
+            let mut indexed = 0u64;
+            for (block, _) in RecordsByBlock::<T>::iter_keys() {
+                indexed += 1;
+                index_cursor = index_cursor.min(block);
+            }
+            BlockIndexPruneCursor::<T>::put(index_cursor);
+
+            T::DbWeight::get().reads_writes(
+                tombstones.saturating_add(indexed).saturating_add(1),
+                tombstones.saturating_add(2),
+            )
+        }
+    }
+
+    /// [`InnerMigrateV10ToV11`] guarded by the on-chain storage version.
+    pub type MigrateV10ToV11<T> = VersionedMigration<
+        10,
+        11,
+        InnerMigrateV10ToV11<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 12: per-level and per-parent record counters.
+pub mod v12 {
+    use super::*;
+
+    /// Recounts `RecordsPerLevel` and `ChildCount` from the records in storage.
+    ///
+    /// Both counters only track records stored after they were introduced, so
+    /// they are rebuilt from scratch rather than topped up.
+    pub struct InnerMigrateV11ToV12<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV11ToV12<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let cleared = ChildCount::<T>::clear(u32::MAX, None).unique as u64;
+
+            let mut records = 0u64;
+            let mut children = 0u64;
+            let mut per_level = [0u64; 3];
+            for record in ImageRecords::<T>::iter_values() {
+                records += 1;
+                if let Some(count) = per_level.get_mut(record.modification_level as usize) {
+                    *count = count.saturating_add(1);
+                }
+                if let Some(parent) = record.parent_image_hash {
+                    children += 1;
+                    ChildCount::<T>::mutate(parent, |count| *count = count.saturating_add(1));
+                }
+            }
+            RecordsPerLevel::<T>::put(per_level);
+
+            T::DbWeight::get().reads_writes(
+                records.saturating_add(children),
+                cleared.saturating_add(children).saturating_add(1),
+            )
+        }
+    }
+
+    /// [`InnerMigrateV11ToV12`] guarded by the on-chain storage version.
+    pub type MigrateV11ToV12<T> = VersionedMigration<
+        11,
+        12,
+        InnerMigrateV11ToV12<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 13: labels for modification levels.
+pub mod v13 {
+    use super::*;
+
+    /// Seeds `ModificationLabels` with `DEFAULT_MODIFICATION_LABELS`, as genesis
+    /// does. Levels that already have a label keep it.
+    pub struct InnerMigrateV12ToV13<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV12ToV13<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut seeded = 0u64;
+            for (level, label) in DEFAULT_MODIFICATION_LABELS.iter().enumerate() {
+                if ModificationLabels::<T>::contains_key(level as u8) {
+                    continue;
+                }
+                let label: BoundedVec<u8, MaxLabelLength> =
+                    label.to_vec().try_into().expect("default labels fit the bound");
+                ModificationLabels::<T>::insert(level as u8, label);
+                seeded += 1;
+            }
+
+            T::DbWeight::get().reads_writes(DEFAULT_MODIFICATION_LABELS.len() as u64, seeded)
+        }
+    }
+
+    /// [`InnerMigrateV12ToV13`] guarded by the on-chain storage version.
+    pub type MigrateV12ToV13<T> = VersionedMigration<
+        12,
+        13,
+        InnerMigrateV12ToV13<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Every migration from storage version 0 to [`STORAGE_VERSION`], in order
+pub type AllMigrations<T> = (
+    v1::MigrateV0ToV1<T>,
+    v2::MigrateV1ToV2<T>,
+    v3::MigrateV2ToV3<T>,
+    v4::MigrateV3ToV4<T>,
+    v5::MigrateV4ToV5<T>,
+    v6::MigrateV5ToV6<T>,
+    v7::MigrateV6ToV7<T>,
+    v8::MigrateV7ToV8<T>,
+    v9::MigrateV8ToV9<T>,
+    v10::MigrateV9ToV10<T>,
+    v11::MigrateV10ToV11<T>,
+    v12::MigrateV11ToV12<T>,
+    v13::MigrateV12ToV13<T>,
+);
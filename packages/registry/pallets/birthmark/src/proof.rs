@@ -0,0 +1,65 @@
+//! Storage proof verification for light clients.
+//!
+//! The node serves `birthmark_getRecordProof` by reading the trie nodes along a
+//! record's `ImageRecords` key at some block. A client that trusts that block's
+//! header (for example because it is GRANDPA-finalized) verifies the proof against
+//! the header's `state_root` without running a full node.
+
+use crate::{Config, ImageRecord, ImageRecords};
+use codec::Decode;
+use sp_state_machine::read_proof_check;
+use sp_trie::StorageProof;
+
+/// Reasons a record proof fails to verify
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The proof is malformed or does not match the state root
+    InvalidProof,
+    /// The proof does not show a record stored under the hash
+    NotIncluded,
+    /// The proven value is not a valid `ImageRecord`
+    UndecodableRecord,
+    /// The proof shows a record where its absence was expected
+    Included,
+}
+
+/// Verify that `proof` shows a record stored under `image_hash` in the state
+/// committed to by `state_root`, returning the proven record.
+///
+/// `state_root` must be taken from the header of the block the proof was generated
+/// at (returned as `stateRoot` by `birthmark_getRecordProof`).
+pub fn verify_record_proof<T: Config>(
+    state_root: T::Hash,
+    image_hash: &[u8; 32],
+    proof: Vec<Vec<u8>>,
+) -> Result<ImageRecord, ProofError> {
+    let key = ImageRecords::<T>::hashed_key_for(image_hash);
+
+    let mut values =
+        read_proof_check::<T::Hashing, _>(state_root, StorageProof::new(proof), [&key])
+            .map_err(|_| ProofError::InvalidProof)?;
+
+    let encoded = values.remove(&key).flatten().ok_or(ProofError::NotIncluded)?;
+    ImageRecord::decode(&mut &encoded[..]).map_err(|_| ProofError::UndecodableRecord)
+}
+
+/// Verify that `proof` shows no record stored under `image_hash` in the state
+/// committed to by `state_root`.
+///
+/// A node cannot hide a record by serving a partial proof: missing trie nodes
+/// make the check fail with `InvalidProof` rather than report absence.
+pub fn verify_record_absence<T: Config>(
+    state_root: T::Hash,
+    image_hash: &[u8; 32],
+    proof: Vec<Vec<u8>>,
+) -> Result<(), ProofError> {
+    let key = ImageRecords::<T>::hashed_key_for(image_hash);
+
+    let values = read_proof_check::<T::Hashing, _>(state_root, StorageProof::new(proof), [&key])
+        .map_err(|_| ProofError::InvalidProof)?;
+
+    match values.get(&key) {
+        Some(Some(_)) => Err(ProofError::Included),
+        _ => Ok(()),
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{self as pallet_birthmark, *};
+use codec::{Compact, MaxEncodedLen};
 use frame_support::{
-    assert_noop, assert_ok, derive_impl, parameter_types,
+    assert_noop, assert_ok, derive_impl,
+    dispatch::DispatchResultWithPostInfo,
+    parameter_types,
     traits::{ConstU32, ConstU64},
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{
+    traits::{Hash as _, IdentityLookup},
+    BuildStorage, DispatchError, ModuleError, Percent,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -13,6 +19,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Timestamp: pallet_timestamp,
+        Aura: pallet_aura,
         Birthmark: pallet_birthmark,
     }
 );
@@ -22,6 +29,8 @@ impl frame_system::Config for Test {
     type Block = Block;
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
+    // Non-zero so weights that count storage reads can be compared
+    type DbWeight = frame_support::weights::constants::RocksDbWeight;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -31,22 +40,109 @@ impl pallet_timestamp::Config for Test {
     type WeightInfo = ();
 }
 
+impl pallet_aura::Config for Test {
+    type AuthorityId = sp_consensus_aura::sr25519::AuthorityId;
+    type DisabledValidators = ();
+    type MaxAuthorities = ConstU32<10>;
+    type AllowMultipleBlocksPerSlot = frame_support::traits::ConstBool<false>;
+    type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Test>;
+}
+
 parameter_types! {
     pub const MaxAuthorityIdLength: u32 = 100;
-    pub const MaxImageHashLength: u32 = 64;
+    pub const MinAuthorityNameLength: u32 = 2;
+    pub const MaxImageHashLength: u32 = 66;
+    pub const MaxBulkQuerySize: u32 = 10;
+    pub const MaxReasonLength: u32 = 16;
+    pub const TombstoneRetention: u64 = 100;
+    pub static MaxPrunedPerBlock: u32 = 16;
+    pub const MutabilityWindow: u64 = 10;
+    pub static SubmissionCooldown: u64 = 0;
+    pub static BlockIndexRetention: u64 = 1_000;
+    pub static MaxBlockIndexPrunedPerBlock: u32 = 64;
+    pub static NormalizeAuthorityNames: bool = false;
+    pub static AllowAuthorityAutoRegistration: bool = true;
+    pub static ValidateUtf8AuthorityNames: bool = true;
+    pub static RequireParentForEdits: bool = false;
+    pub static EmitBatchDetail: bool = false;
+    pub static MaxAuthorities: u32 = 1_000;
+    pub static FirstUserAuthorityId: u32 = 0;
+    pub static AuthorityCapacityWarning: Percent = Percent::from_percent(90);
+    pub const MaxProvenanceDepth: u32 = 8;
+    pub static MaxChildrenPerParent: u32 = 100;
+    pub const MaxBatchSize: u32 = 100;
+    pub static RejectModifiedRecords: bool = false;
+    pub static UseIdentityAuthorization: bool = false;
+    pub static IndexRecordsByHashPrefix: bool = true;
+}
+
+/// Test identity source: only account 7 has a good judgement
+pub struct GoodIdentityForSeven;
+
+impl IdentityVerifier<u64> for GoodIdentityForSeven {
+    fn has_good_identity(who: &u64) -> bool {
+        *who == 7
+    }
+}
+
+/// Test validator rejecting modified (level 2) records while `RejectModifiedRecords` is set
+pub struct RejectModified;
+
+impl ValidateRecord<Test> for RejectModified {
+    fn validate(_submitter: &u64, record: &ImageRecord) -> Result<(), sp_runtime::DispatchError> {
+        if RejectModifiedRecords::get() && record.modification_level == 2 {
+            return Err(sp_runtime::DispatchError::Other("modified records rejected"));
+        }
+        Ok(())
+    }
 }
 
 impl pallet_birthmark::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxAuthorityIdLength = MaxAuthorityIdLength;
+    type MinAuthorityNameLength = MinAuthorityNameLength;
     type MaxImageHashLength = MaxImageHashLength;
+    type MaxAuthorities = MaxAuthorities;
+    type AuthorityCapacityWarning = AuthorityCapacityWarning;
+    type FirstUserAuthorityId = FirstUserAuthorityId;
+    type AllowAuthorityAutoRegistration = AllowAuthorityAutoRegistration;
+    type NormalizeAuthorityNames = NormalizeAuthorityNames;
+    type ValidateUtf8AuthorityNames = ValidateUtf8AuthorityNames;
+    type RequireParentForEdits = RequireParentForEdits;
+    type MaxProvenanceDepth = MaxProvenanceDepth;
+    type MaxChildrenPerParent = MaxChildrenPerParent;
+    type MaxBatchSize = MaxBatchSize;
+    type EmitBatchDetail = EmitBatchDetail;
+    type MaxBulkQuerySize = MaxBulkQuerySize;
+    type IndexRecordsByHashPrefix = IndexRecordsByHashPrefix;
+    type MaxReasonLength = MaxReasonLength;
+    type TombstoneRetention = TombstoneRetention;
+    type MaxPrunedPerBlock = MaxPrunedPerBlock;
+    type MutabilityWindow = MutabilityWindow;
+    type SubmissionCooldown = SubmissionCooldown;
+    type BlockIndexRetention = BlockIndexRetention;
+    type MaxBlockIndexPrunedPerBlock = MaxBlockIndexPrunedPerBlock;
+    type FindAuthor = Aura;
+    type UseIdentityAuthorization = UseIdentityAuthorization;
+    type IdentityVerifier = GoodIdentityForSeven;
+    type RecordValidator = RejectModified;
+    type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+    type RemovalOrigin = frame_system::EnsureRoot<u64>;
+    type WeightInfo = ();
 }
 
 // Helper function to create new test externalities
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
+    // Accounts 1 and 2 act as aggregators
+    pallet_birthmark::GenesisConfig::<Test> {
+        aggregators: vec![1, 2],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext = sp_io::TestExternalities::new(t);
     ext.execute_with(|| {
         // Set block number and timestamp to avoid zero values
@@ -56,10 +152,69 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
     ext
 }
 
-// Helper to create a test image hash
+// Helper to create a test image hash: `[id; 32]` as 64 lowercase hex chars
 fn test_hash(id: u8) -> Vec<u8> {
-    let mut hash = vec![id; 64];
-    hash
+    [id; 32].iter().flat_map(|byte| format!("{:02x}", byte).into_bytes()).collect()
+}
+
+type RawBatchRecord = (Vec<u8>, SubmissionType, u8, Option<Vec<u8>>, Vec<u8>);
+
+// Helper to build a batch entry from a raw record tuple
+fn batch_record(
+    (image_hash, submission_type, modification_level, parent, authority): RawBatchRecord,
+) -> BatchRecordOf<Test> {
+    BatchRecord {
+        image_hash: image_hash.try_into().unwrap(),
+        submission_type,
+        modification_level,
+        parent: parent.map(|parent| parent.try_into().unwrap()),
+        authority: authority.try_into().unwrap(),
+    }
+}
+
+// Helper to build a bounded ref-based batch from record tuples
+fn batch_with_refs(
+    records: Vec<(Vec<u8>, SubmissionType, u8, Option<Vec<u8>>, AuthorityRef)>,
+) -> BoundedVec<BatchRecordWithRefOf<Test>, MaxBatchSize> {
+    records
+        .into_iter()
+        .map(|(image_hash, submission_type, modification_level, parent, authority)| {
+            BatchRecordWithRef {
+                image_hash: image_hash.try_into().unwrap(),
+                submission_type,
+                modification_level,
+                parent: parent.map(|parent| parent.try_into().unwrap()),
+                authority,
+            }
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+// Helper to build a bounded batch from raw record tuples
+fn batch(records: Vec<RawBatchRecord>) -> BoundedVec<BatchRecordOf<Test>, MaxBatchSize> {
+    records
+        .into_iter()
+        .map(batch_record)
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+// Helper to submit a raw camera record with a binary (32-byte) hash
+fn submit_raw(hash: [u8; 32], authority_name: &[u8]) -> DispatchResultWithPostInfo {
+    Birthmark::submit_image_record(
+        RuntimeOrigin::signed(1),
+        hash.to_vec(),
+        SubmissionType::Camera,
+        0,
+        None,
+        authority_name.to_vec(),
+        None,
+        None,
+        None,
+    )
 }
 
 #[test]
@@ -76,13 +231,20 @@ fn submit_image_record_works() {
             0, // modification_level: raw
             None, // no parent
             authority_id.clone(),
+            None,
+            None,
+            None,
         ));
 
-        // Verify record was stored
-        let bounded_hash: BoundedVec<u8, ConstU32<64>> = hash.try_into().unwrap();
-        let record = Birthmark::image_records(&bounded_hash).unwrap();
+        // Verify record was stored under the binary hash
+        let record = Birthmark::image_records([1u8; 32]).unwrap();
+        assert_eq!(record.image_hash, [1u8; 32]);
         assert_eq!(record.modification_level, 0);
         assert_eq!(record.parent_image_hash, None);
+        assert_eq!(
+            Birthmark::get_authority_name(record.authority_id).unwrap().into_inner(),
+            authority_id
+        );
 
         // Verify total count increased
         assert_eq!(Birthmark::total_records(), 1);
@@ -90,8 +252,8 @@ fn submit_image_record_works() {
         // Verify event was emitted
         System::assert_last_event(
             Event::ImageRecordSubmitted {
-                image_hash: bounded_hash,
-                authority_id: authority_id.try_into().unwrap(),
+                image_hash: [1u8; 32],
+                authority_id: record.authority_id,
                 modification_level: 0,
             }
             .into(),
@@ -113,6 +275,9 @@ fn duplicate_hash_fails() {
             0,
             None,
             authority_id.clone(),
+            None,
+            None,
+            None,
         ));
 
         // Attempt duplicate submission
@@ -124,6 +289,9 @@ fn duplicate_hash_fails() {
                 0,
                 None,
                 authority_id,
+                None,
+                None,
+                None,
             ),
             Error::<Test>::HashAlreadyExists
         );
@@ -136,20 +304,26 @@ fn duplicate_hash_fails() {
 #[test]
 fn invalid_hash_length_fails() {
     new_test_ext().execute_with(|| {
-        let short_hash = vec![1u8; 32]; // Only 32 bytes instead of 64
+        let short_hash = vec![1u8; 31]; // Neither 32 binary bytes nor 64 hex chars
+        let non_hex = vec![b'z'; 64]; // Hex length, but not hex
         let authority_id = b"TEST_CAMERA".to_vec();
 
-        assert_noop!(
-            Birthmark::submit_image_record(
-                RuntimeOrigin::signed(1),
-                short_hash,
-                SubmissionType::Camera,
-                0,
-                None,
-                authority_id,
-            ),
-            Error::<Test>::InvalidHashLength
-        );
+        for hash in [short_hash, non_hex] {
+            assert_noop!(
+                Birthmark::submit_image_record(
+                    RuntimeOrigin::signed(1),
+                    hash,
+                    SubmissionType::Camera,
+                    0,
+                    None,
+                    authority_id.clone(),
+                    None,
+                    None,
+                    None,
+                ),
+                Error::<Test>::InvalidHashLength
+            );
+        }
     });
 }
 
@@ -167,6 +341,9 @@ fn invalid_modification_level_fails() {
                 3, // Invalid: must be 0, 1, or 2
                 None,
                 authority_id,
+                None,
+                None,
+                None,
             ),
             Error::<Test>::InvalidModificationLevel
         );
@@ -188,6 +365,9 @@ fn provenance_chain_works() {
             0, // raw
             None,
             authority_id.clone(),
+            None,
+            None,
+            None,
         ));
 
         // Submit processed image with raw as parent
@@ -198,16 +378,15 @@ fn provenance_chain_works() {
             1, // validated/processed
             Some(raw_hash.clone()),
             authority_id,
+            None,
+            None,
+            None,
         ));
 
         // Verify provenance chain
-        let bounded_processed: BoundedVec<u8, ConstU32<64>> = processed_hash.try_into().unwrap();
-        let record = Birthmark::image_records(&bounded_processed).unwrap();
+        let record = Birthmark::image_records([11u8; 32]).unwrap();
         assert_eq!(record.modification_level, 1);
-        assert!(record.parent_image_hash.is_some());
-
-        let bounded_raw: BoundedVec<u8, ConstU32<64>> = raw_hash.try_into().unwrap();
-        assert_eq!(record.parent_image_hash, Some(bounded_raw));
+        assert_eq!(record.parent_image_hash, Some([10u8; 32]));
 
         // Verify total count
         assert_eq!(Birthmark::total_records(), 2);
@@ -230,6 +409,9 @@ fn parent_hash_must_exist() {
                 1,
                 Some(nonexistent_parent),
                 authority_id,
+                None,
+                None,
+                None,
             ),
             Error::<Test>::ParentHashNotFound
         );
@@ -249,10 +431,12 @@ fn software_submission_works() {
             2, // modified
             None,
             authority_id,
+            None,
+            None,
+            None,
         ));
 
-        let bounded_hash: BoundedVec<u8, ConstU32<64>> = hash.try_into().unwrap();
-        let record = Birthmark::image_records(&bounded_hash).unwrap();
+        let record = Birthmark::image_records([30u8; 32]).unwrap();
         assert!(matches!(record.submission_type, SubmissionType::Software));
         assert_eq!(record.modification_level, 2);
     });
@@ -289,7 +473,7 @@ fn batch_submission_works() {
 
         assert_ok!(Birthmark::submit_image_batch(
             RuntimeOrigin::signed(1),
-            records,
+            batch(records),
         ));
 
         // Verify all records were stored
@@ -304,33 +488,48 @@ fn batch_submission_works() {
 fn empty_batch_fails() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Birthmark::submit_image_batch(RuntimeOrigin::signed(1), vec![]),
+            Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(vec![])),
             Error::<Test>::EmptyBatch
         );
     });
 }
 
 #[test]
-fn batch_too_large_fails() {
+fn batch_accepts_prefixed_hex_hashes() {
     new_test_ext().execute_with(|| {
-        let authority_id = b"TEST".to_vec();
-        let mut records = Vec::new();
+        let hash = format!("0x{}", "6b".repeat(32)).into_bytes();
+        assert_eq!(hash.len() as u32, MaxImageHashLength::get());
+
+        let records = vec![(hash, SubmissionType::Camera, 0, None, b"CANON".to_vec())];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+        assert!(Birthmark::image_exists(&[0x6bu8; 32]));
+    });
+}
 
+#[test]
+fn batch_too_large_fails() {
+    new_test_ext().execute_with(|| {
         // Create 101 records (exceeds max of 100)
-        for i in 0..101 {
-            records.push((
-                test_hash(i as u8),
-                SubmissionType::Camera,
-                0,
-                None,
-                authority_id.clone(),
-            ));
-        }
+        let records: Vec<_> = (0..101)
+            .map(|i| (test_hash(i as u8), SubmissionType::Camera, 0, None, b"TEST".to_vec()))
+            .collect();
 
-        assert_noop!(
-            Birthmark::submit_image_batch(RuntimeOrigin::signed(1), records),
-            Error::<Test>::BatchTooLarge
-        );
+        // `submit_image_batch` cannot even be constructed past the bound
+        let entries: Vec<_> = records.iter().cloned().map(batch_record).collect();
+        assert!(BoundedVec::<BatchRecordOf<Test>, MaxBatchSize>::try_from(entries).is_err());
+
+        // Neither can the ref-based batch
+        let refs: Vec<_> = records
+            .into_iter()
+            .map(|(hash, submission_type, level, parent, name)| BatchRecordWithRef {
+                image_hash: hash.try_into().unwrap(),
+                submission_type,
+                modification_level: level,
+                parent: parent.map(|parent| parent.try_into().unwrap()),
+                authority: AuthorityRef::Name(name),
+            })
+            .collect();
+        assert!(BoundedVec::<BatchRecordWithRefOf<Test>, MaxBatchSize>::try_from(refs).is_err());
     });
 }
 
@@ -341,9 +540,9 @@ fn helper_functions_work() {
         let authority_id = b"HELPER_TEST".to_vec();
 
         // Initially doesn't exist
-        let bounded_hash: BoundedVec<u8, ConstU32<64>> = hash.clone().try_into().unwrap();
-        assert!(!Birthmark::image_exists(&bounded_hash));
-        assert_eq!(Birthmark::get_image_record(&bounded_hash), None);
+        let binary_hash = [50u8; 32];
+        assert!(!Birthmark::image_exists(&binary_hash));
+        assert_eq!(Birthmark::get_image_record(&binary_hash), None);
 
         // Submit record
         assert_ok!(Birthmark::submit_image_record(
@@ -353,13 +552,3084 @@ fn helper_functions_work() {
             0,
             None,
             authority_id,
+            None,
+            None,
+            None,
         ));
 
         // Now exists
-        assert!(Birthmark::image_exists(&bounded_hash));
-        assert!(Birthmark::get_image_record(&bounded_hash).is_some());
+        assert!(Birthmark::image_exists(&binary_hash));
+        assert!(Birthmark::get_image_record(&binary_hash).is_some());
 
         // Total count updated
         assert_eq!(Birthmark::get_total_records(), 1);
     });
 }
+
+#[test]
+fn deactivated_authority_blocks_new_submissions() {
+    new_test_ext().execute_with(|| {
+        let old_hash = [60u8; 32];
+        assert_ok!(submit_raw(old_hash, b"COMPROMISED_CAM"));
+        let authority_id = Birthmark::get_image_record(&old_hash).unwrap().authority_id;
+
+        assert_ok!(Birthmark::deactivate_authority(RuntimeOrigin::root(), authority_id));
+        System::assert_last_event(Event::AuthorityDeactivated { authority_id }.into());
+        assert!(!Birthmark::is_authority_active(authority_id));
+
+        // New submissions under the deactivated authority are rejected
+        assert_noop!(
+            submit_raw([61u8; 32], b"COMPROMISED_CAM"),
+            Error::<Test>::AuthorityInactive
+        );
+
+        // Historical records remain queryable and their authority still resolves
+        let record = Birthmark::get_image_record(&old_hash).unwrap();
+        assert_eq!(
+            Birthmark::get_authority_name(record.authority_id).unwrap().into_inner(),
+            b"COMPROMISED_CAM".to_vec()
+        );
+        assert_eq!(Birthmark::get_total_records(), 1);
+    });
+}
+
+#[test]
+fn reactivated_authority_accepts_submissions() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([62u8; 32], b"ROTATED_CAM"));
+        let authority_id = Birthmark::get_image_record(&[62u8; 32]).unwrap().authority_id;
+
+        assert_ok!(Birthmark::deactivate_authority(RuntimeOrigin::root(), authority_id));
+        assert_ok!(Birthmark::reactivate_authority(RuntimeOrigin::root(), authority_id));
+        System::assert_last_event(Event::AuthorityReactivated { authority_id }.into());
+
+        assert_ok!(submit_raw([63u8; 32], b"ROTATED_CAM"));
+        assert_eq!(Birthmark::get_image_record(&[63u8; 32]).unwrap().authority_id, authority_id);
+    });
+}
+
+#[test]
+fn authority_deactivation_requires_governance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([64u8; 32], b"SOME_CAM"));
+
+        assert_noop!(
+            Birthmark::deactivate_authority(RuntimeOrigin::signed(1), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::deactivate_authority(RuntimeOrigin::root(), 42),
+            Error::<Test>::AuthorityNotFound
+        );
+    });
+}
+
+#[test]
+fn record_proof_verifies_against_state_root() {
+    use crate::proof::{verify_record_absence, verify_record_proof, ProofError};
+
+    let hash = [70u8; 32];
+    let mut ext = new_test_ext();
+    ext.execute_with(|| assert_ok!(submit_raw(hash, b"PROOF_CAM")));
+    ext.commit_all().unwrap();
+
+    let key = ext.execute_with(|| Birthmark::record_storage_key(&hash));
+    let state_root = *ext.backend.root();
+    let proof: Vec<Vec<u8>> = sp_state_machine::prove_read_on_trie_backend(&ext.backend, [&key])
+        .unwrap()
+        .into_iter_nodes()
+        .collect();
+
+    // The proof yields the stored record
+    let record = verify_record_proof::<Test>(state_root, &hash, proof.clone()).unwrap();
+    assert_eq!(record.image_hash, hash);
+    assert_eq!(record.modification_level, 0);
+
+    // A hash that was never stored is not proven by the same nodes
+    assert!(verify_record_proof::<Test>(state_root, &[71u8; 32], proof.clone()).is_err());
+
+    // A proof checked against the wrong root is rejected
+    assert_eq!(
+        verify_record_proof::<Test>(sp_core::H256::zero(), &hash, proof.clone()),
+        Err(ProofError::InvalidProof)
+    );
+    assert_eq!(
+        verify_record_absence::<Test>(state_root, &hash, proof),
+        Err(ProofError::Included)
+    );
+
+    // A proof over an absent key proves non-inclusion
+    let absent_key = ext.execute_with(|| Birthmark::record_storage_key(&[71u8; 32]));
+    let absence: Vec<Vec<u8>> =
+        sp_state_machine::prove_read_on_trie_backend(&ext.backend, [&absent_key])
+            .unwrap()
+            .into_iter_nodes()
+            .collect();
+    assert_eq!(verify_record_absence::<Test>(state_root, &[71u8; 32], absence), Ok(()));
+}
+
+#[test]
+fn records_per_authority_tracks_mixed_batches() {
+    new_test_ext().execute_with(|| {
+        let records = vec![
+            ([80u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([81u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"SONY".to_vec()),
+            ([82u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([83u8; 32].to_vec(), SubmissionType::Software, 2, None, b"ADOBE".to_vec()),
+        ];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+        assert_ok!(submit_raw([84u8; 32], b"SONY"));
+
+        let id_of = |hash: [u8; 32]| Birthmark::get_image_record(&hash).unwrap().authority_id;
+        assert_eq!(Birthmark::records_count_for_authority(id_of([80u8; 32])), 2);
+        assert_eq!(Birthmark::records_count_for_authority(id_of([81u8; 32])), 2);
+        assert_eq!(Birthmark::records_count_for_authority(id_of([83u8; 32])), 1);
+        assert_eq!(Birthmark::records_count_for_authority(99), 0);
+    });
+}
+
+/// Store a record in the layout used before storage version 5
+fn put_legacy_record(hash: [u8; 32], authority_id: u16, block_number: u32) {
+    let record = migrations::v5::OldImageRecord {
+        image_hash: hash,
+        submission_type: SubmissionType::Camera,
+        modification_level: 0,
+        parent_image_hash: None,
+        authority_id,
+        timestamp: 10,
+        block_number,
+    };
+    frame_support::storage::unhashed::put(&Birthmark::record_storage_key(&hash), &record);
+}
+
+#[test]
+fn migration_recomputes_records_per_authority() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        put_legacy_record([85u8; 32], 0, 1);
+        put_legacy_record([86u8; 32], 0, 1);
+        put_legacy_record([87u8; 32], 1, 2);
+        StorageVersion::new(1).put::<Birthmark>();
+
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Birthmark::on_chain_storage_version(), StorageVersion::new(2));
+        assert_eq!(migrations::v2::RecordsPerAuthority::<Test>::get(0), 2);
+        assert_eq!(migrations::v2::RecordsPerAuthority::<Test>::get(1), 1);
+    });
+}
+
+#[test]
+fn migration_v4_indexes_legacy_records_by_block() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        put_legacy_record([0x8au8; 32], 0, 3);
+        put_legacy_record([0x8bu8; 32], 0, 4);
+        StorageVersion::new(3).put::<Birthmark>();
+
+        migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert!(RecordsByBlock::<Test>::contains_key(3, [0x8au8; 32]));
+        assert!(RecordsByBlock::<Test>::contains_key(4, [0x8bu8; 32]));
+        assert_eq!(Birthmark::storage_version(), 4);
+    });
+}
+
+#[test]
+fn submissions_gated_until_activation_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::set_activation_block(RuntimeOrigin::root(), Some(5)));
+        System::assert_last_event(Event::ActivationBlockSet { block: Some(5) }.into());
+
+        // Before activation: rejected, reads still work
+        assert_noop!(
+            submit_raw([90u8; 32], b"LAUNCH_CAM"),
+            Error::<Test>::RegistryNotActive
+        );
+        assert!(!Birthmark::image_exists(&[90u8; 32]));
+
+        // At the activation block: accepted
+        System::set_block_number(5);
+        assert_ok!(submit_raw([90u8; 32], b"LAUNCH_CAM"));
+    });
+}
+
+#[test]
+fn clearing_activation_block_opens_registry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::set_activation_block(RuntimeOrigin::root(), Some(100)));
+        assert_noop!(
+            submit_raw([91u8; 32], b"LAUNCH_CAM"),
+            Error::<Test>::RegistryNotActive
+        );
+
+        assert_ok!(Birthmark::set_activation_block(RuntimeOrigin::root(), None));
+        assert_ok!(submit_raw([91u8; 32], b"LAUNCH_CAM"));
+
+        assert_noop!(
+            Birthmark::set_activation_block(RuntimeOrigin::signed(1), Some(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn images_exist_preserves_order() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([100u8; 32], b"GALLERY_CAM"));
+        assert_ok!(submit_raw([102u8; 32], b"GALLERY_CAM"));
+
+        let hashes = [[101u8; 32], [100u8; 32], [103u8; 32], [102u8; 32]];
+        assert_eq!(Birthmark::images_exist(&hashes), vec![false, true, false, true]);
+        assert!(Birthmark::images_exist(&[]).is_empty());
+    });
+}
+
+#[test]
+fn images_exist_is_capped() {
+    new_test_ext().execute_with(|| {
+        let hashes = vec![[104u8; 32]; 15];
+        assert_eq!(Birthmark::images_exist(&hashes).len(), 10);
+    });
+}
+
+#[test]
+fn reclassify_record_works() {
+    new_test_ext().execute_with(|| {
+        let parent = [110u8; 32];
+        let hash = [111u8; 32];
+        assert_ok!(submit_raw(parent, b"REVIEW_CAM"));
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            hash.to_vec(),
+            SubmissionType::Software,
+            2,
+            Some(parent.to_vec()),
+            b"REVIEW_APP".to_vec(),
+            None,
+            None,
+            None,
+        ));
+        let before = Birthmark::get_image_record(&hash).unwrap();
+
+        assert_ok!(Birthmark::reclassify_record(RuntimeOrigin::root(), hash.to_vec(), 1));
+        System::assert_last_event(
+            Event::RecordReclassified { image_hash: hash, old_level: 2, new_level: 1 }.into(),
+        );
+
+        // Only the level changed
+        let after = Birthmark::get_image_record(&hash).unwrap();
+        assert_eq!(after.modification_level, 1);
+        assert_eq!(after.image_hash, before.image_hash);
+        assert_eq!(after.parent_image_hash, Some(parent));
+        assert_eq!(after.authority_id, before.authority_id);
+    });
+}
+
+#[test]
+fn reclassify_record_rejects_invalid_level() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([112u8; 32], b"REVIEW_CAM"));
+
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::root(), [112u8; 32].to_vec(), 3),
+            Error::<Test>::InvalidModificationLevel
+        );
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::root(), [113u8; 32].to_vec(), 1),
+            Error::<Test>::RecordNotFound
+        );
+    });
+}
+
+#[test]
+fn reclassify_record_requires_governance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([114u8; 32], b"REVIEW_CAM"));
+
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::signed(1), [114u8; 32].to_vec(), 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn statistics_snapshots_reflect_counts_at_snapshot_time() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([120u8; 32], b"STATS_CAM"));
+        assert_ok!(Birthmark::snapshot_statistics(RuntimeOrigin::root()));
+
+        System::set_block_number(2);
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            [121u8; 32].to_vec(),
+            SubmissionType::Software,
+            2,
+            Some([120u8; 32].to_vec()),
+            b"STATS_APP".to_vec(),
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Birthmark::snapshot_statistics(RuntimeOrigin::root()));
+
+        let first = Birthmark::get_snapshot(1).unwrap();
+        assert_eq!(first, StatsSnapshot { total_records: 1, records_per_level: [1, 0, 0] });
+        let second = Birthmark::get_snapshot(2).unwrap();
+        assert_eq!(second, StatsSnapshot { total_records: 2, records_per_level: [1, 0, 1] });
+        System::assert_last_event(Event::StatisticsSnapshot { block: 2, snapshot: second.clone() }.into());
+
+        assert_eq!(Birthmark::list_snapshots(), vec![(1, first), (2, second)]);
+
+        assert_noop!(
+            Birthmark::snapshot_statistics(RuntimeOrigin::signed(1)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn normalized_authority_names_collapse() {
+    new_test_ext().execute_with(|| {
+        NormalizeAuthorityNames::set(true);
+
+        assert_ok!(submit_raw([130u8; 32], b"Canon"));
+        assert_ok!(submit_raw([131u8; 32], b" canon "));
+
+        let first = Birthmark::get_image_record(&[130u8; 32]).unwrap().authority_id;
+        let second = Birthmark::get_image_record(&[131u8; 32]).unwrap().authority_id;
+        assert_eq!(first, second);
+        assert_eq!(Birthmark::get_authority_name(first).unwrap().into_inner(), b"canon".to_vec());
+        let canon: BoundedVec<u8, MaxAuthorityIdLength> = b"canon".to_vec().try_into().unwrap();
+        assert_eq!(Birthmark::authority_id_by_name(canon), Some(first));
+        assert_eq!(Birthmark::next_authority_id(), 1);
+    });
+}
+
+#[test]
+fn authority_names_stay_distinct_without_normalization() {
+    new_test_ext().execute_with(|| {
+        NormalizeAuthorityNames::set(false);
+
+        assert_ok!(submit_raw([132u8; 32], b"Canon"));
+        assert_ok!(submit_raw([133u8; 32], b" canon "));
+
+        let first = Birthmark::get_image_record(&[132u8; 32]).unwrap().authority_id;
+        let second = Birthmark::get_image_record(&[133u8; 32]).unwrap().authority_id;
+        assert_ne!(first, second);
+        assert_eq!(Birthmark::get_authority_name(second).unwrap().into_inner(), b" canon ".to_vec());
+    });
+}
+
+#[test]
+fn submissions_by_account_counts_single_and_batch() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([134u8; 32], b"CANON"));
+
+        let records = vec![
+            ([135u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([136u8; 32].to_vec(), SubmissionType::Software, 1, None, b"ADOBE".to_vec()),
+        ];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(2),
+            batch(vec![([137u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"SONY".to_vec())]),
+        ));
+
+        // Rejected submissions do not count
+        assert_noop!(submit_raw([134u8; 32], b"CANON"), Error::<Test>::HashAlreadyExists);
+
+        assert_eq!(Birthmark::submissions_by(&1), 3);
+        assert_eq!(Birthmark::submissions_by(&2), 1);
+        assert_eq!(Birthmark::submissions_by(&3), 0);
+    });
+}
+
+#[test]
+fn records_in_range_walks_block_index() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([138u8; 32], b"CANON"));
+
+        System::set_block_number(2);
+        let records = vec![
+            ([139u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([140u8; 32].to_vec(), SubmissionType::Software, 1, None, b"ADOBE".to_vec()),
+        ];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+
+        assert_eq!(Birthmark::records_in_block(1), vec![[138u8; 32]]);
+
+        let mut second = Birthmark::records_in_block(2);
+        second.sort();
+        assert_eq!(second, vec![[139u8; 32], [140u8; 32]]);
+
+        let range = |from, to, limit| Birthmark::records_in_range(from, to, None, limit).unwrap();
+        assert_eq!(range(1, 1, 10), (vec![[138u8; 32]], None));
+        assert_eq!(range(1, 2, 10).0.len(), 3);
+        assert_eq!(range(2, 5, 10).0.len(), 2);
+        assert!(range(3, 5, 10).0.is_empty());
+
+        // The limit stops the walk mid-block
+        let (page, cursor) = range(1, 2, 2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(cursor, Some((2, Some(page[1]))));
+    });
+}
+
+#[test]
+fn records_in_range_pages_through_a_full_block() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0x9cu8; 32], b"CANON"));
+        System::set_block_number(2);
+        assert_ok!(submit_raw([0x9du8; 32], b"CANON"));
+        assert_ok!(submit_raw([0x9eu8; 32], b"CANON"));
+
+        // One hash per page, so block 2 spans two pages
+        let mut seen = Vec::new();
+        let mut start = None;
+        for _ in 0..4 {
+            let (page, next) = Birthmark::records_in_range(1, 2, start, 1).unwrap();
+            seen.extend(page);
+            start = next;
+            if start.is_none() {
+                break;
+            }
+        }
+        assert!(start.is_none());
+        seen.sort();
+        assert_eq!(seen, vec![[0x9cu8; 32], [0x9du8; 32], [0x9eu8; 32]]);
+    });
+}
+
+#[test]
+fn records_in_range_is_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0x9fu8; 32], b"CANON"));
+
+        // Too wide a range is refused outright
+        assert!(Birthmark::records_in_range(0, MAX_BLOCK_RANGE as u64 + 1, None, 10).is_none());
+
+        // A long empty stretch is walked a bounded number of blocks at a time
+        let to = u64::from(MAX_BLOCK_RANGE);
+        let (page, next) = Birthmark::records_in_range(1, to, None, 10).unwrap();
+        assert_eq!(page, vec![[0x9fu8; 32]]);
+        let resume = u64::from(MAX_BLOCKS_PER_RANGE_PAGE) + 1;
+        assert_eq!(next, Some((resume, None)));
+
+        let (page, next) = Birthmark::records_in_range(1, to, next, 10).unwrap();
+        assert!(page.is_empty());
+        assert_eq!(next, Some((resume + u64::from(MAX_BLOCKS_PER_RANGE_PAGE), None)));
+    });
+}
+
+#[test]
+fn batch_registers_new_authority_once() {
+    new_test_ext().execute_with(|| {
+        let records = vec![
+            ([141u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"FUJIFILM".to_vec()),
+            ([142u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"FUJIFILM".to_vec()),
+            ([143u8; 32].to_vec(), SubmissionType::Camera, 1, None, b"FUJIFILM".to_vec()),
+        ];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+
+        let events: Vec<_> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::Birthmark(event) => Some(event),
+                _ => None,
+            })
+            .collect();
+
+        let registered = events
+            .iter()
+            .filter(|event| matches!(event, Event::AuthorityRegistered { .. }))
+            .count();
+        let batches = events
+            .iter()
+            .filter(|event| matches!(event, Event::ImageBatchSubmitted { count: 3 }))
+            .count();
+        assert_eq!(registered, 1);
+        assert_eq!(batches, 1);
+    });
+}
+
+#[test]
+fn batch_with_refs_mixes_ids_and_names() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([144u8; 32], b"CANON"));
+        let canon = Birthmark::get_image_record(&[144u8; 32]).unwrap().authority_id;
+
+        let records = vec![
+            ([145u8; 32].to_vec(), SubmissionType::Camera, 0, None, AuthorityRef::Id(canon)),
+            ([146u8; 32].to_vec(), SubmissionType::Software, 1, None, AuthorityRef::Name(b"ADOBE".to_vec())),
+            ([147u8; 32].to_vec(), SubmissionType::Camera, 0, None, AuthorityRef::Name(b"CANON".to_vec())),
+        ];
+        assert_ok!(Birthmark::submit_image_batch_with_refs(
+            RuntimeOrigin::signed(1),
+            batch_with_refs(records)
+        ));
+        System::assert_last_event(Event::ImageBatchSubmitted { count: 3 }.into());
+
+        assert_eq!(Birthmark::get_image_record(&[145u8; 32]).unwrap().authority_id, canon);
+        assert_eq!(Birthmark::get_image_record(&[147u8; 32]).unwrap().authority_id, canon);
+        let adobe = Birthmark::get_image_record(&[146u8; 32]).unwrap().authority_id;
+        assert_eq!(Birthmark::get_authority_name(adobe).unwrap().into_inner(), b"ADOBE".to_vec());
+        assert_eq!(Birthmark::records_count_for_authority(canon), 3);
+    });
+}
+
+#[test]
+fn batch_with_refs_rejects_unknown_id() {
+    new_test_ext().execute_with(|| {
+        let records = vec![
+            ([148u8; 32].to_vec(), SubmissionType::Camera, 0, None, AuthorityRef::Name(b"CANON".to_vec())),
+            ([149u8; 32].to_vec(), SubmissionType::Camera, 0, None, AuthorityRef::Id(42)),
+        ];
+        assert_noop!(
+            Birthmark::submit_image_batch_with_refs(RuntimeOrigin::signed(1), batch_with_refs(records)),
+            Error::<Test>::AuthorityNotFound
+        );
+    });
+}
+
+#[test]
+fn record_with_authority_resolves_name() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([150u8; 32], b"NIKON"));
+
+        let (record, name) = Birthmark::get_record_with_authority(&[150u8; 32]).unwrap();
+        assert_eq!(record, Birthmark::get_image_record(&[150u8; 32]).unwrap());
+        assert_eq!(name, Some(b"NIKON".to_vec()));
+
+        assert!(Birthmark::get_record_with_authority(&[151u8; 32]).is_none());
+    });
+}
+
+#[test]
+fn removed_record_leaves_tombstone() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([152u8; 32], b"CANON"));
+        let authority_id = Birthmark::get_image_record(&[152u8; 32]).unwrap().authority_id;
+        assert_eq!(Birthmark::record_status(&[152u8; 32]), RecordStatus::Present);
+
+        System::set_block_number(3);
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [152u8; 32].to_vec(),
+            7,
+            b"court order".to_vec(),
+        ));
+        System::assert_last_event(
+            Event::RecordRemoved {
+                image_hash: [152u8; 32],
+                reason_code: 7,
+                reason: b"court order".to_vec().try_into().unwrap(),
+            }
+            .into(),
+        );
+
+        assert_eq!(Birthmark::record_status(&[152u8; 32]), RecordStatus::Tombstoned);
+        assert_eq!(Birthmark::record_status(&[153u8; 32]), RecordStatus::Absent);
+        assert_eq!(
+            Birthmark::tombstones([152u8; 32]),
+            Some(Tombstone { removed_at: 3, reason_code: 7 })
+        );
+        assert!(TombstonesByBlock::<Test>::contains_key(3, [152u8; 32]));
+        assert!(Birthmark::get_image_record(&[152u8; 32]).is_none());
+        assert_eq!(Birthmark::total_records(), 0);
+        assert_eq!(Birthmark::records_count_for_authority(authority_id), 0);
+        assert!(Birthmark::records_in_block(1).is_empty());
+    });
+}
+
+#[test]
+fn tombstoned_hash_cannot_be_resubmitted() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([154u8; 32], b"CANON"));
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [154u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+
+        assert_noop!(submit_raw([154u8; 32], b"CANON"), Error::<Test>::HashTombstoned);
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(1),
+                batch(vec![([154u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec())]),
+            ),
+            Error::<Test>::HashTombstoned
+        );
+    });
+}
+
+#[test]
+fn remove_record_requires_governance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([155u8; 32], b"CANON"));
+        assert_noop!(
+            Birthmark::remove_record(RuntimeOrigin::signed(1), [155u8; 32].to_vec(), 1, vec![]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::remove_record(RuntimeOrigin::root(), [156u8; 32].to_vec(), 1, vec![]),
+            Error::<Test>::RecordNotFound
+        );
+    });
+}
+
+#[test]
+fn authority_creation_stops_at_configured_cap() {
+    new_test_ext().execute_with(|| {
+        MaxAuthorities::set(2);
+
+        assert_ok!(submit_raw([157u8; 32], b"CANON"));
+        assert_ok!(submit_raw([158u8; 32], b"SONY"));
+        assert_noop!(submit_raw([159u8; 32], b"NIKON"), Error::<Test>::TooManyAuthorities);
+
+        // Existing authorities keep working at the cap
+        assert_ok!(submit_raw([159u8; 32], b"CANON"));
+        assert_eq!(Birthmark::next_authority_id(), 2);
+    });
+}
+
+#[test]
+fn records_capture_aura_author_index() {
+    use codec::Encode;
+    use sp_consensus_aura::{sr25519::AuthorityId as AuraId, Slot, AURA_ENGINE_ID};
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_aura::GenesisConfig::<Test> {
+        authorities: (1..=3u8)
+            .map(|i| AuraId::from(sp_core::sr25519::Public::from_raw([i; 32])))
+            .collect(),
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    pallet_birthmark::GenesisConfig::<Test> {
+        aggregators: vec![1],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(12345);
+
+        // No slot digest yet: the author is unknown
+        assert_ok!(submit_raw([160u8; 32], b"CANON"));
+        assert_eq!(Birthmark::get_image_record(&[160u8; 32]).unwrap().validator_index, None);
+
+        // Slot 7 with three authorities is authored by index 1
+        System::deposit_log(sp_runtime::DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(7u64).encode(),
+        ));
+        assert_ok!(submit_raw([161u8; 32], b"CANON"));
+        assert_eq!(Birthmark::get_image_record(&[161u8; 32]).unwrap().validator_index, Some(1));
+    });
+}
+
+#[test]
+fn provenance_report_resolves_authorities_child_first() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([162u8; 32], b"CANON"));
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            [163u8; 32].to_vec(),
+            SubmissionType::Software,
+            1,
+            Some([162u8; 32].to_vec()),
+            b"ADOBE".to_vec(),
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            [164u8; 32].to_vec(),
+            SubmissionType::Software,
+            2,
+            Some([163u8; 32].to_vec()),
+            b"ADOBE".to_vec(),
+            None,
+            None,
+            None,
+        ));
+
+        let report: Vec<_> = Birthmark::provenance_report(&[164u8; 32])
+            .into_iter()
+            .map(|(record, name)| (record.image_hash, record.modification_level, name.unwrap()))
+            .collect();
+        assert_eq!(
+            report,
+            vec![
+                ([164u8; 32], 2, b"ADOBE".to_vec()),
+                ([163u8; 32], 1, b"ADOBE".to_vec()),
+                ([162u8; 32], 0, b"CANON".to_vec()),
+            ]
+        );
+
+        assert!(Birthmark::provenance_report(&[165u8; 32]).is_empty());
+    });
+}
+
+#[test]
+fn record_validator_can_reject_submissions() {
+    new_test_ext().execute_with(|| {
+        RejectModifiedRecords::set(true);
+
+        assert_noop!(
+            Birthmark::submit_image_record(
+                RuntimeOrigin::signed(1),
+                [166u8; 32].to_vec(),
+                SubmissionType::Software,
+                2,
+                None,
+                b"ADOBE".to_vec(),
+                None,
+                None,
+                None,
+            ),
+            sp_runtime::DispatchError::Other("modified records rejected")
+        );
+
+        // One rejected entry fails the whole batch
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(1),
+                batch(vec![
+                    ([167u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+                    ([168u8; 32].to_vec(), SubmissionType::Software, 2, None, b"ADOBE".to_vec()),
+                ]),
+            ),
+            sp_runtime::DispatchError::Other("modified records rejected")
+        );
+
+        // Other levels pass the validator
+        assert_ok!(submit_raw([167u8; 32], b"CANON"));
+    });
+}
+
+#[test]
+fn level_histogram_follows_snapshots() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([169u8; 32], b"CANON"));
+        assert_ok!(Birthmark::snapshot_statistics(RuntimeOrigin::root()));
+
+        System::set_block_number(4);
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            [170u8; 32].to_vec(),
+            SubmissionType::Software,
+            2,
+            Some([169u8; 32].to_vec()),
+            b"ADOBE".to_vec(),
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(submit_raw([171u8; 32], b"CANON"));
+        assert_ok!(Birthmark::snapshot_statistics(RuntimeOrigin::root()));
+
+        assert_eq!(
+            Birthmark::level_histogram(0, 10),
+            vec![(1, [1, 0, 0]), (4, [2, 0, 1])]
+        );
+        assert_eq!(Birthmark::level_histogram(2, 10), vec![(4, [2, 0, 1])]);
+        assert!(Birthmark::level_histogram(5, 10).is_empty());
+    });
+}
+
+#[test]
+fn authority_names_must_be_long_enough_and_printable() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(submit_raw([172u8; 32], b""), Error::<Test>::AuthorityNameTooShort);
+        assert_noop!(submit_raw([172u8; 32], b"X"), Error::<Test>::AuthorityNameTooShort);
+        assert_noop!(submit_raw([172u8; 32], b"CA\0NON"), Error::<Test>::AuthorityNameInvalidChars);
+        assert_noop!(submit_raw([172u8; 32], &[0xff, 0xfe]), Error::<Test>::AuthorityNameNotUtf8);
+
+        // Non-ASCII UTF-8 is fine
+        assert_ok!(submit_raw([172u8; 32], "Fujifilm \u{00a9}".as_bytes()));
+    });
+}
+
+#[test]
+fn large_batch_from_new_authority_registers_once() {
+    new_test_ext().execute_with(|| {
+        let records = (0..50u8)
+            .map(|i| {
+                let mut hash = [173u8; 32];
+                hash[0] = i;
+                (hash.to_vec(), SubmissionType::Camera, 0, None, b"LEICA".to_vec())
+            })
+            .collect();
+
+        assert_eq!(Birthmark::next_authority_id(), 0);
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+
+        assert_eq!(Birthmark::next_authority_id(), 1);
+        assert_eq!(Birthmark::records_count_for_authority(0), 50);
+    });
+}
+
+#[test]
+fn submissions_are_indexed_by_authority_topic() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([174u8; 32], b"CANON"));
+        let authority_id = Birthmark::get_image_record(&[174u8; 32]).unwrap().authority_id;
+
+        let topic = Birthmark::authority_topic(authority_id);
+        assert_eq!(topic, <Test as frame_system::Config>::Hashing::hash_of(&authority_id));
+
+        let record = System::events().pop().unwrap();
+        assert_eq!(record.topics, vec![topic]);
+        assert_eq!(
+            record.event,
+            RuntimeEvent::Birthmark(Event::ImageRecordSubmitted {
+                image_hash: [174u8; 32],
+                authority_id,
+                modification_level: 0,
+            })
+        );
+        assert_eq!(System::event_topics(topic).len(), 1);
+    });
+}
+
+#[test]
+fn set_aggregators_replaces_whole_set() {
+    new_test_ext().execute_with(|| {
+        let accounts: BoundedVec<u64, MaxAggregators> = vec![3, 4, 5].try_into().unwrap();
+        assert_ok!(Birthmark::set_aggregators(RuntimeOrigin::root(), accounts));
+        System::assert_last_event(Event::AggregatorsReset { count: 3 }.into());
+
+        // Old members can no longer submit
+        assert_noop!(submit_raw([175u8; 32], b"CANON"), Error::<Test>::NotAuthorizedAggregator);
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(2),
+                batch(vec![([175u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec())]),
+            ),
+            Error::<Test>::NotAuthorizedAggregator
+        );
+
+        // New members can
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(4),
+            [175u8; 32].to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            b"CANON".to_vec(),
+            None,
+            None,
+            None,
+        ));
+        assert!(Birthmark::is_authorized_aggregator(&3));
+        assert!(!Birthmark::is_authorized_aggregator(&1));
+    });
+}
+
+#[test]
+fn set_aggregators_requires_governance() {
+    new_test_ext().execute_with(|| {
+        let accounts: BoundedVec<u64, MaxAggregators> = vec![1].try_into().unwrap();
+        assert_noop!(
+            Birthmark::set_aggregators(RuntimeOrigin::signed(1), accounts),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn unknown_authority_rejected_without_auto_registration() {
+    new_test_ext().execute_with(|| {
+        AllowAuthorityAutoRegistration::set(false);
+
+        assert_noop!(submit_raw([176u8; 32], b"CANON"), Error::<Test>::AuthorityNotFound);
+
+        // Governance pre-registers the name, after which submissions resolve it
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"CANON".to_vec()));
+        assert_ok!(submit_raw([176u8; 32], b"CANON"));
+        assert_eq!(Birthmark::next_authority_id(), 1);
+
+        assert_noop!(
+            Birthmark::register_authority(RuntimeOrigin::root(), b"CANON".to_vec()),
+            Error::<Test>::AuthorityAlreadyRegistered
+        );
+        assert_noop!(
+            Birthmark::register_authority(RuntimeOrigin::signed(1), b"SONY".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn unknown_authority_registered_with_auto_registration() {
+    new_test_ext().execute_with(|| {
+        AllowAuthorityAutoRegistration::set(true);
+
+        assert_ok!(submit_raw([177u8; 32], b"CANON"));
+        assert_eq!(Birthmark::next_authority_id(), 1);
+    });
+}
+
+#[test]
+fn storage_version_reported_after_migration() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(4).put::<Birthmark>();
+        assert_eq!(Birthmark::storage_version(), 4);
+
+        migrations::v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
+        assert_eq!(Birthmark::storage_version(), 5);
+    });
+}
+
+#[test]
+fn image_dimensions_round_trip() {
+    new_test_ext().execute_with(|| {
+        let hash = [178u8; 32];
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(1),
+            hash.to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            b"CANON".to_vec(),
+            Some(8192),
+            Some(5464),
+            Some(45_000_000),
+        ));
+
+        let record = Birthmark::get_image_record(&hash).unwrap();
+        assert_eq!(record.width, Some(Compact(8192)));
+        assert_eq!(record.height, Some(Compact(5464)));
+        assert_eq!(record.byte_size, Some(Compact(45_000_000)));
+
+        // Omitted metadata stays unset
+        assert_ok!(submit_raw([179u8; 32], b"CANON"));
+        let record = Birthmark::get_image_record(&[179u8; 32]).unwrap();
+        assert_eq!((record.width, record.height, record.byte_size), (None, None, None));
+    });
+}
+
+#[test]
+fn migration_v6_leaves_dimensions_unset() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let hash = [180u8; 32];
+        let old = migrations::v5::ImageRecordV5 {
+            image_hash: hash,
+            submission_type: SubmissionType::Camera,
+            modification_level: 1,
+            parent_image_hash: None,
+            authority_id: 0,
+            timestamp: 10,
+            block_number: 1,
+            validator_index: Some(2),
+        };
+        frame_support::storage::unhashed::put(&Birthmark::record_storage_key(&hash), &old);
+        StorageVersion::new(5).put::<Birthmark>();
+
+        migrations::v6::MigrateV5ToV6::<Test>::on_runtime_upgrade();
+
+        let record: migrations::v6::ImageRecordV6 =
+            frame_support::storage::unhashed::get(&Birthmark::record_storage_key(&hash)).unwrap();
+        assert_eq!(record.validator_index, Some(2));
+        assert_eq!(record.modification_level, 1);
+        assert_eq!((record.width, record.height, record.byte_size), (None, None, None));
+        assert_eq!(Birthmark::storage_version(), 6);
+    });
+}
+
+#[test]
+fn expired_tombstones_are_pruned() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([181u8; 32], b"CANON"));
+        assert_ok!(submit_raw([182u8; 32], b"CANON"));
+
+        System::set_block_number(2);
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [181u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+        System::set_block_number(60);
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [182u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+
+        // Still inside the retention window
+        System::set_block_number(102);
+        Birthmark::on_initialize(102);
+        assert_eq!(Birthmark::record_status(&[181u8; 32]), RecordStatus::Tombstoned);
+
+        System::set_block_number(103);
+        Birthmark::on_initialize(103);
+        System::assert_last_event(Event::TombstonesPruned { count: 1 }.into());
+        assert_eq!(Birthmark::record_status(&[181u8; 32]), RecordStatus::Absent);
+        assert_eq!(Birthmark::record_status(&[182u8; 32]), RecordStatus::Tombstoned);
+    });
+}
+
+#[test]
+fn tombstone_pruning_is_bounded_per_block() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        // Nothing was removed before block 1
+        TombstonePruneCursor::<Test>::put(1);
+        let total = MaxPrunedPerBlock::get() + 2;
+        for i in 0..total {
+            let mut hash = [183u8; 32];
+            hash[..4].copy_from_slice(&i.to_le_bytes());
+            Tombstones::<Test>::insert(hash, Tombstone { removed_at: 1, reason_code: 0 });
+            TombstonesByBlock::<Test>::insert(1, hash, ());
+        }
+
+        Birthmark::on_initialize(200);
+        assert_eq!(Tombstones::<Test>::iter().count() as u32, 2);
+
+        Birthmark::on_initialize(201);
+        assert_eq!(Tombstones::<Test>::iter().count(), 0);
+        assert_eq!(TombstonesByBlock::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn unverified_authority_rejected_when_verification_required() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([184u8; 32], b"CANON"));
+        let authority_id = Birthmark::get_image_record(&[184u8; 32]).unwrap().authority_id;
+
+        assert_ok!(Birthmark::set_require_verified_authority(RuntimeOrigin::root(), true));
+        System::assert_last_event(Event::RequireVerifiedAuthoritySet { required: true }.into());
+
+        assert_noop!(submit_raw([185u8; 32], b"CANON"), Error::<Test>::AuthorityNotVerified);
+        // Unknown names would be auto-registered unverified, so they are refused too
+        assert_noop!(submit_raw([185u8; 32], b"NIKON"), Error::<Test>::AuthorityNotVerified);
+
+        assert_ok!(Birthmark::set_authority_verified(RuntimeOrigin::root(), authority_id, true));
+        System::assert_last_event(
+            Event::AuthorityVerificationSet { authority_id, verified: true }.into(),
+        );
+        assert_ok!(submit_raw([185u8; 32], b"CANON"));
+
+        // Lifting the requirement restores auto-registration
+        assert_ok!(Birthmark::set_require_verified_authority(RuntimeOrigin::root(), false));
+        assert_ok!(submit_raw([186u8; 32], b"NIKON"));
+    });
+}
+
+#[test]
+fn authority_verification_requires_governance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([187u8; 32], b"CANON"));
+
+        assert_noop!(
+            Birthmark::set_require_verified_authority(RuntimeOrigin::signed(1), true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::set_authority_verified(RuntimeOrigin::signed(1), 0, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::set_authority_verified(RuntimeOrigin::root(), 42, true),
+            Error::<Test>::AuthorityNotFound
+        );
+    });
+}
+
+#[test]
+fn utf8_authority_name_validation_is_configurable() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([188u8; 32], "Leica \u{00e9}".as_bytes()));
+
+        assert_noop!(submit_raw([189u8; 32], &[0xc3, 0x28]), Error::<Test>::AuthorityNameNotUtf8);
+
+        ValidateUtf8AuthorityNames::set(false);
+        assert_ok!(submit_raw([189u8; 32], &[0xc3, 0x28]));
+        let authority_id = Birthmark::get_image_record(&[189u8; 32]).unwrap().authority_id;
+        assert_eq!(Birthmark::get_authority_name(authority_id).unwrap().to_vec(), vec![0xc3, 0x28]);
+
+        // Control characters stay forbidden
+        assert_noop!(submit_raw([190u8; 32], b"CA\tNON"), Error::<Test>::AuthorityNameInvalidChars);
+        ValidateUtf8AuthorityNames::set(true);
+    });
+}
+
+#[test]
+fn auto_registered_ids_start_above_reserved_range() {
+    FirstUserAuthorityId::set(16);
+    new_test_ext().execute_with(|| {
+        assert_eq!(Birthmark::next_authority_id(), 16);
+
+        assert_ok!(submit_raw([191u8; 32], b"CANON"));
+        assert_eq!(Birthmark::get_image_record(&[191u8; 32]).unwrap().authority_id, 16);
+
+        // A counter left below the offset (e.g. by an older genesis) is skipped past
+        NextAuthorityId::<Test>::put(3);
+        assert_ok!(submit_raw([192u8; 32], b"NIKON"));
+        assert_eq!(Birthmark::get_image_record(&[192u8; 32]).unwrap().authority_id, 17);
+    });
+    FirstUserAuthorityId::set(0);
+}
+
+#[test]
+fn force_register_authority_uses_reserved_range() {
+    FirstUserAuthorityId::set(16);
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::force_register_authority(
+            RuntimeOrigin::root(),
+            0,
+            b"C2PA".to_vec()
+        ));
+        assert_eq!(Birthmark::get_authority_name(0).unwrap().to_vec(), b"C2PA".to_vec());
+        assert_eq!(Birthmark::next_authority_id(), 16);
+
+        // Submissions naming it resolve to the reserved ID
+        assert_ok!(submit_raw([193u8; 32], b"C2PA"));
+        assert_eq!(Birthmark::get_image_record(&[193u8; 32]).unwrap().authority_id, 0);
+
+        assert_noop!(
+            Birthmark::force_register_authority(RuntimeOrigin::root(), 0, b"OTHER".to_vec()),
+            Error::<Test>::AuthorityIdTaken
+        );
+        assert_noop!(
+            Birthmark::force_register_authority(RuntimeOrigin::root(), 16, b"OTHER".to_vec()),
+            Error::<Test>::AuthorityIdNotReserved
+        );
+        assert_noop!(
+            Birthmark::force_register_authority(RuntimeOrigin::root(), 1, b"C2PA".to_vec()),
+            Error::<Test>::AuthorityAlreadyRegistered
+        );
+        assert_noop!(
+            Birthmark::force_register_authority(RuntimeOrigin::signed(1), 1, b"OTHER".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+    FirstUserAuthorityId::set(0);
+}
+
+#[test]
+fn records_indexed_by_submission_type() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([194u8; 32], b"CANON"));
+        assert_ok!(submit_raw([195u8; 32], b"CANON"));
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(1),
+            batch(vec![
+                ([196u8; 32].to_vec(), SubmissionType::Software, 1, None, b"ADOBE".to_vec()),
+                ([197u8; 32].to_vec(), SubmissionType::Software, 1, None, b"ADOBE".to_vec()),
+                ([198u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"NIKON".to_vec()),
+            ]),
+        ));
+
+        let mut camera = Birthmark::records_by_submission_type(SubmissionType::Camera, None, 10);
+        camera.sort();
+        assert_eq!(camera, vec![[194u8; 32], [195u8; 32], [198u8; 32]]);
+
+        let mut software = Birthmark::records_by_submission_type(SubmissionType::Software, None, 10);
+        software.sort();
+        assert_eq!(software, vec![[196u8; 32], [197u8; 32]]);
+
+        // Paging resumes after the last hash of the previous page
+        let first = Birthmark::records_by_submission_type(SubmissionType::Camera, None, 2);
+        let rest = Birthmark::records_by_submission_type(SubmissionType::Camera, Some(first[1]), 2);
+        assert_eq!(first.len() + rest.len(), 3);
+        assert!(first.iter().all(|hash| !rest.contains(hash)));
+
+        // Removal drops the hash from the index
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [196u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+        assert_eq!(
+            Birthmark::records_by_submission_type(SubmissionType::Software, None, 10),
+            vec![[197u8; 32]]
+        );
+    });
+}
+
+#[test]
+fn register_authorities_batch_skips_existing_names() {
+    new_test_ext().execute_with(|| {
+        type Names = BoundedVec<BoundedVec<u8, MaxAuthorityIdLength>, ConstU32<100>>;
+        let names = |list: &[&[u8]]| -> Names {
+            let list: Vec<_> = list.iter().map(|name| name.to_vec().try_into().unwrap()).collect();
+            list.try_into().unwrap()
+        };
+
+        assert_ok!(Birthmark::register_authorities_batch(
+            RuntimeOrigin::root(),
+            names(&[b"CANON", b"NIKON", b"SONY", b"LEICA", b"FUJIFILM"]),
+        ));
+        System::assert_last_event(Event::AuthoritiesRegistered { ids: vec![0, 1, 2, 3, 4] }.into());
+        assert_eq!(Birthmark::next_authority_id(), 5);
+        let leica: BoundedVec<u8, MaxAuthorityIdLength> = b"LEICA".to_vec().try_into().unwrap();
+        assert_eq!(Birthmark::authority_id_by_name(leica), Some(3));
+
+        // Overlapping names are skipped; only the new one gets an ID
+        assert_ok!(Birthmark::register_authorities_batch(
+            RuntimeOrigin::root(),
+            names(&[b"SONY", b"PENTAX", b"CANON"]),
+        ));
+        System::assert_last_event(Event::AuthoritiesRegistered { ids: vec![5] }.into());
+        assert_eq!(Birthmark::next_authority_id(), 6);
+
+        assert_noop!(
+            Birthmark::register_authorities_batch(RuntimeOrigin::signed(1), names(&[b"OLYMPUS"])),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Names past the length bound cannot even be put in the call
+        let long = vec![b'A'; MaxAuthorityIdLength::get() as usize + 1];
+        assert!(BoundedVec::<u8, MaxAuthorityIdLength>::try_from(long).is_err());
+    });
+}
+
+#[test]
+fn register_authorities_batch_weight_scales_with_names() {
+    use frame_support::dispatch::GetDispatchInfo;
+
+    let call = |count: u8| {
+        let names: Vec<BoundedVec<u8, MaxAuthorityIdLength>> =
+            (0..count).map(|i| vec![b'A', b'A' + i].try_into().unwrap()).collect();
+        Call::<Test>::register_authorities_batch { names: names.try_into().unwrap() }
+            .get_dispatch_info()
+            .weight
+    };
+
+    assert_eq!(call(1), <() as weights::WeightInfo>::register_authorities_batch(1));
+    assert_eq!(call(20), <() as weights::WeightInfo>::register_authorities_batch(20));
+    assert!(call(20).all_gt(call(1)));
+}
+
+#[test]
+fn record_block_reports_storing_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        assert_ok!(submit_raw([199u8; 32], b"CANON"));
+
+        assert_eq!(Birthmark::record_block(&[199u8; 32]), Some(7));
+        assert_eq!(Birthmark::record_block(&[200u8; 32]), None);
+    });
+}
+
+#[test]
+fn batch_weight_charges_for_parent_lookups() {
+    use frame_support::{dispatch::GetDispatchInfo, traits::Get};
+
+    new_test_ext().execute_with(|| {
+        let records = |with_parents: bool| {
+            (0..10u8)
+                .map(|i| {
+                    let parent = with_parents.then(|| [i; 32].to_vec());
+                    let hash = [201u8.wrapping_add(i); 32].to_vec();
+                    (hash, SubmissionType::Software, 2, parent, b"ADOBE".to_vec())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let flat = Call::<Test>::submit_image_batch { records: batch(records(false)) }
+            .get_dispatch_info()
+            .weight;
+        let with_parents = Call::<Test>::submit_image_batch { records: batch(records(true)) }
+            .get_dispatch_info()
+            .weight;
+
+        assert_eq!(flat, Birthmark::batch_weight(10, 0));
+        assert_eq!(with_parents, Birthmark::batch_weight(10, 10));
+        // At least the extra parent reads are charged
+        assert!(with_parents
+            .saturating_sub(flat)
+            .all_gte(<Test as frame_system::Config>::DbWeight::get().reads(10)));
+
+        let refs = Call::<Test>::submit_image_batch_with_refs {
+            records: batch_with_refs(
+                records(true)
+                    .into_iter()
+                    .map(|(hash, kind, level, parent, name)| {
+                        (hash, kind, level, parent, AuthorityRef::Name(name))
+                    })
+                    .collect(),
+            ),
+        };
+        assert_eq!(refs.get_dispatch_info().weight, with_parents);
+    });
+}
+
+#[test]
+fn list_records_pages_through_whole_registry() {
+    new_test_ext().execute_with(|| {
+        for i in 211u8..=225 {
+            assert_ok!(submit_raw([i; 32], b"CANON"));
+        }
+
+        let mut seen = Vec::new();
+        let mut start = None;
+        let mut pages = 0;
+        loop {
+            // Page size above `MaxBulkQuerySize` is capped
+            let (records, next) = Birthmark::list_records(start, 50);
+            assert!(records.len() <= 10);
+            seen.extend(records.into_iter().map(|record| record.image_hash));
+            pages += 1;
+            match next {
+                Some(key) => start = Some(key),
+                None => break,
+            }
+        }
+
+        assert_eq!(pages, 2);
+        seen.sort();
+        assert_eq!(seen, (211u8..=225).map(|i| [i; 32]).collect::<Vec<_>>());
+    });
+}
+
+#[test]
+fn identity_authorization_replaces_allowlist() {
+    new_test_ext().execute_with(|| {
+        let submit_as = |who: u64, hash: [u8; 32]| {
+            Birthmark::submit_image_record(
+                RuntimeOrigin::signed(who),
+                hash.to_vec(),
+                SubmissionType::Camera,
+                0,
+                None,
+                b"CANON".to_vec(),
+                None,
+                None,
+                None,
+            )
+        };
+
+        // Allowlist mode: account 7 has an identity but is not an aggregator
+        assert_noop!(submit_as(7, [226u8; 32]), Error::<Test>::NotAuthorizedAggregator);
+
+        UseIdentityAuthorization::set(true);
+        // Aggregator 1 has no judged identity
+        assert_noop!(submit_as(1, [226u8; 32]), Error::<Test>::NotAuthorizedAggregator);
+        assert_ok!(submit_as(7, [226u8; 32]));
+        UseIdentityAuthorization::set(false);
+    });
+}
+
+#[test]
+fn resubmission_with_different_authority_is_flagged() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([227u8; 32], b"CANON"));
+        assert_ok!(submit_raw([228u8; 32], b"NIKON"));
+
+        // Matching attribution is a plain duplicate
+        assert_eq!(Birthmark::resubmission_conflict(&[227u8; 32], b"CANON"), None);
+        assert_noop!(submit_raw([227u8; 32], b"CANON"), Error::<Test>::HashAlreadyExists);
+
+        // A different (registered or unknown) authority is a conflict
+        assert_eq!(Birthmark::resubmission_conflict(&[227u8; 32], b"NIKON"), Some((0, Some(1))));
+        assert_eq!(Birthmark::resubmission_conflict(&[227u8; 32], b"SPOOF"), Some((0, None)));
+        assert_noop!(submit_raw([227u8; 32], b"NIKON"), Error::<Test>::HashAlreadyExists);
+
+        // Nothing to conflict with for an unknown hash
+        assert_eq!(Birthmark::resubmission_conflict(&[229u8; 32], b"CANON"), None);
+    });
+}
+
+#[test]
+fn conflicting_resubmission_emits_event() {
+    new_test_ext().execute_with(|| {
+        let hash = [0x5au8; 32];
+        assert_ok!(submit_raw(hash, b"CANON"));
+        assert_ok!(submit_raw([0x5bu8; 32], b"NIKON"));
+
+        // The runtime notes the resubmission once the failed call is rolled back
+        assert_noop!(submit_raw(hash, b"NIKON"), Error::<Test>::HashAlreadyExists);
+        Birthmark::note_resubmission(&hash, b"NIKON");
+        System::assert_last_event(
+            Event::ConflictingResubmission {
+                hash,
+                stored_authority: 0,
+                attempted_authority: Some(1),
+            }
+            .into(),
+        );
+
+        Birthmark::note_resubmission(&hash, b"SPOOF");
+        System::assert_last_event(
+            Event::ConflictingResubmission { hash, stored_authority: 0, attempted_authority: None }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn matching_resubmission_emits_no_event() {
+    new_test_ext().execute_with(|| {
+        let hash = [0x5cu8; 32];
+        assert_ok!(submit_raw(hash, b"CANON"));
+        let events = System::events().len();
+
+        assert_noop!(submit_raw(hash, b"CANON"), Error::<Test>::HashAlreadyExists);
+        Birthmark::note_resubmission(&hash, b"CANON");
+        // Unknown or malformed hashes have nothing to conflict with
+        Birthmark::note_resubmission(&[0x5du8; 32], b"NIKON");
+        Birthmark::note_resubmission(b"not a hash", b"NIKON");
+
+        assert_eq!(System::events().len(), events);
+    });
+}
+
+#[test]
+fn old_block_index_entries_are_pruned_but_records_kept() {
+    use frame_support::traits::Hooks;
+
+    BlockIndexRetention::set(10);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        assert_ok!(submit_raw([230u8; 32], b"CANON"));
+        System::set_block_number(5);
+        assert_ok!(submit_raw([231u8; 32], b"CANON"));
+
+        // Block 2 leaves the window at block 13; block 5 is still inside it
+        Birthmark::on_initialize(13);
+        assert!(Birthmark::records_in_block(2).is_empty());
+        assert_eq!(Birthmark::records_in_block(5), vec![[231u8; 32]]);
+        assert_eq!(Birthmark::records_in_range(0, 13, None, 10).unwrap().0, vec![[231u8; 32]]);
+
+        // The record itself is never pruned
+        assert_eq!(Birthmark::record_block(&[230u8; 32]), Some(2));
+        assert_eq!(Birthmark::total_records(), 2);
+
+        Birthmark::on_initialize(16);
+        assert!(Birthmark::records_in_block(5).is_empty());
+        assert_eq!(BlockIndexPruneCursor::<Test>::get(), 6);
+    });
+    BlockIndexRetention::set(1_000);
+}
+
+#[test]
+fn block_index_pruning_charges_visited_blocks() {
+    BlockIndexRetention::set(10);
+    MaxBlockIndexPrunedPerBlock::set(4);
+    new_test_ext().execute_with(|| {
+        let charged = <() as weights::WeightInfo>::prune_block_index(4);
+
+        // Empty blocks use up the budget and are charged like removals
+        assert_eq!(Birthmark::prune_block_index(100), charged);
+        assert_eq!(BlockIndexPruneCursor::<Test>::get(), 4);
+
+        // One unit for block 4, two entries in block 5, one unit for block 6
+        RecordsByBlock::<Test>::insert(5, [0x60u8; 32], ());
+        RecordsByBlock::<Test>::insert(5, [0x61u8; 32], ());
+        assert_eq!(Birthmark::prune_block_index(100), charged);
+        assert!(Birthmark::records_in_block(5).is_empty());
+        assert_eq!(BlockIndexPruneCursor::<Test>::get(), 7);
+
+        // Nothing left to visit once the cursor reaches the cutoff
+        BlockIndexPruneCursor::<Test>::put(90);
+        assert_eq!(
+            Birthmark::prune_block_index(100),
+            <() as weights::WeightInfo>::prune_block_index(0)
+        );
+    });
+    MaxBlockIndexPrunedPerBlock::set(64);
+    BlockIndexRetention::set(1_000);
+}
+
+// Number of `AuthorityCapacityLow` events deposited so far
+fn capacity_warnings() -> usize {
+    System::events()
+        .into_iter()
+        .filter(|record| {
+            matches!(record.event, RuntimeEvent::Birthmark(Event::AuthorityCapacityLow { .. }))
+        })
+        .count()
+}
+
+#[test]
+fn low_authority_capacity_warned_once() {
+    new_test_ext().execute_with(|| {
+        MaxAuthorities::set(10);
+
+        let names: Vec<BoundedVec<u8, MaxAuthorityIdLength>> =
+            (0..8u8).map(|i| vec![b'A', b'A' + i].try_into().unwrap()).collect();
+        assert_ok!(Birthmark::register_authorities_batch(
+            RuntimeOrigin::root(),
+            names.try_into().unwrap()
+        ));
+        assert_eq!(capacity_warnings(), 0);
+
+        // The ninth authority reaches 90% of the cap of ten
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"NINTH".to_vec()));
+        System::assert_has_event(Event::AuthorityCapacityLow { remaining: 1 }.into());
+        assert_eq!(capacity_warnings(), 1);
+
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"TENTH".to_vec()));
+        assert_eq!(capacity_warnings(), 1);
+    });
+}
+
+#[test]
+fn reserved_ids_past_threshold_warn_on_first_registration() {
+    new_test_ext().execute_with(|| {
+        MaxAuthorities::set(10);
+        // The reserved range alone uses 80% of the cap
+        FirstUserAuthorityId::set(8);
+
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"FIRST".to_vec()));
+        System::assert_has_event(Event::AuthorityCapacityLow { remaining: 1 }.into());
+        assert!(AuthorityCapacityWarned::<Test>::get());
+
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"SECOND".to_vec()));
+        assert_eq!(capacity_warnings(), 1);
+    });
+    FirstUserAuthorityId::set(0);
+}
+
+#[test]
+fn zero_capacity_warning_is_disabled() {
+    new_test_ext().execute_with(|| {
+        MaxAuthorities::set(2);
+        AuthorityCapacityWarning::set(Percent::from_percent(0));
+
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"FIRST".to_vec()));
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"SECOND".to_vec()));
+        assert_eq!(capacity_warnings(), 0);
+        assert!(!AuthorityCapacityWarned::<Test>::get());
+    });
+    AuthorityCapacityWarning::set(Percent::from_percent(90));
+}
+
+#[test]
+fn genesis_authorities_are_recorded() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_birthmark::GenesisConfig::<Test> {
+        aggregators: vec![1],
+        authorities: vec![b"CANON".to_vec(), b"NIKON".to_vec()],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(Birthmark::genesis_authorities(), vec![0, 1]);
+        assert_eq!(Birthmark::get_authority_name(1).unwrap().to_vec(), b"NIKON".to_vec());
+
+        // Authorities registered later are not genesis authorities
+        assert_ok!(submit_raw([232u8; 32], b"SONY"));
+        assert_eq!(Birthmark::genesis_authorities(), vec![0, 1]);
+    });
+}
+
+#[test]
+fn production_mode_genesis_requires_verified_authorities() {
+    let genesis = |production_mode| {
+        let mut t = frame_system::GenesisConfig::<Test>::default()
+            .build_storage()
+            .unwrap();
+        pallet_birthmark::GenesisConfig::<Test> {
+            aggregators: vec![1],
+            authorities: vec![b"CANON".to_vec()],
+            production_mode,
+            ..Default::default()
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        sp_io::TestExternalities::new(t)
+    };
+
+    genesis(true).execute_with(|| {
+        System::set_block_number(1);
+        assert!(Birthmark::require_verified_authority());
+        // Unknown names are not auto-registered
+        assert_noop!(submit_raw([0xd1u8; 32], b"NIKON"), Error::<Test>::AuthorityNotFound);
+
+        // Authorities registered after genesis still need verifying
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"NIKON".to_vec()));
+        assert_noop!(submit_raw([0xd1u8; 32], b"NIKON"), Error::<Test>::AuthorityNotVerified);
+        assert_ok!(Birthmark::set_authority_verified(RuntimeOrigin::root(), 1, true));
+        assert_ok!(submit_raw([0xd1u8; 32], b"NIKON"));
+        // Edits need a parent even though the mock does not require one
+        assert_noop!(
+            Birthmark::submit_image_record(
+                RuntimeOrigin::signed(1),
+                [0xd2u8; 32].to_vec(),
+                SubmissionType::Software,
+                1,
+                None,
+                b"CANON".to_vec(),
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::MissingParentForEdit
+        );
+    });
+
+    genesis(false).execute_with(|| {
+        System::set_block_number(1);
+        assert!(!Birthmark::require_verified_authority());
+        assert_ok!(submit_raw([0xd1u8; 32], b"CANON"));
+        assert_ok!(submit_raw([0xd2u8; 32], b"NIKON"));
+    });
+}
+
+#[test]
+fn production_mode_genesis_authorities_can_submit() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_birthmark::GenesisConfig::<Test> {
+        aggregators: vec![1],
+        authorities: vec![b"CANON".to_vec(), b"NIKON".to_vec()],
+        production_mode: true,
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        System::set_block_number(1);
+        assert!(Birthmark::is_authority_verified(0));
+        assert!(Birthmark::is_authority_verified(1));
+
+        // The genesis aggregator submits for a seeded authority without root
+        assert_ok!(submit_raw([0xd3u8; 32], b"CANON"));
+        assert_eq!(Birthmark::get_image_record(&[0xd3u8; 32]).unwrap().authority_id, 0);
+    });
+}
+
+#[test]
+fn modification_labels_default_and_can_be_changed() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Birthmark::modification_label(0), Some(b"raw".to_vec()));
+        assert_eq!(Birthmark::modification_label(1), Some(b"validated".to_vec()));
+        assert_eq!(Birthmark::modification_label(2), Some(b"modified".to_vec()));
+        assert_eq!(Birthmark::modification_label(3), None);
+
+        assert_ok!(Birthmark::set_modification_label(
+            RuntimeOrigin::root(),
+            1,
+            b"minor edits".to_vec()
+        ));
+        assert_eq!(Birthmark::modification_label(1), Some(b"minor edits".to_vec()));
+
+        assert_noop!(
+            Birthmark::set_modification_label(RuntimeOrigin::root(), 3, b"other".to_vec()),
+            Error::<Test>::InvalidModificationLevel
+        );
+        assert_noop!(
+            Birthmark::set_modification_label(RuntimeOrigin::root(), 1, vec![b'x'; 33]),
+            Error::<Test>::LabelTooLong
+        );
+        assert_noop!(
+            Birthmark::set_modification_label(RuntimeOrigin::signed(1), 1, b"x".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn reassign_authority_moves_record_between_authorities() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([233u8; 32], b"CANON"));
+        assert_ok!(submit_raw([234u8; 32], b"NIKON"));
+
+        assert_ok!(Birthmark::reassign_authority(RuntimeOrigin::root(), [233u8; 32].to_vec(), 1));
+        System::assert_last_event(
+            Event::RecordReassigned { image_hash: [233u8; 32], old_authority: 0, new_authority: 1 }
+                .into(),
+        );
+
+        assert_eq!(Birthmark::get_image_record(&[233u8; 32]).unwrap().authority_id, 1);
+        assert_eq!(Birthmark::records_count_for_authority(0), 0);
+        assert_eq!(Birthmark::records_count_for_authority(1), 2);
+        assert_eq!(Birthmark::total_records(), 2);
+    });
+}
+
+#[test]
+fn reassign_authority_requires_existing_target() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([235u8; 32], b"CANON"));
+
+        assert_noop!(
+            Birthmark::reassign_authority(RuntimeOrigin::root(), [235u8; 32].to_vec(), 9),
+            Error::<Test>::AuthorityNotFound
+        );
+        assert_noop!(
+            Birthmark::reassign_authority(RuntimeOrigin::root(), [236u8; 32].to_vec(), 0),
+            Error::<Test>::RecordNotFound
+        );
+        assert_noop!(
+            Birthmark::reassign_authority(RuntimeOrigin::signed(1), [235u8; 32].to_vec(), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn get_records_is_positional() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([237u8; 32], b"CANON"));
+        assert_ok!(submit_raw([238u8; 32], b"NIKON"));
+
+        let records = Birthmark::get_records(&[[239u8; 32], [238u8; 32], [240u8; 32], [237u8; 32]]);
+        let hashes: Vec<_> = records.iter().map(|r| r.as_ref().map(|r| r.image_hash)).collect();
+        assert_eq!(hashes, vec![None, Some([238u8; 32]), None, Some([237u8; 32])]);
+
+        // Capped at `MaxBulkQuerySize`
+        assert_eq!(Birthmark::get_records(&[[237u8; 32]; 12]).len(), 10);
+    });
+}
+
+#[test]
+fn new_authority_submission_reports_registration_weight() {
+    new_test_ext().execute_with(|| {
+        let registering = submit_raw([241u8; 32], b"CANON").unwrap();
+        assert_eq!(
+            registering.actual_weight,
+            Some(Birthmark::submit_image_record_new_authority())
+        );
+
+        let existing = submit_raw([242u8; 32], b"CANON").unwrap();
+        assert_eq!(
+            existing.actual_weight,
+            Some(Birthmark::submit_image_record_existing_authority())
+        );
+
+        assert!(registering.actual_weight.unwrap().any_gt(existing.actual_weight.unwrap()));
+    });
+}
+
+#[test]
+fn records_indexed_by_hash_prefix() {
+    new_test_ext().execute_with(|| {
+        let mut shared_a = [243u8; 32];
+        shared_a[31] = 1;
+        let mut shared_b = [243u8; 32];
+        shared_b[31] = 2;
+        for hash in [shared_a, shared_b, [244u8; 32], [245u8; 32]] {
+            assert_ok!(submit_raw(hash, b"CANON"));
+        }
+
+        let mut shard = Birthmark::records_with_prefix(243, None, 10);
+        shard.sort();
+        assert_eq!(shard, vec![shared_a, shared_b]);
+        assert_eq!(Birthmark::records_with_prefix(244, None, 10), vec![[244u8; 32]]);
+
+        // Every record sits in exactly one shard
+        let indexed: u64 = (0..=u8::MAX)
+            .map(|prefix| RecordsByHashPrefix::<Test>::iter_key_prefix(prefix).count() as u64)
+            .sum();
+        assert_eq!(indexed, Birthmark::total_records());
+
+        // Removal drops the hash from its shard
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [245u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+        assert!(Birthmark::records_with_prefix(245, None, 10).is_empty());
+    });
+}
+
+#[test]
+fn hash_prefix_index_is_optional() {
+    IndexRecordsByHashPrefix::set(false);
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([246u8; 32], b"CANON"));
+        assert!(Birthmark::records_with_prefix(246, None, 10).is_empty());
+    });
+    IndexRecordsByHashPrefix::set(true);
+}
+
+#[test]
+fn records_carry_schema_version() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([247u8; 32], b"CANON"));
+        assert_eq!(Birthmark::get_image_record(&[247u8; 32]).unwrap().schema, RECORD_SCHEMA);
+
+        let hash = [248u8; 32];
+        let old = migrations::v6::ImageRecordV6 {
+            image_hash: hash,
+            submission_type: SubmissionType::Software,
+            modification_level: 2,
+            parent_image_hash: None,
+            authority_id: 0,
+            timestamp: 10,
+            block_number: 1,
+            validator_index: None,
+            width: Some(Compact(640)),
+            height: Some(Compact(480)),
+            byte_size: None,
+        };
+        frame_support::storage::unhashed::put(&Birthmark::record_storage_key(&hash), &old);
+        StorageVersion::new(8).put::<Birthmark>();
+
+        migrations::v9::MigrateV8ToV9::<Test>::on_runtime_upgrade();
+
+        let record: migrations::v9::ImageRecordV9 =
+            frame_support::storage::unhashed::get(&Birthmark::record_storage_key(&hash)).unwrap();
+        assert_eq!(record.schema, 0);
+        assert_eq!(record.width, Some(Compact(640)));
+        assert_eq!(Birthmark::storage_version(), 9);
+    });
+}
+
+#[test]
+fn migration_v10_widens_authority_ids() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext().execute_with(|| {
+        let hash = [0x77u8; 32];
+        let old = migrations::v9::ImageRecordV9 {
+            image_hash: hash,
+            submission_type: SubmissionType::Camera,
+            modification_level: 1,
+            parent_image_hash: None,
+            authority_id: 300,
+            timestamp: 10,
+            block_number: 1,
+            validator_index: Some(2),
+            width: None,
+            height: None,
+            byte_size: Some(Compact(1024)),
+            schema: 1,
+        };
+        unhashed::put(&Birthmark::record_storage_key(&hash), &old);
+
+        let name: BoundedVec<u8, MaxAuthorityIdLength> = b"CANON".to_vec().try_into().unwrap();
+        let info = AuthorityInfo { name: name.clone(), active: true };
+        migrations::v10::AuthorityRegistry::<Test>::insert(300u16, info.clone());
+        migrations::v10::RecordsPerAuthority::<Test>::insert(300u16, 1);
+        migrations::v10::VerifiedAuthorities::<Test>::insert(300u16, ());
+        unhashed::put(&AuthorityIdByName::<Test>::hashed_key_for(&name), &300u16);
+        unhashed::put(&NextAuthorityId::<Test>::hashed_key(), &301u16);
+        unhashed::put(&GenesisAuthorities::<Test>::hashed_key(), &vec![300u16]);
+        StorageVersion::new(9).put::<Birthmark>();
+
+        migrations::v10::MigrateV9ToV10::<Test>::on_runtime_upgrade();
+
+        let record = Birthmark::get_image_record(&hash).unwrap();
+        assert_eq!(record.authority_id, 300);
+        assert_eq!(record.validator_index, Some(2));
+        assert_eq!(record.byte_size, Some(Compact(1024)));
+        assert_eq!(record.schema, 1);
+
+        assert_eq!(Birthmark::authority_registry(300), Some(info));
+        assert_eq!(Birthmark::records_count_for_authority(300), 1);
+        assert!(Birthmark::is_authority_verified(300));
+        assert_eq!(Birthmark::authority_id_by_name(&name), Some(300));
+        assert_eq!(Birthmark::next_authority_id(), 301);
+        assert_eq!(Birthmark::genesis_authorities(), vec![300]);
+        assert_eq!(Birthmark::storage_version(), 10);
+
+        // The record still resolves and resubmitting it is rejected as before
+        assert_noop!(submit_raw(hash, b"CANON"), Error::<Test>::HashAlreadyExists);
+    });
+}
+
+#[test]
+fn all_migrations_upgrade_baseline_storage() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext().execute_with(|| {
+        // Storage as written by the version 0 pallet: bare names under `u16` IDs
+        let canon: BoundedVec<u8, MaxAuthorityIdLength> = b"CANON".to_vec().try_into().unwrap();
+        let nikon: BoundedVec<u8, MaxAuthorityIdLength> = b"NIKON".to_vec().try_into().unwrap();
+        unhashed::put(&migrations::v1::AuthorityRegistry::<Test>::hashed_key_for(0), &canon);
+        unhashed::put(&migrations::v1::AuthorityRegistry::<Test>::hashed_key_for(1), &nikon);
+        unhashed::put(&NextAuthorityId::<Test>::hashed_key(), &2u16);
+        put_legacy_record([0x8cu8; 32], 0, 1);
+        put_legacy_record([0x8du8; 32], 0, 2);
+        put_legacy_record([0x8eu8; 32], 1, 2);
+        StorageVersion::new(0).put::<Birthmark>();
+
+        migrations::AllMigrations::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Birthmark::storage_version(), 13);
+        assert_eq!(Birthmark::records_per_level(), [3, 0, 0]);
+        assert_eq!(
+            Birthmark::authority_registry(0),
+            Some(AuthorityInfo { name: canon.clone(), active: true })
+        );
+        assert_eq!(
+            Birthmark::authority_registry(1),
+            Some(AuthorityInfo { name: nikon.clone(), active: true })
+        );
+        assert_eq!(Birthmark::authority_id_by_name(&canon), Some(0));
+        assert_eq!(Birthmark::authority_id_by_name(&nikon), Some(1));
+        assert_eq!(Birthmark::next_authority_id(), 2);
+
+        assert_eq!(Birthmark::records_count_for_authority(0), 2);
+        assert_eq!(Birthmark::records_count_for_authority(1), 1);
+        assert!(RecordsByBlock::<Test>::contains_key(1, [0x8cu8; 32]));
+        assert!(RecordsByBlock::<Test>::contains_key(2, [0x8eu8; 32]));
+        assert!(RecordsBySubmissionType::<Test>::contains_key(
+            SubmissionType::Camera,
+            [0x8du8; 32]
+        ));
+
+        let record = Birthmark::get_image_record(&[0x8eu8; 32]).unwrap();
+        assert_eq!(record.authority_id, 1);
+        assert_eq!(record.validator_index, None);
+        assert_eq!(record.schema, 0);
+
+        // Migrated authorities are reused rather than registered again
+        assert_ok!(submit_raw([0x8fu8; 32], b"NIKON"));
+        assert_eq!(Birthmark::get_image_record(&[0x8fu8; 32]).unwrap().authority_id, 1);
+        assert_eq!(Birthmark::next_authority_id(), 2);
+    });
+}
+
+#[test]
+fn migration_v11_indexes_tombstones_by_removal_block() {
+    use frame_support::traits::{Hooks, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        Tombstones::<Test>::insert([0x9au8; 32], Tombstone { removed_at: 5, reason_code: 1 });
+        Tombstones::<Test>::insert([0x9bu8; 32], Tombstone { removed_at: 8, reason_code: 2 });
+        RecordsByBlock::<Test>::insert(3, [0x9au8; 32], ());
+        RecordsByBlock::<Test>::insert(12, [0x9bu8; 32], ());
+        System::set_block_number(20);
+        StorageVersion::new(10).put::<Birthmark>();
+
+        migrations::v11::MigrateV10ToV11::<Test>::on_runtime_upgrade();
+
+        assert!(TombstonesByBlock::<Test>::contains_key(5, [0x9au8; 32]));
+        assert!(TombstonesByBlock::<Test>::contains_key(8, [0x9bu8; 32]));
+        assert_eq!(TombstonePruneCursor::<Test>::get(), 5);
+        // Block-index pruning resumes at the oldest indexed block, not block zero
+        assert_eq!(BlockIndexPruneCursor::<Test>::get(), 3);
+        assert_eq!(Birthmark::storage_version(), 11);
+
+        // Backfilled tombstones expire like newly created ones
+        Birthmark::on_initialize(106);
+        assert_eq!(Birthmark::record_status(&[0x9au8; 32]), RecordStatus::Absent);
+        assert_eq!(Birthmark::record_status(&[0x9bu8; 32]), RecordStatus::Tombstoned);
+    });
+}
+
+#[test]
+fn migration_v12_recounts_levels_and_children() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let parent = [0x9cu8; 32];
+        assert_ok!(submit_raw(parent, b"CANON"));
+        for child in [0x9du8, 0x9e] {
+            assert_ok!(Birthmark::submit_image_record(
+                RuntimeOrigin::signed(1),
+                [child; 32].to_vec(),
+                SubmissionType::Software,
+                2,
+                Some(parent.to_vec()),
+                b"ADOBE".to_vec(),
+                None,
+                None,
+                None,
+            ));
+        }
+        // Counters as left by a chain that stored these before version 12
+        RecordsPerLevel::<Test>::kill();
+        ChildCount::<Test>::insert(parent, 1);
+        ChildCount::<Test>::insert([0x9fu8; 32], 4);
+        StorageVersion::new(11).put::<Birthmark>();
+
+        migrations::v12::MigrateV11ToV12::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Birthmark::records_per_level(), [1, 0, 2]);
+        assert_eq!(ChildCount::<Test>::get(parent), 2);
+        assert!(!ChildCount::<Test>::contains_key([0x9fu8; 32]));
+        assert_eq!(Birthmark::storage_version(), 12);
+
+        // Removal decrements the backfilled counters instead of saturating at zero
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [0x9du8; 32].to_vec(),
+            1,
+            b"duplicate".to_vec(),
+        ));
+        assert_eq!(Birthmark::records_per_level(), [1, 0, 1]);
+        assert_eq!(ChildCount::<Test>::get(parent), 1);
+    });
+}
+
+#[test]
+fn migration_v13_seeds_missing_modification_labels() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // An upgraded chain has no labels; keep one set by governance
+        let _ = ModificationLabels::<Test>::clear(u32::MAX, None);
+        assert_eq!(Birthmark::modification_label(0), None);
+        assert_ok!(Birthmark::set_modification_label(RuntimeOrigin::root(), 2, b"edited".to_vec()));
+        StorageVersion::new(12).put::<Birthmark>();
+
+        migrations::v13::MigrateV12ToV13::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Birthmark::modification_label(0), Some(b"raw".to_vec()));
+        assert_eq!(Birthmark::modification_label(1), Some(b"validated".to_vec()));
+        assert_eq!(Birthmark::modification_label(2), Some(b"edited".to_vec()));
+        assert_eq!(Birthmark::storage_version(), 13);
+    });
+}
+
+#[test]
+fn authority_ids_can_exceed_u16() {
+    new_test_ext().execute_with(|| {
+        MaxAuthorities::set(100_000);
+        NextAuthorityId::<Test>::put(u32::from(u16::MAX));
+
+        assert_ok!(submit_raw([0x78u8; 32], b"CANON"));
+        assert_ok!(submit_raw([0x79u8; 32], b"NIKON"));
+
+        let first = Birthmark::get_image_record(&[0x78u8; 32]).unwrap().authority_id;
+        let second = Birthmark::get_image_record(&[0x79u8; 32]).unwrap().authority_id;
+        assert_eq!(first, u32::from(u16::MAX));
+        assert_eq!(second, u32::from(u16::MAX) + 1);
+        assert_eq!(Birthmark::get_authority_name(second).unwrap().to_vec(), b"NIKON".to_vec());
+    });
+    MaxAuthorities::set(1_000);
+}
+
+#[test]
+fn authority_trust_rewards_verification_and_activity() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Birthmark::authority_trust(0), None);
+
+        assert_ok!(submit_raw([249u8; 32], b"CANON"));
+        assert_ok!(submit_raw([250u8; 32], b"NIKON"));
+        RecordsPerAuthority::<Test>::insert(0, 150);
+        assert_ok!(Birthmark::set_authority_verified(RuntimeOrigin::root(), 0, true));
+
+        // Verified + active + 100..10,000 records against active + 1..100 records
+        assert_eq!(Birthmark::authority_trust(0), Some(90));
+        assert_eq!(Birthmark::authority_trust(1), Some(30));
+
+        assert_ok!(Birthmark::deactivate_authority(RuntimeOrigin::root(), 0));
+        assert_eq!(Birthmark::authority_trust(0), Some(0));
+    });
+}
+
+fn submit_idempotent(
+    hash: [u8; 32],
+    level: u8,
+    authority_name: &[u8],
+) -> DispatchResultWithPostInfo {
+    Birthmark::submit_image_record_idempotent(
+        RuntimeOrigin::signed(1),
+        hash.to_vec(),
+        SubmissionType::Camera,
+        level,
+        None,
+        authority_name.to_vec(),
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn idempotent_submit_accepts_identical_resubmission() {
+    new_test_ext().execute_with(|| {
+        // A fresh hash is stored as usual
+        assert_ok!(submit_idempotent([251u8; 32], 0, b"CANON"));
+        assert!(Birthmark::image_exists(&[251u8; 32]));
+        assert_eq!(Birthmark::total_records(), 1);
+
+        // Retrying the same record is a no-op success
+        assert_ok!(submit_idempotent([251u8; 32], 0, b"CANON"));
+        System::assert_last_event(Event::RecordAlreadyPresent { image_hash: [251u8; 32] }.into());
+        assert_eq!(Birthmark::total_records(), 1);
+    });
+}
+
+#[test]
+fn idempotent_submit_rejects_conflicting_resubmission() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([252u8; 32], b"CANON"));
+
+        assert_noop!(
+            submit_idempotent([252u8; 32], 1, b"CANON"),
+            Error::<Test>::HashAlreadyExists
+        );
+        assert_noop!(
+            submit_idempotent([252u8; 32], 0, b"NIKON"),
+            Error::<Test>::HashAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn takedown_reason_is_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([253u8; 32], b"CANON"));
+
+        assert_noop!(
+            Birthmark::remove_record(RuntimeOrigin::root(), [253u8; 32].to_vec(), 1, vec![0; 17]),
+            Error::<Test>::ReasonTooLong
+        );
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [253u8; 32].to_vec(),
+            1,
+            vec![0; 16],
+        ));
+    });
+}
+
+#[test]
+fn recent_records_are_newest_first_and_bounded() {
+    new_test_ext().execute_with(|| {
+        let hash = |i: u16| {
+            let mut hash = [0xeeu8; 32];
+            hash[..2].copy_from_slice(&i.to_be_bytes());
+            hash
+        };
+
+        assert_ok!(submit_raw(hash(0), b"CANON"));
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(1),
+            batch(vec![
+                (hash(1).to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+                (hash(2).to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ]),
+        ));
+        assert_eq!(Birthmark::recent_records(2), vec![hash(2), hash(1)]);
+        assert_eq!(Birthmark::recent_records(10), vec![hash(2), hash(1), hash(0)]);
+
+        // Filling past capacity evicts the oldest hashes
+        for i in 3..300 {
+            assert_ok!(submit_raw(hash(i), b"CANON"));
+        }
+        let recent = Birthmark::recent_records(u32::MAX);
+        assert_eq!(recent.len(), 256);
+        assert_eq!(recent[0], hash(299));
+        assert_eq!(recent[255], hash(44));
+    });
+}
+
+#[test]
+fn edits_need_parent_when_required() {
+    RequireParentForEdits::set(true);
+    new_test_ext().execute_with(|| {
+        let submit = |hash: [u8; 32], level: u8, parent: Option<[u8; 32]>| {
+            Birthmark::submit_image_record(
+                RuntimeOrigin::signed(1),
+                hash.to_vec(),
+                SubmissionType::Software,
+                level,
+                parent.map(|p| p.to_vec()),
+                b"ADOBE".to_vec(),
+                None,
+                None,
+                None,
+            )
+        };
+
+        // Raw records never need a parent
+        assert_ok!(submit([254u8; 32], 0, None));
+
+        assert_noop!(submit([255u8; 32], 1, None), Error::<Test>::MissingParentForEdit);
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(1),
+                batch(vec![([255u8; 32].to_vec(), SubmissionType::Software, 2, None, b"ADOBE".to_vec())]),
+            ),
+            Error::<Test>::MissingParentForEdit
+        );
+        assert_ok!(submit([255u8; 32], 1, Some([254u8; 32])));
+
+        // Reclassification cannot turn a parentless record into an edit either
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::root(), [254u8; 32].to_vec(), 1),
+            Error::<Test>::MissingParentForEdit
+        );
+        assert_ok!(Birthmark::reclassify_record(RuntimeOrigin::root(), [255u8; 32].to_vec(), 2));
+        assert_ok!(Birthmark::reclassify_record(RuntimeOrigin::root(), [255u8; 32].to_vec(), 0));
+    });
+    RequireParentForEdits::set(false);
+}
+
+#[test]
+fn records_merkle_root_depends_only_on_the_record_set() {
+    use sp_runtime::traits::BlakeTwo256;
+
+    let leaf = |hash: [u8; 32]| BlakeTwo256::hash(&[&[MERKLE_LEAF_TAG][..], &hash[..]].concat()).0;
+    let (low, high) = ([0x11u8; 32], [0x22u8; 32]);
+    let root_after = |order: [[u8; 32]; 2]| {
+        new_test_ext().execute_with(|| {
+            for hash in order {
+                assert_ok!(submit_raw(hash, b"CANON"));
+            }
+            Birthmark::records_merkle_root(1, 1).unwrap()
+        })
+    };
+
+    let root = root_after([low, high]);
+    assert_eq!(root, root_after([high, low]));
+    let node = [&[MERKLE_NODE_TAG][..], &leaf(low)[..], &leaf(high)[..]].concat();
+    assert_eq!(root, BlakeTwo256::hash(&node).0);
+
+    new_test_ext().execute_with(|| {
+        assert_eq!(Birthmark::records_merkle_root(1, 1), Some([0u8; 32]));
+
+        // A lone record's root is its tagged leaf, not the record hash itself
+        assert_ok!(submit_raw(low, b"CANON"));
+        assert_eq!(Birthmark::records_merkle_root(1, 1), Some(leaf(low)));
+
+        assert_ok!(submit_raw(high, b"CANON"));
+        assert_eq!(Birthmark::records_merkle_root(1, 5), Some(root));
+
+        // Another record changes the root
+        System::set_block_number(2);
+        assert_ok!(submit_raw([0x33u8; 32], b"CANON"));
+        assert_ne!(Birthmark::records_merkle_root(1, 2), Some(root));
+        assert_eq!(Birthmark::records_merkle_root(1, 1), Some(root));
+
+        // Too wide a range is refused
+        let to = u64::from(MAX_BLOCK_RANGE) + 2;
+        assert_eq!(Birthmark::records_merkle_root(1, to), None);
+        assert!(Birthmark::records_merkle_root(2, to).is_some());
+    });
+}
+
+#[test]
+fn records_merkle_root_is_bounded_by_record_count() {
+    use sp_runtime::traits::BlakeTwo256;
+
+    let hash = |i: u32| {
+        let mut hash = [0x55u8; 32];
+        hash[..4].copy_from_slice(&i.to_be_bytes());
+        hash
+    };
+    let leaf = |hash: [u8; 32]| BlakeTwo256::hash(&[&[MERKLE_LEAF_TAG][..], &hash[..]].concat()).0;
+    let node = |left: [u8; 32], right: [u8; 32]| {
+        BlakeTwo256::hash(&[&[MERKLE_NODE_TAG][..], &left[..], &right[..]].concat()).0
+    };
+
+    new_test_ext().execute_with(|| {
+        // An odd last node moves up unchanged
+        for i in 0..3 {
+            RecordsByBlock::<Test>::insert(1, hash(i), ());
+        }
+        let root = node(node(leaf(hash(0)), leaf(hash(1))), leaf(hash(2)));
+        assert_eq!(Birthmark::records_merkle_root(1, 1), Some(root));
+
+        for i in 3..MAX_MERKLE_LEAVES {
+            RecordsByBlock::<Test>::insert(2, hash(i), ());
+        }
+        assert!(Birthmark::records_merkle_root(1, 2).is_some());
+
+        // One record past the bound is refused even though the range is narrow
+        RecordsByBlock::<Test>::insert(3, hash(MAX_MERKLE_LEAVES), ());
+        assert_eq!(Birthmark::records_merkle_root(1, 3), None);
+        assert!(Birthmark::records_merkle_root(2, 3).is_some());
+    });
+}
+
+#[test]
+fn removal_origin_gates_record_edits() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0x44u8; 32], b"CANON"));
+
+        // Not even the submitting aggregator may edit the record set
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::signed(1), [0x44u8; 32].to_vec(), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::remove_record(RuntimeOrigin::signed(1), [0x44u8; 32].to_vec(), 1, vec![]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Birthmark::reclassify_record(RuntimeOrigin::root(), [0x44u8; 32].to_vec(), 2));
+        assert_ok!(Birthmark::remove_record(
+            RuntimeOrigin::root(),
+            [0x44u8; 32].to_vec(),
+            1,
+            vec![],
+        ));
+    });
+}
+
+#[test]
+fn aggregator_status_follows_allowlist() {
+    new_test_ext().execute_with(|| {
+        assert!(Birthmark::is_authorized_aggregator(&1));
+        assert!(!Birthmark::is_authorized_aggregator(&42));
+
+        assert_ok!(Birthmark::set_aggregators(RuntimeOrigin::root(), vec![42].try_into().unwrap()));
+        assert!(Birthmark::is_authorized_aggregator(&42));
+        assert!(!Birthmark::is_authorized_aggregator(&1));
+    });
+}
+
+#[test]
+fn prune_authority_removes_only_empty_authorities() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"UNUSED".to_vec()));
+        assert_ok!(submit_raw([0x55u8; 32], b"CANON"));
+        let unused = 0;
+        let canon = Birthmark::get_image_record(&[0x55u8; 32]).unwrap().authority_id;
+
+        assert_noop!(
+            Birthmark::prune_authority(RuntimeOrigin::root(), canon),
+            Error::<Test>::AuthorityHasRecords
+        );
+        assert_noop!(
+            Birthmark::prune_authority(RuntimeOrigin::signed(1), unused),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Birthmark::prune_authority(RuntimeOrigin::root(), unused));
+        System::assert_last_event(Event::AuthorityPruned { authority_id: unused }.into());
+        assert!(Birthmark::get_authority_name(unused).is_none());
+        assert_noop!(
+            Birthmark::prune_authority(RuntimeOrigin::root(), unused),
+            Error::<Test>::AuthorityNotFound
+        );
+
+        // The name is free again and gets a fresh ID
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"UNUSED".to_vec()));
+        assert_eq!(Birthmark::get_authority_name(2).unwrap().to_vec(), b"UNUSED".to_vec());
+    });
+}
+
+#[test]
+fn pruned_genesis_authority_leaves_genesis_list() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_birthmark::GenesisConfig::<Test> {
+        aggregators: vec![1],
+        authorities: vec![b"CANON".to_vec(), b"NIKON".to_vec()],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    sp_io::TestExternalities::new(t).execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(Birthmark::prune_authority(RuntimeOrigin::root(), 0));
+        assert_eq!(Birthmark::genesis_authorities(), vec![1]);
+
+        // Re-registering the name does not make it a genesis authority again
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"CANON".to_vec()));
+        assert_eq!(Birthmark::genesis_authorities(), vec![1]);
+    });
+}
+
+#[test]
+fn tombstone_pruning_respects_configured_cap() {
+    use frame_support::traits::Hooks;
+
+    MaxPrunedPerBlock::set(3);
+    new_test_ext().execute_with(|| {
+        TombstonePruneCursor::<Test>::put(1);
+        for i in 0..10u32 {
+            let mut hash = [0x66u8; 32];
+            hash[..4].copy_from_slice(&i.to_le_bytes());
+            Tombstones::<Test>::insert(hash, Tombstone { removed_at: 1, reason_code: 0 });
+            TombstonesByBlock::<Test>::insert(1, hash, ());
+        }
+
+        for (block, left) in [(200, 7), (201, 4), (202, 1), (203, 0)] {
+            // The whole budget is spent, on removals or on visiting empty blocks
+            let weight = Birthmark::on_initialize(block);
+            assert_eq!(weight, <() as weights::WeightInfo>::prune_tombstones(3));
+            assert_eq!(Tombstones::<Test>::iter().count(), left);
+        }
+        System::assert_last_event(Event::TombstonesPruned { count: 1 }.into());
+    });
+    MaxPrunedPerBlock::set(16);
+}
+
+#[test]
+fn records_by_submitter_partitions_by_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0x81u8; 32], b"CANON"));
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(1),
+            batch(vec![([0x82u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec())]),
+        ));
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(2),
+            batch(vec![
+                ([0x83u8; 32].to_vec(), SubmissionType::Software, 1, None, b"ADOBE".to_vec()),
+                ([0x84u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"SONY".to_vec()),
+            ]),
+        ));
+
+        let mut first = Birthmark::records_by_submitter(&1, None, 10);
+        first.sort();
+        assert_eq!(first, vec![[0x81u8; 32], [0x82u8; 32]]);
+        let mut second = Birthmark::records_by_submitter(&2, None, 10);
+        second.sort();
+        assert_eq!(second, vec![[0x83u8; 32], [0x84u8; 32]]);
+        assert!(Birthmark::records_by_submitter(&3, None, 10).is_empty());
+
+        // Paging resumes after the last hash of the previous page
+        let page = Birthmark::records_by_submitter(&2, None, 1);
+        let rest = Birthmark::records_by_submitter(&2, Some(page[0]), 10);
+        assert_eq!(rest.len(), 1);
+        assert_ne!(rest[0], page[0]);
+    });
+}
+
+#[test]
+fn removal_clears_the_submitter_index() {
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        let hash = [0x6cu8; 32];
+        assert_ok!(submit_raw(hash, b"CANON"));
+        assert_eq!(RecordSubmitter::<Test>::get(hash), Some(1));
+
+        assert_ok!(Birthmark::remove_record(RuntimeOrigin::root(), hash.to_vec(), 1, b"court order".to_vec()));
+        assert!(Birthmark::records_by_submitter(&1, None, 10).is_empty());
+        assert_eq!(Birthmark::submissions_by(&1), 0);
+        assert_eq!(RecordSubmitter::<Test>::get(hash), None);
+
+        // Once the tombstone expires, another aggregator may register the hash
+        System::set_block_number(102);
+        Birthmark::on_initialize(102);
+        assert_ok!(Birthmark::submit_image_record(
+            RuntimeOrigin::signed(2),
+            hash.to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            b"CANON".to_vec(),
+            None,
+            None,
+            None,
+        ));
+
+        // The first submitter holds no rights over the new record
+        assert_noop!(
+            Birthmark::amend_record(RuntimeOrigin::signed(1), hash.to_vec(), 1, SubmissionType::Camera),
+            Error::<Test>::NotOriginalSubmitter
+        );
+        assert_eq!(Birthmark::records_by_submitter(&2, None, 10), vec![hash]);
+    });
+}
+
+#[test]
+fn amend_record_within_mutability_window() {
+    new_test_ext().execute_with(|| {
+        let hash = [0x91u8; 32];
+        assert_ok!(submit_raw(hash, b"CANON"));
+        System::set_block_number(1 + MutabilityWindow::get());
+
+        assert_ok!(Birthmark::amend_record(
+            RuntimeOrigin::signed(1),
+            hash.to_vec(),
+            1,
+            SubmissionType::Software,
+        ));
+
+        let record = Birthmark::get_image_record(&hash).unwrap();
+        assert_eq!(record.modification_level, 1);
+        assert_eq!(record.submission_type, SubmissionType::Software);
+        assert_eq!(Birthmark::records_per_level(), [0, 1, 0]);
+        assert_eq!(Birthmark::records_by_submission_type(SubmissionType::Software, None, 10), vec![hash]);
+        assert!(Birthmark::records_by_submission_type(SubmissionType::Camera, None, 10).is_empty());
+        System::assert_last_event(
+            Event::RecordAmended {
+                image_hash: hash,
+                old_level: 0,
+                new_level: 1,
+                submission_type: SubmissionType::Software,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn amend_record_rejected_after_window_or_by_others() {
+    new_test_ext().execute_with(|| {
+        let hash = [0x92u8; 32];
+        assert_ok!(submit_raw(hash, b"CANON"));
+
+        // Account 2 is an aggregator too, but not the submitter
+        assert_noop!(
+            Birthmark::amend_record(RuntimeOrigin::signed(2), hash.to_vec(), 1, SubmissionType::Camera),
+            Error::<Test>::NotOriginalSubmitter
+        );
+
+        System::set_block_number(2 + MutabilityWindow::get());
+        assert_noop!(
+            Birthmark::amend_record(RuntimeOrigin::signed(1), hash.to_vec(), 1, SubmissionType::Camera),
+            Error::<Test>::MutabilityWindowClosed
+        );
+        assert_eq!(Birthmark::get_image_record(&hash).unwrap().modification_level, 0);
+    });
+}
+
+#[test]
+fn duplicate_submission_counts_as_rejection() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0xa1u8; 32], b"CANON"));
+
+        // The runtime notes the error after dispatch, outside the rolled-back call
+        let error = submit_raw([0xa1u8; 32], b"CANON").unwrap_err().error;
+        Birthmark::note_rejection(&error);
+        Birthmark::note_rejection(&error);
+
+        let DispatchError::Module(ModuleError { error: code, .. }) =
+            DispatchError::from(Error::<Test>::HashAlreadyExists)
+        else {
+            panic!("pallet errors are module errors");
+        };
+        assert_eq!(Birthmark::rejection_stats(), vec![(code[0], 2)]);
+
+        // Errors from outside the pallet are not counted
+        Birthmark::note_rejection(&DispatchError::BadOrigin);
+        assert_eq!(Birthmark::rejection_stats(), vec![(code[0], 2)]);
+    });
+}
+
+#[test]
+fn alternate_hash_links_resolve_both_ways() {
+    new_test_ext().execute_with(|| {
+        let sha256 = [0xb1u8; 32];
+        let blake3 = [0xb2u8; 32];
+        assert_ok!(submit_raw(sha256, b"CANON"));
+        assert_ok!(submit_raw(blake3, b"CANON"));
+
+        assert_noop!(
+            Birthmark::link_alternate_hash(RuntimeOrigin::signed(1), blake3.to_vec(), [0xb3u8; 32].to_vec()),
+            Error::<Test>::RecordNotFound
+        );
+        assert_noop!(
+            Birthmark::link_alternate_hash(RuntimeOrigin::signed(2), blake3.to_vec(), sha256.to_vec()),
+            Error::<Test>::NotOriginalSubmitter
+        );
+        assert_ok!(Birthmark::link_alternate_hash(
+            RuntimeOrigin::signed(1),
+            blake3.to_vec(),
+            sha256.to_vec()
+        ));
+        System::assert_last_event(
+            Event::AlternateHashLinked { image_hash: blake3, linked_to: sha256 }.into(),
+        );
+        assert_noop!(
+            Birthmark::link_alternate_hash(RuntimeOrigin::signed(1), blake3.to_vec(), sha256.to_vec()),
+            Error::<Test>::AlreadyLinked
+        );
+
+        assert_eq!(Birthmark::linked_records(&blake3), vec![sha256]);
+        assert_eq!(Birthmark::linked_records(&sha256), vec![blake3]);
+
+        // Removing either side drops the link
+        assert_ok!(Birthmark::remove_record(RuntimeOrigin::root(), sha256.to_vec(), 0, vec![]));
+        assert!(Birthmark::linked_records(&blake3).is_empty());
+    });
+}
+
+#[test]
+fn authority_quota_caps_records() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0xc1u8; 32], b"CANON"));
+        assert_ok!(Birthmark::set_authority_quota(RuntimeOrigin::root(), 0, Some(2)));
+        System::assert_last_event(Event::AuthorityQuotaSet { authority_id: 0, quota: Some(2) }.into());
+
+        // The second record reaches the quota; a third would pass it
+        assert_ok!(submit_raw([0xc2u8; 32], b"CANON"));
+        assert_noop!(submit_raw([0xc3u8; 32], b"CANON"), Error::<Test>::AuthorityQuotaExceeded);
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(1),
+                batch(vec![([0xc3u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec())]),
+            ),
+            Error::<Test>::AuthorityQuotaExceeded
+        );
+        // Other authorities are unaffected
+        assert_ok!(submit_raw([0xc4u8; 32], b"NIKON"));
+
+        // Raising the quota re-enables submissions, clearing it removes the cap
+        assert_ok!(Birthmark::set_authority_quota(RuntimeOrigin::root(), 0, Some(3)));
+        assert_ok!(submit_raw([0xc3u8; 32], b"CANON"));
+        assert_noop!(submit_raw([0xc5u8; 32], b"CANON"), Error::<Test>::AuthorityQuotaExceeded);
+        assert_ok!(Birthmark::set_authority_quota(RuntimeOrigin::root(), 0, None));
+        assert_ok!(submit_raw([0xc5u8; 32], b"CANON"));
+        assert_eq!(Birthmark::records_count_for_authority(0), 4);
+    });
+}
+
+#[test]
+fn authority_quota_counts_earlier_batch_records() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0xc6u8; 32], b"CANON"));
+        assert_ok!(Birthmark::set_authority_quota(RuntimeOrigin::root(), 0, Some(2)));
+
+        let records = vec![
+            ([0xc7u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([0xc8u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+        ];
+        assert_noop!(
+            Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)),
+            Error::<Test>::AuthorityQuotaExceeded
+        );
+        assert_noop!(
+            Birthmark::set_authority_quota(RuntimeOrigin::root(), 9, Some(1)),
+            Error::<Test>::AuthorityNotFound
+        );
+    });
+}
+
+#[test]
+fn storage_footprint_grows_per_record_and_authority() {
+    new_test_ext().execute_with(|| {
+        let record = ImageRecord::max_encoded_len() as u64;
+        let authority = AuthorityInfo::<MaxAuthorityIdLength>::max_encoded_len() as u64;
+        assert_eq!(Birthmark::storage_footprint(), StorageFootprint::default());
+
+        assert_ok!(submit_raw([0xe1u8; 32], b"CANON"));
+        assert_ok!(submit_raw([0xe2u8; 32], b"CANON"));
+        let footprint = Birthmark::storage_footprint();
+        assert_eq!((footprint.record_count, footprint.authority_count), (2, 1));
+        assert_eq!(footprint.estimated_bytes, 2 * record + authority);
+
+        // A record under a new authority adds one of each
+        assert_ok!(submit_raw([0xe3u8; 32], b"NIKON"));
+        let grown = Birthmark::storage_footprint();
+        assert_eq!(grown.estimated_bytes - footprint.estimated_bytes, record + authority);
+    });
+}
+
+#[test]
+fn rename_authority_keeps_id_and_records_name_history() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0xf1u8; 32], b"CANON"));
+        let id = Birthmark::get_image_record(&[0xf1u8; 32]).unwrap().authority_id;
+        assert_eq!(Birthmark::authority_name_history(id), vec![b"CANON".to_vec()]);
+
+        assert_noop!(
+            Birthmark::rename_authority(RuntimeOrigin::signed(1), id, b"CANON_INC".to_vec()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Birthmark::rename_authority(RuntimeOrigin::root(), id + 1, b"CANON_INC".to_vec()),
+            Error::<Test>::AuthorityNotFound
+        );
+
+        assert_ok!(Birthmark::rename_authority(RuntimeOrigin::root(), id, b"CANON_INC".to_vec()));
+        System::assert_last_event(
+            Event::AuthorityRenamed {
+                authority_id: id,
+                old_name: b"CANON".to_vec().try_into().unwrap(),
+                new_name: b"CANON_INC".to_vec().try_into().unwrap(),
+            }
+            .into(),
+        );
+        assert_ok!(Birthmark::rename_authority(RuntimeOrigin::root(), id, b"CANON_GLOBAL".to_vec()));
+
+        assert_eq!(Birthmark::get_authority_name(id).unwrap().to_vec(), b"CANON_GLOBAL".to_vec());
+        let current: BoundedVec<u8, MaxAuthorityIdLength> =
+            b"CANON_GLOBAL".to_vec().try_into().unwrap();
+        assert_eq!(AuthorityIdByName::<Test>::get(current), Some(id));
+        assert_eq!(
+            Birthmark::authority_name_history(id),
+            vec![b"CANON".to_vec(), b"CANON_INC".to_vec(), b"CANON_GLOBAL".to_vec()]
+        );
+
+        // The old name is free again, so submissions under it register a new authority
+        assert_ok!(submit_raw([0xf2u8; 32], b"CANON"));
+        assert_ne!(Birthmark::get_image_record(&[0xf2u8; 32]).unwrap().authority_id, id);
+        assert_noop!(
+            Birthmark::rename_authority(RuntimeOrigin::root(), id, b"CANON".to_vec()),
+            Error::<Test>::AuthorityAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn authority_name_history_is_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"NAME_0".to_vec()));
+        for i in 1..=10 {
+            let name = format!("NAME_{i}").into_bytes();
+            assert_ok!(Birthmark::rename_authority(RuntimeOrigin::root(), 0, name));
+        }
+
+        let history = Birthmark::authority_name_history(0);
+        assert_eq!(history.len(), MaxNameHistory::get() as usize);
+        assert_eq!(history.last().unwrap(), &Birthmark::get_authority_name(0).unwrap().to_vec());
+        assert_eq!(history.first().unwrap(), b"NAME_3".as_slice());
+    });
+}
+
+#[test]
+fn submission_cooldown_spaces_out_an_account() {
+    SubmissionCooldown::set(3);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(submit_raw([0xf3u8; 32], b"CANON"));
+        assert_eq!(LastSubmission::<Test>::get(1), Some(1));
+
+        System::set_block_number(3);
+        assert_noop!(submit_raw([0xf4u8; 32], b"CANON"), Error::<Test>::SubmissionCooldown);
+
+        System::set_block_number(4);
+        assert_ok!(submit_raw([0xf4u8; 32], b"CANON"));
+        assert_eq!(LastSubmission::<Test>::get(1), Some(4));
+    });
+    SubmissionCooldown::set(0);
+}
+
+#[test]
+fn batch_counts_as_one_submission_for_cooldown() {
+    SubmissionCooldown::set(2);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let records = vec![
+            ([0xf5u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+            ([0xf6u8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()),
+        ];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+        assert_noop!(submit_raw([0xf7u8; 32], b"CANON"), Error::<Test>::SubmissionCooldown);
+
+        System::set_block_number(3);
+        assert_ok!(submit_raw([0xf7u8; 32], b"CANON"));
+    });
+    SubmissionCooldown::set(0);
+}
+
+#[test]
+fn config_constants_report_configured_limits() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Birthmark::config_constants(),
+            BirthmarkConstants {
+                max_batch_size: MaxBatchSize::get(),
+                max_authority_id_length: MaxAuthorityIdLength::get(),
+                max_image_hash_length: MaxImageHashLength::get(),
+                max_provenance_depth: MaxProvenanceDepth::get(),
+            }
+        );
+    });
+}
+
+#[test]
+fn sealed_authority_records_are_frozen() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0xf8u8; 32], b"CANON"));
+        assert_ok!(submit_raw([0xf9u8; 32], b"SONY"));
+        let canon = Birthmark::get_image_record(&[0xf8u8; 32]).unwrap().authority_id;
+        let sony = Birthmark::get_image_record(&[0xf9u8; 32]).unwrap().authority_id;
+
+        assert_noop!(
+            Birthmark::seal_authority(RuntimeOrigin::signed(1), canon),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Birthmark::seal_authority(RuntimeOrigin::root(), canon));
+        System::assert_last_event(Event::AuthoritySealed { authority_id: canon }.into());
+        assert!(Birthmark::is_authority_sealed(canon));
+        assert_noop!(
+            Birthmark::seal_authority(RuntimeOrigin::root(), canon),
+            Error::<Test>::AuthorityAlreadySealed
+        );
+
+        let sealed = [0xf8u8; 32].to_vec();
+        assert_noop!(
+            Birthmark::reclassify_record(RuntimeOrigin::root(), sealed.clone(), 2),
+            Error::<Test>::AuthoritySealed
+        );
+        assert_noop!(
+            Birthmark::reassign_authority(RuntimeOrigin::root(), sealed.clone(), sony),
+            Error::<Test>::AuthoritySealed
+        );
+        assert_noop!(
+            Birthmark::remove_record(RuntimeOrigin::root(), sealed.clone(), 1, vec![]),
+            Error::<Test>::AuthoritySealed
+        );
+        assert_noop!(
+            Birthmark::amend_record(RuntimeOrigin::signed(1), sealed, 1, SubmissionType::Camera),
+            Error::<Test>::AuthoritySealed
+        );
+        // Nothing can be moved into a sealed authority either
+        assert_noop!(
+            Birthmark::reassign_authority(RuntimeOrigin::root(), [0xf9u8; 32].to_vec(), canon),
+            Error::<Test>::AuthoritySealed
+        );
+
+        // Other authorities stay mutable
+        let open = [0xf9u8; 32].to_vec();
+        assert_ok!(Birthmark::reclassify_record(RuntimeOrigin::root(), open.clone(), 2));
+        assert_ok!(Birthmark::remove_record(RuntimeOrigin::root(), open, 1, vec![]));
+    });
+}
+
+#[test]
+fn sealed_authority_cannot_be_pruned() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Birthmark::register_authority(RuntimeOrigin::root(), b"RETIRED".to_vec()));
+        assert_ok!(Birthmark::seal_authority(RuntimeOrigin::root(), 0));
+        assert_noop!(
+            Birthmark::prune_authority(RuntimeOrigin::root(), 0),
+            Error::<Test>::AuthoritySealed
+        );
+        assert_noop!(
+            Birthmark::seal_authority(RuntimeOrigin::root(), 1),
+            Error::<Test>::AuthorityNotFound
+        );
+    });
+}
+
+fn submit_inferred(
+    hash: [u8; 32],
+    submission_type: Option<SubmissionType>,
+    level: u8,
+) -> DispatchResultWithPostInfo {
+    Birthmark::submit_image_record_inferred(
+        RuntimeOrigin::signed(1),
+        hash.to_vec(),
+        submission_type,
+        level,
+        None,
+        b"CANON".to_vec(),
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn inferred_submission_type_follows_level() {
+    new_test_ext().execute_with(|| {
+        let expected = [SubmissionType::Camera, SubmissionType::Software, SubmissionType::Software];
+        for (level, kind) in expected.into_iter().enumerate() {
+            let hash = [0xfa + level as u8; 32];
+            assert_ok!(submit_inferred(hash, None, level as u8));
+            assert_eq!(Birthmark::get_image_record(&hash).unwrap().submission_type, kind);
+        }
+    });
+}
+
+#[test]
+fn explicit_submission_type_overrides_inference() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_inferred([0xfdu8; 32], Some(SubmissionType::Software), 0));
+        assert_ok!(submit_inferred([0xfeu8; 32], Some(SubmissionType::Camera), 2));
+
+        let kind = |hash: [u8; 32]| Birthmark::get_image_record(&hash).unwrap().submission_type;
+        assert_eq!(kind([0xfdu8; 32]), SubmissionType::Software);
+        assert_eq!(kind([0xfeu8; 32]), SubmissionType::Camera);
+    });
+}
+
+#[test]
+fn batch_detail_lists_stored_hashes_in_order() {
+    EmitBatchDetail::set(true);
+    new_test_ext().execute_with(|| {
+        let hashes = [[0x1au8; 32], [0x0bu8; 32], [0x2cu8; 32]];
+        let records = hashes
+            .iter()
+            .map(|hash| (hash.to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec()))
+            .collect();
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+
+        System::assert_last_event(
+            Event::BatchDetail { hashes: hashes.to_vec().try_into().unwrap() }.into(),
+        );
+        System::assert_has_event(Event::ImageBatchSubmitted { count: 3 }.into());
+    });
+    EmitBatchDetail::set(false);
+}
+
+#[test]
+fn batch_detail_is_opt_in() {
+    new_test_ext().execute_with(|| {
+        let records = vec![([0x3du8; 32].to_vec(), SubmissionType::Camera, 0, None, b"CANON".to_vec())];
+        assert_ok!(Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)));
+
+        System::assert_last_event(Event::ImageBatchSubmitted { count: 1 }.into());
+        assert!(!System::events()
+            .iter()
+            .any(|record| matches!(record.event, RuntimeEvent::Birthmark(Event::BatchDetail { .. }))));
+    });
+}
+
+fn submit_child(hash: [u8; 32], parent: [u8; 32]) -> DispatchResultWithPostInfo {
+    Birthmark::submit_image_record(
+        RuntimeOrigin::signed(1),
+        hash.to_vec(),
+        SubmissionType::Software,
+        2,
+        Some(parent.to_vec()),
+        b"ADOBE".to_vec(),
+        None,
+        None,
+        None,
+    )
+}
+
+#[test]
+fn children_per_parent_are_capped() {
+    MaxChildrenPerParent::set(2);
+    new_test_ext().execute_with(|| {
+        let parent = [0x4eu8; 32];
+        assert_ok!(submit_raw(parent, b"CANON"));
+
+        assert_ok!(submit_child([0x4fu8; 32], parent));
+        assert_ok!(submit_child([0x50u8; 32], parent));
+        assert_eq!(ChildCount::<Test>::get(parent), 2);
+        assert_noop!(submit_child([0x51u8; 32], parent), Error::<Test>::TooManyChildren);
+
+        // Batches are held to the same cap
+        let records = vec![(
+            [0x51u8; 32].to_vec(),
+            SubmissionType::Software,
+            2,
+            Some(parent.to_vec()),
+            b"ADOBE".to_vec(),
+        )];
+        assert_noop!(
+            Birthmark::submit_image_batch(RuntimeOrigin::signed(1), batch(records)),
+            Error::<Test>::TooManyChildren
+        );
+
+        // Removing a child frees a slot; other parents are unaffected
+        assert_ok!(Birthmark::remove_record(RuntimeOrigin::root(), [0x50u8; 32].to_vec(), 1, vec![]));
+        assert_ok!(submit_child([0x51u8; 32], parent));
+        assert_ok!(submit_child([0x52u8; 32], [0x4fu8; 32]));
+    });
+    MaxChildrenPerParent::set(100);
+}
+
+#[test]
+fn batch_siblings_count_towards_child_cap() {
+    MaxChildrenPerParent::set(2);
+    new_test_ext().execute_with(|| {
+        let parent = [0x53u8; 32];
+        assert_ok!(submit_raw(parent, b"CANON"));
+
+        let child = |hash: u8| {
+            ([hash; 32].to_vec(), SubmissionType::Software, 2, Some(parent.to_vec()), b"ADOBE".to_vec())
+        };
+        assert_noop!(
+            Birthmark::submit_image_batch(
+                RuntimeOrigin::signed(1),
+                batch(vec![child(0x54), child(0x55), child(0x56)])
+            ),
+            Error::<Test>::TooManyChildren
+        );
+        assert_ok!(Birthmark::submit_image_batch(
+            RuntimeOrigin::signed(1),
+            batch(vec![child(0x54), child(0x55)])
+        ));
+    });
+    MaxChildrenPerParent::set(100);
+}
+
+#[test]
+fn records_with_authority_are_positional_and_capped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit_raw([0x57u8; 32], b"CANON"));
+        assert_ok!(submit_raw([0x59u8; 32], b"NIKON"));
+
+        let resolved = Birthmark::get_records_with_authority(&[[0x58u8; 32], [0x59u8; 32], [0x57u8; 32]]);
+        let names: Vec<_> = resolved
+            .into_iter()
+            .map(|entry| entry.map(|(record, name)| (record.image_hash, name.unwrap())))
+            .collect();
+        assert_eq!(
+            names,
+            vec![None, Some(([0x59u8; 32], b"NIKON".to_vec())), Some(([0x57u8; 32], b"CANON".to_vec()))]
+        );
+
+        let many = vec![[0x57u8; 32]; MaxBulkQuerySize::get() as usize + 5];
+        assert_eq!(Birthmark::get_records_with_authority(&many).len(), MaxBulkQuerySize::get() as usize);
+    });
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis authority name \"CANON\" at index 2")]
+fn duplicate_genesis_authority_names_abort_genesis() {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let _ = pallet_birthmark::GenesisConfig::<Test> {
+        authorities: vec![b"CANON".to_vec(), b"NIKON".to_vec(), b"CANON".to_vec()],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage);
+}
+
+#[test]
+fn force_submit_is_operational_and_governance_only() {
+    use frame_support::dispatch::{DispatchClass, GetDispatchInfo};
+
+    let call = Call::<Test>::force_submit_image_record {
+        image_hash: [0x6au8; 32].to_vec(),
+        submission_type: SubmissionType::Camera,
+        modification_level: 0,
+        parent_image_hash: None,
+        authority_name: b"CANON".to_vec(),
+    };
+    assert_eq!(call.get_dispatch_info().class, DispatchClass::Operational);
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Birthmark::force_submit_image_record(
+                RuntimeOrigin::signed(1),
+                [0x6au8; 32].to_vec(),
+                SubmissionType::Camera,
+                0,
+                None,
+                b"CANON".to_vec(),
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Birthmark::force_submit_image_record(
+            RuntimeOrigin::root(),
+            [0x6au8; 32].to_vec(),
+            SubmissionType::Camera,
+            0,
+            None,
+            b"CANON".to_vec(),
+        ));
+        assert!(Birthmark::image_exists(&[0x6au8; 32]));
+
+        // No account submitted it, so no submitter index is touched
+        assert!(Birthmark::records_by_submitter(&1, None, 10).is_empty());
+        assert_eq!(Birthmark::submissions_by(&1), 0);
+    });
+}
@@ -0,0 +1,162 @@
+//! Weights for the Birthmark pallet.
+//!
+//! Derived by hand from the storage accesses of the benchmarked paths in
+//! `benchmarking.rs`, with a fixed execution cost per item. Replace with the
+//! benchmark output once it has been run on reference hardware:
+//!
+//! ```text
+//! birthmark-node benchmark pallet --chain dev --pallet pallet_birthmark --extrinsic '*' \
+//!     --output pallets/birthmark/src/weights.rs
+//! ```
+
+use frame_support::{
+    traits::Get,
+    weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed by the Birthmark pallet
+pub trait WeightInfo {
+    fn submit_image_record_existing_authority() -> Weight;
+    fn submit_image_record_new_authority() -> Weight;
+    fn submit_image_batch(n: u32, p: u32) -> Weight;
+    fn prune_tombstones(n: u32) -> Weight;
+    fn prune_block_index(n: u32) -> Weight;
+    fn register_authorities_batch(n: u32) -> Weight;
+}
+
+/// Weights for the Birthmark pallet, using the runtime's database weights
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `Birthmark::AuthorizedAggregators` (r:1 w:0)
+    /// Storage: `Birthmark::ImageRecords` (r:1 w:1)
+    /// Storage: `Birthmark::Tombstones` (r:1 w:0)
+    /// Storage: `Birthmark::AuthorityIdByName` (r:1 w:0)
+    /// Storage: `Birthmark::AuthorityRegistry` (r:1 w:0)
+    /// Storage: `Birthmark::RecordsPerAuthority` (r:1 w:1)
+    /// Storage: `Birthmark::SubmissionsByAccount` (r:1 w:1)
+    /// Storage: `Birthmark::RecentRecords` (r:1 w:1)
+    /// Storage: record indexes and counters (r:6 w:8)
+    fn submit_image_record_existing_authority() -> Weight {
+        Weight::from_parts(38_000_000, 4_200)
+            .saturating_add(T::DbWeight::get().reads(14_u64))
+            .saturating_add(T::DbWeight::get().writes(12_u64))
+    }
+    /// Storage: as `submit_image_record_existing_authority`, plus
+    /// Storage: `Birthmark::NextAuthorityId` (r:1 w:1)
+    /// Storage: `Birthmark::ProductionMode` (r:1 w:0)
+    /// Storage: `Birthmark::RequireVerifiedAuthority` (r:1 w:0)
+    /// Storage: `Birthmark::AuthorityRegistry` (r:0 w:1)
+    /// Storage: `Birthmark::AuthorityIdByName` (r:0 w:1)
+    fn submit_image_record_new_authority() -> Weight {
+        Weight::from_parts(52_000_000, 5_800)
+            .saturating_add(T::DbWeight::get().reads(17_u64))
+            .saturating_add(T::DbWeight::get().writes(15_u64))
+    }
+    /// Storage: `Birthmark::AuthorizedAggregators` (r:1 w:0)
+    /// Storage: `Birthmark::ImageRecords` (r:n+2p w:n+p)
+    /// Storage: `Birthmark::ChildCount` (r:p w:p)
+    /// Storage: record indexes and counters (r:6(n+p) w:10(n+p))
+    /// The range of component `n` is `[0, MaxBatchSize / 2]`.
+    /// The range of component `p` is `[1, MaxBatchSize / 2]`.
+    fn submit_image_batch(n: u32, p: u32) -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(Weight::from_parts(27_000_000, 2_600).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(33_000_000, 5_200).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(
+                4_u64
+                    .saturating_add(u64::from(n).saturating_mul(8))
+                    .saturating_add(u64::from(p).saturating_mul(10)),
+            ))
+            .saturating_add(T::DbWeight::get().writes(
+                1_u64
+                    .saturating_add(u64::from(n).saturating_mul(11))
+                    .saturating_add(u64::from(p).saturating_mul(12)),
+            ))
+    }
+    /// Storage: `Birthmark::TombstonePruneCursor` (r:1 w:1)
+    /// Storage: `Birthmark::TombstonesByBlock` (r:n w:n)
+    /// Storage: `Birthmark::Tombstones` (r:0 w:n)
+    /// The range of component `n` is `[1, MaxPrunedPerBlock]`.
+    fn prune_tombstones(n: u32) -> Weight {
+        Weight::from_parts(5_000_000, 1_500)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64.saturating_add(n.into())))
+            .saturating_add(
+                T::DbWeight::get().writes(1_u64.saturating_add(u64::from(n).saturating_mul(2))),
+            )
+    }
+    /// Storage: `Birthmark::BlockIndexPruneCursor` (r:1 w:1)
+    /// Storage: `Birthmark::RecordsByBlock` (r:n w:n)
+    /// The range of component `n` is `[1, MaxBlockIndexPrunedPerBlock]`.
+    fn prune_block_index(n: u32) -> Weight {
+        Weight::from_parts(4_000_000, 1_500)
+            .saturating_add(Weight::from_parts(3_000_000, 2_550).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64.saturating_add(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64.saturating_add(n.into())))
+    }
+    /// Storage: `Birthmark::AuthorityIdByName` (r:n w:n)
+    /// Storage: `Birthmark::NextAuthorityId` (r:n w:n)
+    /// Storage: `Birthmark::AuthorityCapacityWarned` (r:n w:1)
+    /// Storage: `Birthmark::AuthorityRegistry` (r:0 w:n)
+    /// The range of component `n` is `[1, 100]`.
+    fn register_authorities_batch(n: u32) -> Weight {
+        Weight::from_parts(6_000_000, 1_500)
+            .saturating_add(Weight::from_parts(14_000_000, 2_700).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(u64::from(n).saturating_mul(3)))
+            .saturating_add(
+                T::DbWeight::get().writes(1_u64.saturating_add(u64::from(n).saturating_mul(3))),
+            )
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn submit_image_record_existing_authority() -> Weight {
+        Weight::from_parts(38_000_000, 4_200)
+            .saturating_add(RocksDbWeight::get().reads(14_u64))
+            .saturating_add(RocksDbWeight::get().writes(12_u64))
+    }
+    fn submit_image_record_new_authority() -> Weight {
+        Weight::from_parts(52_000_000, 5_800)
+            .saturating_add(RocksDbWeight::get().reads(17_u64))
+            .saturating_add(RocksDbWeight::get().writes(15_u64))
+    }
+    fn submit_image_batch(n: u32, p: u32) -> Weight {
+        Weight::from_parts(20_000_000, 3_000)
+            .saturating_add(Weight::from_parts(27_000_000, 2_600).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(33_000_000, 5_200).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(
+                4_u64
+                    .saturating_add(u64::from(n).saturating_mul(8))
+                    .saturating_add(u64::from(p).saturating_mul(10)),
+            ))
+            .saturating_add(RocksDbWeight::get().writes(
+                1_u64
+                    .saturating_add(u64::from(n).saturating_mul(11))
+                    .saturating_add(u64::from(p).saturating_mul(12)),
+            ))
+    }
+    fn prune_tombstones(n: u32) -> Weight {
+        Weight::from_parts(5_000_000, 1_500)
+            .saturating_add(Weight::from_parts(4_000_000, 2_600).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64.saturating_add(n.into())))
+            .saturating_add(
+                RocksDbWeight::get().writes(1_u64.saturating_add(u64::from(n).saturating_mul(2))),
+            )
+    }
+    fn prune_block_index(n: u32) -> Weight {
+        Weight::from_parts(4_000_000, 1_500)
+            .saturating_add(Weight::from_parts(3_000_000, 2_550).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64.saturating_add(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64.saturating_add(n.into())))
+    }
+    fn register_authorities_batch(n: u32) -> Weight {
+        Weight::from_parts(6_000_000, 1_500)
+            .saturating_add(Weight::from_parts(14_000_000, 2_700).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(u64::from(n).saturating_mul(3)))
+            .saturating_add(
+                RocksDbWeight::get().writes(1_u64.saturating_add(u64::from(n).saturating_mul(3))),
+            )
+    }
+}
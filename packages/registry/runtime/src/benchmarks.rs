@@ -0,0 +1,7 @@
+//! Pallets benchmarked by `birthmark-node benchmark pallet`.
+
+frame_benchmarking::define_benchmarks!(
+    [frame_benchmarking, BaselineBench::<Runtime>]
+    [frame_system, SystemBench::<Runtime>]
+    [pallet_birthmark, Birthmark]
+);
@@ -0,0 +1,117 @@
+//! Transaction-pool guard against submissions from non-aggregators
+//!
+//! `submit_image_record` and the batch calls reject unauthorized signers only
+//! when executed, so until then their transactions hold pool space and are
+//! gossiped to peers. [`CheckAggregator`] reads the aggregator set during
+//! validation and refuses such transactions before they enter the pool.
+//!
+//! Only submission calls are checked. Submissions are always signed, so there
+//! is no root or council submit path to exempt; governance calls keep their own
+//! origin checks and pass through untouched.
+
+use crate::{AccountId, Birthmark, RuntimeCall};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+    RuntimeDebug,
+};
+
+/// Rejects Birthmark submissions from accounts that may not submit
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct CheckAggregator;
+
+impl SignedExtension for CheckAggregator {
+    const IDENTIFIER: &'static str = "CheckAggregator";
+    type AccountId = AccountId;
+    type Call = RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        match call {
+            RuntimeCall::Birthmark(call)
+                if call.is_submission() && !Birthmark::is_authorized_aggregator(who) =>
+            {
+                Err(InvalidTransaction::BadSigner.into())
+            }
+            _ => Ok(ValidTransaction::default()),
+        }
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        self.validate(who, call, info, len).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RuntimeGenesisConfig;
+    use frame_support::dispatch::DispatchInfo;
+    use pallet_birthmark::SubmissionType;
+    use sp_runtime::BuildStorage;
+
+    fn submit_call() -> RuntimeCall {
+        RuntimeCall::Birthmark(pallet_birthmark::Call::submit_image_record {
+            image_hash: [1u8; 32].to_vec(),
+            submission_type: SubmissionType::Camera,
+            modification_level: 0,
+            parent_image_hash: None,
+            authority_name: b"CANON".to_vec(),
+            width: None,
+            height: None,
+            byte_size: None,
+        })
+    }
+
+    fn ext_with_aggregator(aggregator: AccountId) -> sp_io::TestExternalities {
+        let mut config = RuntimeGenesisConfig::default();
+        config.birthmark.aggregators = vec![aggregator];
+        config.build_storage().unwrap().into()
+    }
+
+    #[test]
+    fn non_aggregator_submission_is_rejected_at_validation() {
+        let aggregator = AccountId::from([1u8; 32]);
+        let outsider = AccountId::from([2u8; 32]);
+        let info = DispatchInfo::default();
+
+        ext_with_aggregator(aggregator.clone()).execute_with(|| {
+            assert_eq!(
+                CheckAggregator.validate(&outsider, &submit_call(), &info, 0),
+                Err(InvalidTransaction::BadSigner.into())
+            );
+            assert!(CheckAggregator.validate(&aggregator, &submit_call(), &info, 0).is_ok());
+        });
+    }
+
+    #[test]
+    fn non_submission_calls_are_not_checked() {
+        let outsider = AccountId::from([2u8; 32]);
+        let call = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 0 });
+
+        ext_with_aggregator(AccountId::from([1u8; 32])).execute_with(|| {
+            assert!(CheckAggregator.validate(&outsider, &call, &DispatchInfo::default(), 0).is_ok());
+        });
+    }
+}
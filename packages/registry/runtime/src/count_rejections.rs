@@ -0,0 +1,260 @@
+//! Counter of failed Birthmark submissions, for monitoring attack patterns
+//!
+//! A failed extrinsic rolls back every write it made, so the pallet cannot count
+//! its own rejections. [`CountRejections`] notes the outcome in `post_dispatch`,
+//! which runs after the call's storage layer has been discarded, and forwards
+//! failures to `pallet_birthmark::Pallet::note_rejection`. Single-record
+//! submissions rejected as duplicates are also passed to
+//! `pallet_birthmark::Pallet::note_resubmission`, which reports any claim of a
+//! different authority than the stored record's.
+
+use crate::{AccountId, Birthmark, Runtime, RuntimeCall};
+use codec::{Decode, Encode};
+use pallet_birthmark::Error as BirthmarkError;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, PostDispatchInfoOf, SignedExtension},
+    transaction_validity::TransactionValidityError,
+    DispatchError, DispatchResult, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Counts Birthmark submissions that fail during dispatch, by error, and reports
+/// duplicates that claim another authority
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct CountRejections;
+
+impl CountRejections {
+    /// Whether `call` submits records to the Birthmark registry
+    fn is_submission(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Birthmark(call) if call.is_submission())
+    }
+}
+
+impl SignedExtension for CountRejections {
+    const IDENTIFIER: &'static str = "CountRejections";
+    type AccountId = AccountId;
+    type Call = RuntimeCall;
+    type AdditionalSigned = ();
+    /// Whether the dispatched call was a submission, and the image hash and
+    /// authority name it claimed if it submitted a single record
+    type Pre = (bool, Option<(Vec<u8>, Vec<u8>)>);
+
+    fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+        Ok(())
+    }
+
+    fn pre_dispatch(
+        self,
+        _who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        let claim = match call {
+            RuntimeCall::Birthmark(call) => call
+                .submitted_record()
+                .map(|(image_hash, authority_name)| (image_hash.to_vec(), authority_name.to_vec())),
+            _ => None,
+        };
+        Ok((Self::is_submission(call), claim))
+    }
+
+    fn post_dispatch(
+        pre: Option<Self::Pre>,
+        _info: &DispatchInfoOf<Self::Call>,
+        _post_info: &PostDispatchInfoOf<Self::Call>,
+        _len: usize,
+        result: &DispatchResult,
+    ) -> Result<(), TransactionValidityError> {
+        if let (Some((true, claim)), Err(error)) = (pre, result) {
+            Birthmark::note_rejection(error);
+
+            if let Some((image_hash, authority_name)) = claim {
+                let duplicate = DispatchError::from(BirthmarkError::<Runtime>::HashAlreadyExists);
+                if *error == duplicate {
+                    Birthmark::note_resubmission(&image_hash, &authority_name);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        check_aggregator::CheckAggregator, Executive, Header, Nonce, RuntimeEvent,
+        RuntimeGenesisConfig, RuntimeOrigin, SignedExtra, System, UncheckedExtrinsic,
+    };
+    use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+    use pallet_birthmark::SubmissionType;
+    use sp_keyring::Sr25519Keyring;
+    use sp_runtime::{generic, traits::Dispatchable, BuildStorage, ModuleError, MultiAddress};
+
+    fn submit_call(image_hash: [u8; 32], authority_name: &[u8]) -> RuntimeCall {
+        RuntimeCall::Birthmark(pallet_birthmark::Call::submit_image_record {
+            image_hash: image_hash.to_vec(),
+            submission_type: SubmissionType::Camera,
+            modification_level: 0,
+            parent_image_hash: None,
+            authority_name: authority_name.to_vec(),
+            width: None,
+            height: None,
+            byte_size: None,
+        })
+    }
+
+    fn ext_with_aggregator(aggregator: AccountId) -> sp_io::TestExternalities {
+        let mut config = RuntimeGenesisConfig::default();
+        config.birthmark.aggregators = vec![aggregator];
+        let mut ext: sp_io::TestExternalities = config.build_storage().unwrap().into();
+        // Events are not deposited in the genesis block
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    /// Dispatch `call` between the extension's `pre_dispatch` and `post_dispatch`
+    fn dispatch_with_extension(who: &AccountId, call: RuntimeCall) -> DispatchResult {
+        let info = call.get_dispatch_info();
+        let pre = CountRejections.pre_dispatch(who, &call, &info, 0).unwrap();
+        let result = call
+            .dispatch(RuntimeOrigin::signed(who.clone()))
+            .map(|_| ())
+            .map_err(|error| error.error);
+        CountRejections::post_dispatch(Some(pre), &info, &PostDispatchInfo::default(), 0, &result)
+            .unwrap();
+        result
+    }
+
+    /// Sign `call` as `signer`, with every extension of the runtime's `SignedExtra`
+    fn signed_extrinsic(
+        signer: Sr25519Keyring,
+        nonce: Nonce,
+        call: RuntimeCall,
+    ) -> UncheckedExtrinsic {
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::new(),
+            frame_system::CheckSpecVersion::new(),
+            frame_system::CheckTxVersion::new(),
+            frame_system::CheckGenesis::new(),
+            frame_system::CheckEra::from(generic::Era::Immortal),
+            frame_system::CheckNonce::from(nonce),
+            frame_system::CheckWeight::new(),
+            CheckAggregator,
+            CountRejections,
+        );
+        let payload = generic::SignedPayload::new(call.clone(), extra.clone()).unwrap();
+        let signature = payload.using_encoded(|payload| signer.sign(payload));
+        UncheckedExtrinsic::new_signed(
+            call,
+            MultiAddress::Id(signer.to_account_id()),
+            signature.into(),
+            extra,
+        )
+    }
+
+    fn conflict_events() -> usize {
+        System::events()
+            .iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    RuntimeEvent::Birthmark(pallet_birthmark::Event::ConflictingResubmission { .. })
+                )
+            })
+            .count()
+    }
+
+    #[test]
+    fn only_submissions_are_tracked() {
+        let submit = submit_call([1u8; 32], b"CANON");
+        let remove = RuntimeCall::Birthmark(pallet_birthmark::Call::remove_record {
+            image_hash: [1u8; 32].to_vec(),
+            reason_code: 0,
+            reason: Vec::new(),
+        });
+        let timestamp = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 0 });
+
+        assert!(CountRejections::is_submission(&submit));
+        assert!(!CountRejections::is_submission(&remove));
+        assert!(!CountRejections::is_submission(&timestamp));
+    }
+
+    #[test]
+    fn conflicting_resubmission_is_reported_after_dispatch() {
+        let aggregator = AccountId::from([1u8; 32]);
+
+        ext_with_aggregator(aggregator.clone()).execute_with(|| {
+            assert!(dispatch_with_extension(&aggregator, submit_call([2u8; 32], b"CANON")).is_ok());
+            assert!(dispatch_with_extension(&aggregator, submit_call([3u8; 32], b"NIKON")).is_ok());
+
+            let result = dispatch_with_extension(&aggregator, submit_call([2u8; 32], b"NIKON"));
+            assert_eq!(result, Err(BirthmarkError::<Runtime>::HashAlreadyExists.into()));
+
+            let (stored_authority, attempted_authority) =
+                Birthmark::resubmission_conflict(&[2u8; 32], b"NIKON").unwrap();
+            assert!(attempted_authority.is_some());
+            assert_eq!(
+                System::events().last().map(|record| record.event.clone()),
+                Some(RuntimeEvent::Birthmark(pallet_birthmark::Event::ConflictingResubmission {
+                    hash: [2u8; 32],
+                    stored_authority,
+                    attempted_authority,
+                }))
+            );
+            assert_eq!(conflict_events(), 1);
+        });
+    }
+
+    #[test]
+    fn matching_resubmission_is_not_reported() {
+        let aggregator = AccountId::from([1u8; 32]);
+
+        ext_with_aggregator(aggregator.clone()).execute_with(|| {
+            assert!(dispatch_with_extension(&aggregator, submit_call([2u8; 32], b"CANON")).is_ok());
+
+            let result = dispatch_with_extension(&aggregator, submit_call([2u8; 32], b"CANON"));
+            assert_eq!(result, Err(BirthmarkError::<Runtime>::HashAlreadyExists.into()));
+            assert_eq!(conflict_events(), 0);
+            // The rejection itself is still counted
+            assert_eq!(Birthmark::rejection_stats().len(), 1);
+        });
+    }
+
+    #[test]
+    fn duplicate_signed_extrinsic_is_counted() {
+        let alice = Sr25519Keyring::Alice;
+
+        ext_with_aggregator(alice.to_account_id()).execute_with(|| {
+            // `CheckNonce` only accepts accounts that exist, and with no genesis
+            // endowments nothing provides for the aggregator's account
+            System::inc_providers(&alice.to_account_id());
+            Executive::initialize_block(&Header::new(
+                1,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ));
+
+            let call = submit_call([4u8; 32], b"CANON");
+            let first = signed_extrinsic(alice, 0, call.clone());
+            assert_eq!(Executive::apply_extrinsic(first), Ok(Ok(())));
+            assert!(Birthmark::rejection_stats().is_empty());
+
+            // The duplicate is included, but its dispatch fails and is counted
+            assert_eq!(
+                Executive::apply_extrinsic(signed_extrinsic(alice, 1, call)),
+                Ok(Err(BirthmarkError::<Runtime>::HashAlreadyExists.into()))
+            );
+            let DispatchError::Module(ModuleError { error, .. }) =
+                DispatchError::from(BirthmarkError::<Runtime>::HashAlreadyExists)
+            else {
+                unreachable!("pallet errors are module errors");
+            };
+            assert_eq!(Birthmark::rejection_stats(), vec![(error[0], 1)]);
+        });
+    }
+}
@@ -0,0 +1,117 @@
+//! Named genesis presets for the `GenesisBuilder` runtime API
+//!
+//! These mirror the development and local testnet specs in the node's
+//! `chain_spec.rs`, so tools like `chain-spec-builder` can produce a chain spec
+//! from the runtime wasm alone. Like the node's specs, only pallets present in
+//! this runtime are configured.
+
+use crate::{AccountId, RuntimeGenesisConfig};
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_genesis_builder::{self, PresetId};
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+use sp_std::prelude::*;
+
+/// Build the genesis JSON for a set of PoA authorities and aggregator accounts
+fn testnet_genesis(
+    initial_authorities: Vec<(AuraId, GrandpaId)>,
+    aggregators: Vec<AccountId>,
+) -> Value {
+    let config = RuntimeGenesisConfig {
+        aura: pallet_aura::GenesisConfig {
+            // Configure initial block production authorities
+            authorities: initial_authorities.iter().map(|x| x.0.clone()).collect(),
+        },
+        grandpa: pallet_grandpa::GenesisConfig {
+            // Configure initial finality authorities
+            authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
+            ..Default::default()
+        },
+        birthmark: pallet_birthmark::GenesisConfig {
+            // Accounts allowed to submit records
+            aggregators,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    serde_json::to_value(config).expect("Could not build genesis config.")
+}
+
+/// Aura and GRANDPA keys for a well-known development account
+fn authority_keys(sr: Sr25519Keyring, ed: Ed25519Keyring) -> (AuraId, GrandpaId) {
+    (sr.public().into(), ed.public().into())
+}
+
+/// Single-validator development chain (Alice)
+pub fn development_config_genesis() -> Value {
+    testnet_genesis(
+        vec![authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice)],
+        vec![Sr25519Keyring::Alice.to_account_id()],
+    )
+}
+
+/// Three-validator local testnet (Alice, Bob, Charlie)
+pub fn local_config_genesis() -> Value {
+    testnet_genesis(
+        vec![
+            authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+            authority_keys(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+            authority_keys(Sr25519Keyring::Charlie, Ed25519Keyring::Charlie),
+        ],
+        vec![Sr25519Keyring::Alice.to_account_id(), Sr25519Keyring::Bob.to_account_id()],
+    )
+}
+
+/// Return the JSON patch for a named preset, or `None` if it is unknown
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+    let patch = match id.as_ref() {
+        sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
+        sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+        _ => return None,
+    };
+
+    Some(
+        serde_json::to_string(&patch)
+            .expect("serialization to json is expected to work. qed.")
+            .into_bytes(),
+    )
+}
+
+/// Names of the presets served by [`get_preset`]
+pub fn preset_names() -> Vec<PresetId> {
+    vec![
+        PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
+        PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn development_preset_is_decodable() {
+        let preset = frame_support::genesis_builder_helper::get_preset::<RuntimeGenesisConfig>(
+            &Some(sp_genesis_builder::DEV_RUNTIME_PRESET.into()),
+            get_preset,
+        )
+        .expect("development preset exists");
+        assert!(!preset.is_empty());
+
+        let config: RuntimeGenesisConfig =
+            serde_json::from_slice(&preset).expect("preset decodes as the runtime genesis config");
+        assert_eq!(config.aura.authorities.len(), 1);
+        assert_eq!(config.grandpa.authorities.len(), 1);
+        assert_eq!(config.birthmark.aggregators, vec![Sr25519Keyring::Alice.to_account_id()]);
+    }
+
+    #[test]
+    fn preset_names_are_all_served() {
+        for name in preset_names() {
+            assert!(get_preset(&name).is_some());
+        }
+        assert!(get_preset(&"unknown".into()).is_none());
+    }
+}
@@ -29,7 +29,7 @@ use frame_support::{
     construct_runtime, derive_impl, parameter_types,
     traits::{
         ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse,
-        EqualPrivilegeOnly,
+        EqualPrivilegeOnly, VariantCountOf,
     },
     weights::{
         constants::RocksDbWeight, ConstantMultiplier, IdentityFee, Weight,
@@ -50,7 +50,13 @@ pub use frame_support::{
 pub use pallet_timestamp::Call as TimestampCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
+pub mod check_aggregator;
+pub mod count_rejections;
+pub mod genesis_config_presets;
 
 /// Opaque types for node integration
 pub mod opaque {
@@ -76,10 +82,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("birthmark-node"),
     impl_name: create_runtime_str!("birthmark-node"),
     authoring_version: 1,
-    spec_version: 1,
+    spec_version: 3,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -107,13 +113,18 @@ pub type Nonce = u32;
 pub type Hash = sp_core::H256;
 pub type Moment = u64;
 
-// Note: Balance type removed - no token economy in Birthmark
+/// Balance type; only identity deposits are denominated in it
+pub type Balance = u128;
 
 /// Block weights and limits
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+/// Maximum encoded block size (5 MiB)
+const MAXIMUM_BLOCK_LENGTH: u32 = 5 * 1024 * 1024;
+/// Proof-size budget: a block's storage proof may be no larger than the block
+/// itself, so the PoV dimension is bounded by `MAXIMUM_BLOCK_LENGTH`
 const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
     2u64 * WEIGHT_REF_TIME_PER_SECOND,
-    u64::MAX,
+    MAXIMUM_BLOCK_LENGTH as u64,
 );
 
 parameter_types! {
@@ -136,7 +147,7 @@ parameter_types! {
         .avg_block_initialization(Perbill::from_percent(10))
         .build_or_panic();
     pub BlockLengthConfig: BlockLength = BlockLength::max_with_normal_ratio(
-        5 * 1024 * 1024,
+        MAXIMUM_BLOCK_LENGTH,
         NORMAL_DISPATCH_RATIO,
     );
     pub const SS58Prefix: u8 = 42;
@@ -154,7 +165,7 @@ impl frame_system::Config for Runtime {
     type BlockHashCount = BlockHashCount;
     type DbWeight = RocksDbWeight;
     type Version = Version;
-    type AccountData = (); // No account data - no balances needed
+    type AccountData = pallet_balances::AccountData<Balance>;
     type SS58Prefix = SS58Prefix;
     type MaxConsumers = ConstU32<16>;
 }
@@ -191,8 +202,68 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+/// Configure pallet_balances (backs identity deposits only)
+impl pallet_balances::Config for Runtime {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+    type FreezeIdentifier = RuntimeFreezeReason;
+    type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
+/// Configure pallet_identity (submitter identities and registrar judgements)
+parameter_types! {
+    pub const MaxAdditionalFields: u32 = 100;
+    pub const MaxRegistrars: u32 = 20;
+}
+
+impl pallet_identity::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    // No token economy: registrar judgements, not deposits, decide who is trusted
+    type BasicDeposit = ConstU128<0>;
+    type ByteDeposit = ConstU128<0>;
+    type SubAccountDeposit = ConstU128<0>;
+    type MaxSubAccounts = ConstU32<100>;
+    type IdentityInformation = pallet_identity::legacy::IdentityInfo<MaxAdditionalFields>;
+    type MaxRegistrars = MaxRegistrars;
+    type Slashed = ();
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type RegistrarOrigin = EnsureRoot<AccountId>;
+    type OffchainSignature = Signature;
+    type SigningPublicKey = <Signature as Verify>::Signer;
+    type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+    type PendingUsernameExpiration = ConstU32<{ 7 * DAYS }>;
+    type MaxSuffixLength = ConstU32<7>;
+    type MaxUsernameLength = ConstU32<32>;
+    type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+/// Vouches for accounts a registrar judged `Reasonable` or `KnownGood`
+pub struct JudgedIdentity;
+
+impl pallet_birthmark::IdentityVerifier<AccountId> for JudgedIdentity {
+    fn has_good_identity(who: &AccountId) -> bool {
+        Identity::identity(who.clone()).is_some_and(|(registration, _)| {
+            registration.judgements.iter().any(|(_, judgement)| {
+                matches!(
+                    judgement,
+                    pallet_identity::Judgement::Reasonable | pallet_identity::Judgement::KnownGood
+                )
+            })
+        })
+    }
+}
+
 // Removed pallet configurations (optimization):
-// - pallet_balances (no token economy)
 // - pallet_transaction_payment (feeless chain)
 // - pallet_sudo (off-chain governance)
 // - pallet_democracy (off-chain governance)
@@ -204,13 +275,77 @@ impl pallet_timestamp::Config for Runtime {
 /// Configure pallet_birthmark (custom)
 parameter_types! {
     pub const MaxAuthorityIdLength: u32 = 100;
-    pub const MaxImageHashLength: u32 = 64;
+    pub const MinAuthorityNameLength: u32 = 2;
+    // 64 hex chars plus an optional `0x` prefix
+    pub const MaxImageHashLength: u32 = 66;
+    pub const MaxBulkQuerySize: u32 = 256;
+    // Takedown tombstones are kept for a year before being pruned
+    pub const TombstoneRetention: BlockNumber = 365 * DAYS;
+    pub const MaxPrunedPerBlock: u32 = 16;
+    // Submitters have an hour to correct a mislabelled record
+    pub const MutabilityWindow: BlockNumber = HOURS;
+    // No cross-block cooldown; the per-transaction batch cap already bounds load
+    pub const SubmissionCooldown: BlockNumber = 0;
+    pub const MaxReasonLength: u32 = 256;
+    // Block/range listings cover the last 90 days; older records are found by hash
+    pub const BlockIndexRetention: BlockNumber = 90 * DAYS;
+    pub const MaxBlockIndexPrunedPerBlock: u32 = 64;
+    pub const MaxAuthorities: u32 = 1_000;
+    // IDs 0-15 are kept for governance-curated system authorities
+    pub const FirstUserAuthorityId: u32 = 16;
+    // Warn governance when 90% of the authority cap is in use
+    pub const AuthorityCapacityWarning: Percent = Percent::from_percent(90);
+    pub const MaxProvenanceDepth: u32 = 32;
+    // A raw image may be cropped or re-encoded by many outlets, but not without limit
+    pub const MaxChildrenPerParent: u32 = 10_000;
+    pub const MaxBatchSize: u32 = 100;
+    // Off: submitters come from the aggregator allowlist. Governance can switch to
+    // identity judgements by setting this key with `system.set_storage`
+    pub storage UseIdentityAuthorization: bool = false;
 }
 
 impl pallet_birthmark::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxAuthorityIdLength = MaxAuthorityIdLength;
+    type MinAuthorityNameLength = MinAuthorityNameLength;
     type MaxImageHashLength = MaxImageHashLength;
+    type MaxAuthorities = MaxAuthorities;
+    type AuthorityCapacityWarning = AuthorityCapacityWarning;
+    type FirstUserAuthorityId = FirstUserAuthorityId;
+    // Aggregators may introduce new manufacturers; governance can still pre-register
+    type AllowAuthorityAutoRegistration = ConstBool<true>;
+    // Exact-bytes authority matching; enable before launch to collapse case/whitespace variants
+    type NormalizeAuthorityNames = ConstBool<false>;
+    // Authority names are shown to verifiers, so they must be readable text
+    type ValidateUtf8AuthorityNames = ConstBool<true>;
+    // Aggregators may register edits whose source was never submitted
+    type RequireParentForEdits = ConstBool<false>;
+    type MaxProvenanceDepth = MaxProvenanceDepth;
+    type MaxChildrenPerParent = MaxChildrenPerParent;
+    type MaxBatchSize = MaxBatchSize;
+    // Indexers read batch contents from storage; keep events small
+    type EmitBatchDetail = ConstBool<false>;
+    type MaxBulkQuerySize = MaxBulkQuerySize;
+    // Lets auditors sample hash shards without walking the whole registry
+    type IndexRecordsByHashPrefix = ConstBool<true>;
+    type MaxReasonLength = MaxReasonLength;
+    type TombstoneRetention = TombstoneRetention;
+    type MaxPrunedPerBlock = MaxPrunedPerBlock;
+    type MutabilityWindow = MutabilityWindow;
+    type SubmissionCooldown = SubmissionCooldown;
+    type BlockIndexRetention = BlockIndexRetention;
+    type MaxBlockIndexPrunedPerBlock = MaxBlockIndexPrunedPerBlock;
+    type FindAuthor = Aura;
+    type UseIdentityAuthorization = UseIdentityAuthorization;
+    type IdentityVerifier = JudgedIdentity;
+    // No extra acceptance checks beyond the pallet's own validation
+    type RecordValidator = ();
+    // No on-chain council in the minimal runtime - governance acts through root
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    // Would be EitherOfDiverse<EnsureRoot, EnsureProportionAtLeast<Council, 2, 3>>
+    // once a council pallet is added; root is the only governance origin for now
+    type RemovalOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_birthmark::weights::SubstrateWeight<Runtime>;
 }
 
 // Construct the runtime - MINIMAL CONFIGURATION
@@ -222,6 +357,8 @@ construct_runtime!(
         Aura: pallet_aura,
         Grandpa: pallet_grandpa,
         Birthmark: pallet_birthmark,
+        Balances: pallet_balances,
+        Identity: pallet_identity,
     }
 );
 
@@ -245,10 +382,14 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
+    check_aggregator::CheckAggregator,
+    count_rejections::CountRejections,
 );
 /// Unchecked extrinsic type
 pub type UncheckedExtrinsic =
     generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+/// Pending storage migrations, executed on the next runtime upgrade
+pub type Migrations = pallet_birthmark::migrations::AllMigrations<Runtime>;
 /// Executive: handles dispatch to the various modules
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -256,6 +397,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 impl_runtime_apis! {
@@ -381,17 +523,177 @@ impl_runtime_apis! {
 
     // Removed: TransactionPaymentApi (feeless chain optimization)
 
+    impl pallet_birthmark_runtime_api::BirthmarkApi<Block, AccountId> for Runtime {
+        fn get_image_record(image_hash: [u8; 32]) -> Option<pallet_birthmark::ImageRecord> {
+            Birthmark::get_image_record(&image_hash)
+        }
+
+        fn record_block(image_hash: [u8; 32]) -> Option<u32> {
+            Birthmark::record_block(&image_hash)
+        }
+
+        fn get_record_with_authority(
+            image_hash: [u8; 32],
+        ) -> Option<(pallet_birthmark::ImageRecord, Option<Vec<u8>>)> {
+            Birthmark::get_record_with_authority(&image_hash)
+        }
+
+        fn provenance_report(
+            image_hash: [u8; 32],
+        ) -> Vec<(pallet_birthmark::ImageRecord, Option<Vec<u8>>)> {
+            Birthmark::provenance_report(&image_hash)
+        }
+
+        fn record_status(image_hash: [u8; 32]) -> pallet_birthmark::RecordStatus {
+            Birthmark::record_status(&image_hash)
+        }
+
+        fn record_storage_key(image_hash: [u8; 32]) -> Vec<u8> {
+            Birthmark::record_storage_key(&image_hash)
+        }
+
+        fn storage_version() -> u16 {
+            Birthmark::storage_version()
+        }
+
+        fn total_records() -> u64 {
+            Birthmark::get_total_records()
+        }
+
+        fn records_count_for_authority(authority_id: u32) -> u64 {
+            Birthmark::records_count_for_authority(authority_id)
+        }
+
+        fn images_exist(image_hashes: Vec<[u8; 32]>) -> Vec<bool> {
+            Birthmark::images_exist(&image_hashes)
+        }
+
+        fn get_records(image_hashes: Vec<[u8; 32]>) -> Vec<Option<pallet_birthmark::ImageRecord>> {
+            Birthmark::get_records(&image_hashes)
+        }
+
+        fn get_records_with_authority(
+            image_hashes: Vec<[u8; 32]>,
+        ) -> Vec<Option<(pallet_birthmark::ImageRecord, Option<Vec<u8>>)>> {
+            Birthmark::get_records_with_authority(&image_hashes)
+        }
+
+        fn get_snapshot(block: BlockNumber) -> Option<pallet_birthmark::StatsSnapshot> {
+            Birthmark::get_snapshot(block)
+        }
+
+        fn list_snapshots() -> Vec<(BlockNumber, pallet_birthmark::StatsSnapshot)> {
+            Birthmark::list_snapshots()
+        }
+
+        fn level_histogram(from_block: BlockNumber, to_block: BlockNumber) -> Vec<(BlockNumber, [u64; 3])> {
+            Birthmark::level_histogram(from_block, to_block)
+        }
+
+        fn records_in_range(
+            from: BlockNumber,
+            to: BlockNumber,
+            start: Option<(BlockNumber, Option<[u8; 32]>)>,
+            limit: u32,
+        ) -> Option<(Vec<[u8; 32]>, Option<(BlockNumber, Option<[u8; 32]>)>)> {
+            Birthmark::records_in_range(from, to, start, limit)
+        }
+
+        fn records_merkle_root(from: BlockNumber, to: BlockNumber) -> Option<[u8; 32]> {
+            Birthmark::records_merkle_root(from, to)
+        }
+
+        fn records_by_submission_type(
+            kind: pallet_birthmark::SubmissionType,
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<[u8; 32]> {
+            Birthmark::records_by_submission_type(kind, start, limit)
+        }
+
+        fn list_records(
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> (Vec<pallet_birthmark::ImageRecord>, Option<[u8; 32]>) {
+            Birthmark::list_records(start, limit)
+        }
+
+        fn batch_weight(records: u32, parents: u32) -> (u64, u64) {
+            let weight = Birthmark::batch_weight(records as usize, parents as usize);
+            (weight.ref_time(), weight.proof_size())
+        }
+
+        fn modification_label(level: u8) -> Option<Vec<u8>> {
+            Birthmark::modification_label(level)
+        }
+
+        fn genesis_authorities() -> Vec<u32> {
+            Birthmark::genesis_authorities()
+        }
+
+        fn recent_records(n: u32) -> Vec<[u8; 32]> {
+            Birthmark::recent_records(n)
+        }
+
+        fn authority_trust(id: u32) -> Option<u8> {
+            Birthmark::authority_trust(id)
+        }
+
+        fn submissions_by(who: AccountId) -> u64 {
+            Birthmark::submissions_by(&who)
+        }
+
+        fn is_aggregator(who: AccountId) -> bool {
+            Birthmark::is_authorized_aggregator(&who)
+        }
+
+        fn records_by_submitter(
+            who: AccountId,
+            start: Option<[u8; 32]>,
+            limit: u32,
+        ) -> Vec<[u8; 32]> {
+            Birthmark::records_by_submitter(&who, start, limit)
+        }
+
+        fn rejection_stats() -> Vec<(u8, u64)> {
+            Birthmark::rejection_stats()
+        }
+
+        fn linked_records(image_hash: [u8; 32]) -> Vec<[u8; 32]> {
+            Birthmark::linked_records(&image_hash)
+        }
+
+        fn storage_footprint() -> pallet_birthmark::StorageFootprint {
+            Birthmark::storage_footprint()
+        }
+
+        fn authority_name_history(authority_id: u32) -> Vec<Vec<u8>> {
+            Birthmark::authority_name_history(authority_id)
+        }
+
+        fn config_constants() -> pallet_birthmark::BirthmarkConstants {
+            Birthmark::config_constants()
+        }
+
+        fn authority_name(authority_id: u32) -> Option<Vec<u8>> {
+            Birthmark::get_authority_name(authority_id).map(|name| name.into_inner())
+        }
+    }
+
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
             frame_support::genesis_builder_helper::build_state::<RuntimeGenesisConfig>(config)
         }
 
         fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
-            frame_support::genesis_builder_helper::get_preset::<RuntimeGenesisConfig>(id, |_| None)
+            frame_support::genesis_builder_helper::get_preset::<RuntimeGenesisConfig>(
+                id,
+                genesis_config_presets::get_preset,
+            )
         }
 
         fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-            vec![]
+            genesis_config_presets::preset_names()
         }
     }
 
@@ -403,6 +705,8 @@ impl_runtime_apis! {
         ) {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkList};
             use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
 
             let mut list = Vec::<BenchmarkList>::new();
             list_benchmarks!(list, extra);
@@ -416,8 +720,11 @@ impl_runtime_apis! {
             config: frame_benchmarking::BenchmarkConfig
         ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
             use frame_benchmarking::{baseline, Benchmarking, BenchmarkBatch};
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use baseline::Pallet as BaselineBench;
 
             impl frame_system_benchmarking::Config for Runtime {}
+            impl baseline::Config for Runtime {}
 
             use frame_support::traits::WhitelistedStorageKeys;
             let whitelist: Vec<frame_benchmarking::TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
@@ -448,3 +755,188 @@ impl_runtime_apis! {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::dispatch::DispatchClass;
+
+    #[test]
+    fn normal_proof_size_fits_block_length() {
+        let max_total = BlockWeightsConfig::get()
+            .get(DispatchClass::Normal)
+            .max_total
+            .expect("normal class has a limit");
+        let max_length = *BlockLengthConfig::get().max.get(DispatchClass::Normal);
+
+        assert!(max_total.proof_size() <= max_length as u64);
+        assert!(MAXIMUM_BLOCK_WEIGHT.proof_size() <= MAXIMUM_BLOCK_LENGTH as u64);
+    }
+
+    #[test]
+    fn governance_submit_uses_operational_reserve() {
+        use frame_support::dispatch::GetDispatchInfo;
+        use pallet_birthmark::SubmissionType;
+        use sp_runtime::traits::Dispatchable;
+
+        let force = RuntimeCall::Birthmark(pallet_birthmark::Call::force_submit_image_record {
+            image_hash: [7u8; 32].to_vec(),
+            submission_type: SubmissionType::Camera,
+            modification_level: 0,
+            parent_image_hash: None,
+            authority_name: b"COURT_ORDER".to_vec(),
+        });
+        let normal = RuntimeCall::Birthmark(pallet_birthmark::Call::submit_image_record {
+            image_hash: [8u8; 32].to_vec(),
+            submission_type: SubmissionType::Camera,
+            modification_level: 0,
+            parent_image_hash: None,
+            authority_name: b"CANON".to_vec(),
+            width: None,
+            height: None,
+            byte_size: None,
+        });
+        assert_eq!(force.get_dispatch_info().class, DispatchClass::Operational);
+
+        let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            // Fill the normal class to its limit
+            let normal_limit = BlockWeightsConfig::get()
+                .get(DispatchClass::Normal)
+                .max_total
+                .expect("normal class has a limit");
+            frame_system::BlockWeight::<Runtime>::mutate(|weight| {
+                weight.set(normal_limit, DispatchClass::Normal)
+            });
+
+            assert!(frame_system::CheckWeight::<Runtime>::do_pre_dispatch(
+                &normal.get_dispatch_info(),
+                100
+            )
+            .is_err());
+            assert!(frame_system::CheckWeight::<Runtime>::do_pre_dispatch(
+                &force.get_dispatch_info(),
+                100
+            )
+            .is_ok());
+
+            // Only governance may take the operational path
+            let alice = AccountId::from([1u8; 32]);
+            assert!(force.clone().dispatch(RuntimeOrigin::signed(alice)).is_err());
+            assert!(force.dispatch(RuntimeOrigin::root()).is_ok());
+            assert!(Birthmark::get_image_record(&[7u8; 32]).is_some());
+        });
+    }
+
+    #[test]
+    fn oversized_ref_batch_fails_to_decode() {
+        use codec::{Decode, Encode};
+        use pallet_birthmark::{AuthorityRef, BatchRecordWithRef, SubmissionType};
+
+        let max = <Runtime as pallet_birthmark::Config>::MaxBatchSize::get();
+        let records = |count: u32| -> Vec<BatchRecordWithRef<MaxImageHashLength>> {
+            (0..count)
+                .map(|i| BatchRecordWithRef {
+                    image_hash: (i as u8..i as u8 + 32).collect::<Vec<_>>().try_into().unwrap(),
+                    submission_type: SubmissionType::Camera,
+                    modification_level: 0,
+                    parent: None,
+                    authority: AuthorityRef::Name(b"CANON".to_vec()),
+                })
+                .collect()
+        };
+        // Unsigned extrinsic bytes: version byte, then the call
+        let extrinsic = |call: &[u8]| [&[4u8][..], call].concat().encode();
+
+        let full = RuntimeCall::Birthmark(pallet_birthmark::Call::submit_image_batch_with_refs {
+            records: records(max).try_into().unwrap(),
+        })
+        .encode();
+        assert!(RuntimeCall::decode(&mut &full[..]).is_ok());
+        assert!(UncheckedExtrinsic::decode(&mut &extrinsic(&full)[..]).is_ok());
+
+        // Same pallet and call index, one record over the bound: no transaction
+        // validation or block import ever sees it
+        let oversized = [&full[..2], &records(max + 1).encode()[..]].concat();
+        assert!(RuntimeCall::decode(&mut &oversized[..]).is_err());
+        assert!(UncheckedExtrinsic::decode(&mut &extrinsic(&oversized)[..]).is_err());
+    }
+
+    #[test]
+    fn runtime_upgrade_migrates_birthmark_storage_to_latest() {
+        use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+        let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            StorageVersion::new(0).put::<Birthmark>();
+
+            Executive::execute_on_runtime_upgrade();
+
+            assert_eq!(
+                Birthmark::on_chain_storage_version(),
+                Birthmark::in_code_storage_version()
+            );
+        });
+    }
+
+    #[test]
+    fn identity_mode_admits_only_judged_submitters() {
+        use pallet_identity::{legacy::IdentityInfo, Data, Judgement};
+        use sp_runtime::traits::{Dispatchable, Hash as _};
+
+        let registrar = AccountId::from([1u8; 32]);
+        let anonymous = AccountId::from([2u8; 32]);
+        let newsroom = AccountId::from([3u8; 32]);
+        let submit = |who: &AccountId, hash: u8| {
+            RuntimeCall::Birthmark(pallet_birthmark::Call::submit_image_record {
+                image_hash: [hash; 32].to_vec(),
+                submission_type: pallet_birthmark::SubmissionType::Camera,
+                modification_level: 0,
+                parent_image_hash: None,
+                authority_name: b"CANON".to_vec(),
+                width: None,
+                height: None,
+                byte_size: None,
+            })
+            .dispatch(RuntimeOrigin::signed(who.clone()))
+            .map_err(|e| e.error)
+        };
+        let info = IdentityInfo {
+            additional: Default::default(),
+            display: Data::Raw(b"Newsroom".to_vec().try_into().unwrap()),
+            legal: Data::None,
+            web: Data::None,
+            riot: Data::None,
+            email: Data::None,
+            pgp_fingerprint: None,
+            image: Data::None,
+            twitter: Data::None,
+        };
+
+        let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+        sp_io::TestExternalities::new(storage).execute_with(|| {
+            UseIdentityAuthorization::set(&true);
+
+            let root = RuntimeOrigin::root();
+            assert!(Identity::add_registrar(root, registrar.clone().into()).is_ok());
+            let origin = RuntimeOrigin::signed(newsroom.clone());
+            assert!(Identity::set_identity(origin.clone(), Box::new(info.clone())).is_ok());
+            assert!(Identity::request_judgement(origin, 0, 0).is_ok());
+            assert!(Identity::provide_judgement(
+                RuntimeOrigin::signed(registrar),
+                0,
+                newsroom.clone().into(),
+                Judgement::KnownGood,
+                BlakeTwo256::hash_of(&info),
+            )
+            .is_ok());
+
+            assert_eq!(
+                submit(&anonymous, 1),
+                Err(pallet_birthmark::Error::<Runtime>::NotAuthorizedAggregator.into())
+            );
+            assert!(submit(&newsroom, 2).is_ok());
+            assert!(Birthmark::get_image_record(&[2u8; 32]).is_some());
+        });
+    }
+}
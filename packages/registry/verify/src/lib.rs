@@ -0,0 +1,102 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Birthmark Verify
+//!
+//! Image hash parsing shared by the Birthmark pallet and off-chain verifiers.
+//!
+//! The crate has no FRAME dependencies and builds for `no_std`, so the exact same
+//! routine runs inside the runtime (`pallet_birthmark::Pallet::parse_image_hash`)
+//! and in browsers through the optional `wasm` feature.
+//!
+//! Accepted inputs:
+//! - 32 raw bytes (binary SHA-256 digest)
+//! - 64 hex characters, upper or lower case
+//! - 64 hex characters prefixed with `0x`
+//!
+//! Callers that take text, like the browser export, use [`parse_hex_hash`],
+//! which drops the binary form.
+
+use core::fmt;
+
+/// Why an input could not be parsed as an image hash
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashError {
+    /// Input is neither 32 bytes, 64 hex chars, nor `0x` + 64 hex chars
+    InvalidLength(usize),
+    /// Input has a hex length but contains a non-hex character
+    InvalidHex,
+}
+
+impl fmt::Display for HashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(
+                f,
+                "invalid hash length {} (expected 32 bytes or 64 hex chars)",
+                len
+            ),
+            Self::InvalidHex => write!(f, "hash contains non-hex characters"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HashError {}
+
+/// Parse an image hash given as binary or hex into its 32-byte binary form
+pub fn parse_hash(input: &[u8]) -> Result<[u8; 32], HashError> {
+    let hex = match input.len() {
+        32 => {
+            let mut result = [0u8; 32];
+            result.copy_from_slice(input);
+            return Ok(result);
+        }
+        64 => input,
+        66 if input.starts_with(b"0x") => &input[2..],
+        len => return Err(HashError::InvalidLength(len)),
+    };
+
+    let mut result = [0u8; 32];
+    for (byte, pair) in result.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_value(pair[0])? << 4) | hex_value(pair[1])?;
+    }
+    Ok(result)
+}
+
+/// Parse an image hash given as hex text into its 32-byte binary form
+///
+/// Like [`parse_hash`] without the binary form, so 32 characters of text (such
+/// as half a hex hash) are rejected instead of taken as a raw digest.
+pub fn parse_hex_hash(input: &[u8]) -> Result<[u8; 32], HashError> {
+    match input.len() {
+        64 | 66 => parse_hash(input),
+        len => Err(HashError::InvalidLength(len)),
+    }
+}
+
+/// Value of a single ASCII hex digit
+fn hex_value(c: u8) -> Result<u8, HashError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(HashError::InvalidHex),
+    }
+}
+
+/// wasm-bindgen exports for browser verifiers
+#[cfg(feature = "wasm")]
+pub mod wasm {
+    use wasm_bindgen::prelude::*;
+
+    /// Parse a hex image hash (optional `0x` prefix), returning its 32 binary bytes
+    #[wasm_bindgen(js_name = parseHash)]
+    pub fn parse_hash(input: &str) -> Result<Vec<u8>, JsError> {
+        super::parse_hex_hash(input.as_bytes())
+            .map(|hash| hash.to_vec())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests;
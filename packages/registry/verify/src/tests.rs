@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn parses_binary_hash() {
+    assert_eq!(parse_hash(&[7u8; 32]), Ok([7u8; 32]));
+}
+
+#[test]
+fn parses_hex_hash() {
+    let mut expected = [0xabu8; 32];
+    expected[0] = 0x01;
+
+    let lower = format!("01{}", "ab".repeat(31));
+    assert_eq!(parse_hash(lower.as_bytes()), Ok(expected));
+
+    let upper = lower.to_uppercase();
+    assert_eq!(parse_hash(upper.as_bytes()), Ok(expected));
+}
+
+#[test]
+fn parses_0x_prefixed_hash() {
+    let prefixed = format!("0x{}", "cd".repeat(32));
+    assert_eq!(parse_hash(prefixed.as_bytes()), Ok([0xcd; 32]));
+}
+
+#[test]
+fn rejects_wrong_lengths() {
+    assert_eq!(parse_hash(b""), Err(HashError::InvalidLength(0)));
+    assert_eq!(parse_hash(&[1u8; 31]), Err(HashError::InvalidLength(31)));
+    assert_eq!(parse_hash(&[b'a'; 65]), Err(HashError::InvalidLength(65)));
+    // 66 bytes are only accepted with a 0x prefix
+    assert_eq!(parse_hash(&[b'a'; 66]), Err(HashError::InvalidLength(66)));
+}
+
+#[test]
+fn rejects_non_hex_characters() {
+    let bad = format!("{}zz", "00".repeat(31));
+    assert_eq!(parse_hash(bad.as_bytes()), Err(HashError::InvalidHex));
+}
+
+#[test]
+fn hex_parser_refuses_binary_length_text() {
+    // Half of a hex hash is 32 bytes long but is not a digest
+    let half = "ab".repeat(16);
+    assert_eq!(parse_hash(half.as_bytes()), Ok([b'a', b'b'].repeat(16).try_into().unwrap()));
+    assert_eq!(parse_hex_hash(half.as_bytes()), Err(HashError::InvalidLength(32)));
+
+    assert_eq!(parse_hex_hash("ab".repeat(32).as_bytes()), Ok([0xab; 32]));
+    assert_eq!(parse_hex_hash(format!("0x{}", "ab".repeat(32)).as_bytes()), Ok([0xab; 32]));
+    assert_eq!(parse_hex_hash(&[b'a'; 66]), Err(HashError::InvalidLength(66)));
+}